@@ -0,0 +1,460 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Genesis Asset Registry
+//!
+//! Human-readable asset registry files which seed `pallet_asset_manager` at genesis. Registries
+//! are read as TOML when the file has a `.toml` extension and as JSON otherwise.
+
+use codec::Encode;
+use manta_primitives::{
+    assets::{AssetLocation, AssetRegistryMetadata, AssetStorageMetadata},
+    types::{AccountId, Balance},
+};
+use pallet_asset_manager::{rpc::JsonLocation, GenesisAsset};
+use serde::{de, Deserialize, Deserializer, Serialize};
+use std::{fs, path::Path};
+
+/// Genesis Assets of the Asset Manager
+pub type GenesisAssets = Vec<GenesisAsset<AccountId, Balance>>;
+
+/// Chain Specifications which accept an asset registry file with `<id>:<path>`.
+pub const ASSET_REGISTRY_CHAIN_SPECS: [&str; 6] = [
+    "calamari-dev",
+    "calamari-local",
+    "calamari-localdev",
+    "dolphin-dev",
+    "dolphin-local",
+    "dolphin-localdev",
+];
+
+/// Splits the chain specification `spec` into its id and the path of its asset registry file,
+/// if `spec` is of the form `<id>:<path>` for an id in [`ASSET_REGISTRY_CHAIN_SPECS`].
+#[inline]
+pub fn split_registry_spec(spec: &str) -> (&str, Option<&str>) {
+    match spec.split_once(':') {
+        Some((id, path)) if ASSET_REGISTRY_CHAIN_SPECS.contains(&id) => (id, Some(path)),
+        _ => (spec, None),
+    }
+}
+
+/// Asset Registry
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct AssetRegistry {
+    /// Assets to register at genesis, in asset id order
+    #[serde(default)]
+    pub assets: Vec<RegistryAsset>,
+}
+
+impl AssetRegistry {
+    /// Reads the asset registry stored at `path`.
+    #[inline]
+    pub fn from_file<P>(path: P) -> Result<Self, String>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path)
+            .map_err(|err| format!("Unable to read asset registry {}: {}", path.display(), err))?;
+        let registry = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_toml(&contents),
+            _ => Self::from_json(&contents),
+        };
+        registry.map_err(|err| format!("Invalid asset registry {}: {}", path.display(), err))
+    }
+
+    /// Parses an asset registry from its JSON representation.
+    #[inline]
+    pub fn from_json(contents: &str) -> Result<Self, String> {
+        serde_json::from_str(contents).map_err(|err| err.to_string())
+    }
+
+    /// Parses an asset registry from its TOML representation.
+    #[inline]
+    pub fn from_toml(contents: &str) -> Result<Self, String> {
+        toml::from_str(contents).map_err(|err| err.to_string())
+    }
+
+    /// Converts the registry into the `pallet_asset_manager` genesis assets.
+    #[inline]
+    pub fn genesis_assets(&self) -> Result<GenesisAssets, String> {
+        self.assets
+            .iter()
+            .map(RegistryAsset::genesis_asset)
+            .collect()
+    }
+}
+
+/// Registry Asset
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct RegistryAsset {
+    /// Location of the asset
+    pub location: JsonLocation,
+
+    /// Asset Name
+    pub name: String,
+
+    /// Asset Symbol
+    pub symbol: String,
+
+    /// Number of Decimals
+    pub decimals: u8,
+
+    /// Minimum Balance
+    #[serde(deserialize_with = "deserialize_balance")]
+    pub min_balance: Balance,
+
+    /// Frozen Flag
+    #[serde(default)]
+    pub is_frozen: bool,
+
+    /// Sufficiency Flag
+    #[serde(default = "default_is_sufficient")]
+    pub is_sufficient: bool,
+
+    /// XCM transfer cost of the asset
+    #[serde(default, deserialize_with = "deserialize_optional_balance")]
+    pub units_per_second: Option<u128>,
+
+    /// Minimum XCM execution fee paid on the reserve chain of the asset
+    #[serde(default, deserialize_with = "deserialize_optional_balance")]
+    pub min_xcm_fee: Option<u128>,
+
+    /// Initial balances minted to their accounts
+    #[serde(default)]
+    pub mints: Vec<RegistryMint>,
+}
+
+impl RegistryAsset {
+    /// Converts `self` into a `pallet_asset_manager` genesis asset.
+    #[inline]
+    pub fn genesis_asset(&self) -> Result<GenesisAsset<AccountId, Balance>, String> {
+        let location = AssetLocation::try_from(self.location.clone())
+            .map_err(|err| format!("Invalid location for {}: {:?}", self.symbol, err))?;
+        let metadata = AssetRegistryMetadata {
+            metadata: AssetStorageMetadata {
+                name: self.name.as_bytes().to_vec(),
+                symbol: self.symbol.as_bytes().to_vec(),
+                decimals: self.decimals,
+                is_frozen: self.is_frozen,
+            },
+            min_balance: self.min_balance,
+            is_sufficient: self.is_sufficient,
+        };
+        Ok(GenesisAsset {
+            location: location.encode(),
+            metadata: metadata.encode(),
+            units_per_second: self.units_per_second,
+            min_xcm_fee: self.min_xcm_fee,
+            mints: self
+                .mints
+                .iter()
+                .map(|mint| (mint.account.clone(), mint.amount))
+                .collect(),
+        })
+    }
+}
+
+/// Registry Mint
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RegistryMint {
+    /// SS58 encoded account receiving the mint
+    pub account: AccountId,
+
+    /// Amount to mint
+    #[serde(deserialize_with = "deserialize_balance")]
+    pub amount: Balance,
+}
+
+/// Assets without an explicit sufficiency flag are sufficient, so that fresh accounts can
+/// receive them over XCM.
+#[inline]
+fn default_is_sufficient() -> bool {
+    true
+}
+
+/// Balance Representation
+///
+/// TOML integers are limited to 64 bits, so balances may also be written as decimal strings.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BalanceRepr {
+    /// Integer Balance
+    Integer(u128),
+
+    /// Decimal String Balance
+    String(String),
+}
+
+impl BalanceRepr {
+    /// Parses `self` into a [`Balance`].
+    #[inline]
+    fn parse<E>(self) -> Result<Balance, E>
+    where
+        E: de::Error,
+    {
+        match self {
+            Self::Integer(balance) => Ok(balance),
+            Self::String(balance) => balance.replace('_', "").parse().map_err(E::custom),
+        }
+    }
+}
+
+/// Deserializes a [`Balance`] from either an integer or a decimal string.
+#[inline]
+fn deserialize_balance<'de, D>(deserializer: D) -> Result<Balance, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    BalanceRepr::deserialize(deserializer)?.parse()
+}
+
+/// Deserializes an optional [`Balance`] from either an integer or a decimal string.
+#[inline]
+fn deserialize_optional_balance<'de, D>(deserializer: D) -> Result<Option<Balance>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<BalanceRepr>::deserialize(deserializer)?
+        .map(BalanceRepr::parse)
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use xcm::latest::{Junction::Parachain, Junctions::X1, MultiLocation};
+
+    /// SS58 Encoding of the Alice Development Account
+    const ALICE: &str = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+
+    /// Parses a [`BalanceRepr`] from its JSON representation.
+    fn parse_balance(balance: &str) -> Result<Balance, serde_json::Error> {
+        serde_json::from_str::<BalanceRepr>(balance)?.parse()
+    }
+
+    /// Checks that `registry` holds the single sibling asset of the valid test registries.
+    fn assert_sibling_asset(registry: AssetRegistry) {
+        let assets = registry.genesis_assets().unwrap();
+        assert_eq!(assets.len(), 1);
+        let asset = &assets[0];
+        assert_eq!(
+            asset.location,
+            AssetLocation::from(MultiLocation::new(1, X1(Parachain(2000)))).encode()
+        );
+        assert_eq!(
+            asset.metadata,
+            AssetRegistryMetadata {
+                metadata: AssetStorageMetadata {
+                    name: b"Sibling".to_vec(),
+                    symbol: b"SIB".to_vec(),
+                    decimals: 12,
+                    is_frozen: false,
+                },
+                min_balance: u128::MAX,
+                is_sufficient: true,
+            }
+            .encode()
+        );
+        assert_eq!(asset.units_per_second, Some(1_000_000_000_000));
+        assert_eq!(asset.min_xcm_fee, None);
+        assert_eq!(
+            asset.mints,
+            vec![(ALICE.parse().unwrap(), 10_000_000_000_000)]
+        );
+    }
+
+    #[test]
+    fn json_registry_should_parse() {
+        let registry = AssetRegistry::from_json(
+            r#"{
+                "assets": [{
+                    "location": { "parents": 1, "interior": [{ "parachain": 2000 }] },
+                    "name": "Sibling",
+                    "symbol": "SIB",
+                    "decimals": 12,
+                    "minBalance": "340282366920938463463374607431768211455",
+                    "unitsPerSecond": 1000000000000,
+                    "mints": [{ "account": "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY", "amount": "10_000_000_000_000" }]
+                }]
+            }"#,
+        )
+        .unwrap();
+        assert_sibling_asset(registry);
+        assert!(AssetRegistry::from_json("{}").unwrap().assets.is_empty());
+    }
+
+    #[test]
+    fn malformed_json_registry_should_not_parse() {
+        // Unknown fields are rejected
+        assert!(AssetRegistry::from_json(r#"{ "asset": [] }"#).is_err());
+        // Missing metadata
+        assert!(AssetRegistry::from_json(
+            r#"{ "assets": [{ "location": { "parents": 1, "interior": [] }, "name": "Sibling" }] }"#
+        )
+        .is_err());
+        // Invalid balance
+        assert!(AssetRegistry::from_json(
+            r#"{
+                "assets": [{
+                    "location": { "parents": 1, "interior": [] },
+                    "name": "Sibling",
+                    "symbol": "SIB",
+                    "decimals": 12,
+                    "minBalance": "1e12"
+                }]
+            }"#
+        )
+        .is_err());
+        // Invalid account
+        assert!(AssetRegistry::from_json(
+            r#"{
+                "assets": [{
+                    "location": { "parents": 1, "interior": [] },
+                    "name": "Sibling",
+                    "symbol": "SIB",
+                    "decimals": 12,
+                    "minBalance": 1,
+                    "mints": [{ "account": "alice", "amount": 1 }]
+                }]
+            }"#
+        )
+        .is_err());
+        assert!(AssetRegistry::from_json("assets = []").is_err());
+    }
+
+    #[test]
+    fn toml_registry_should_parse() {
+        let registry = AssetRegistry::from_toml(
+            r#"
+            [[assets]]
+            location = { parents = 1, interior = [{ parachain = 2000 }] }
+            name = "Sibling"
+            symbol = "SIB"
+            decimals = 12
+            minBalance = "340_282_366_920_938_463_463_374_607_431_768_211_455"
+            unitsPerSecond = 1_000_000_000_000
+
+            [[assets.mints]]
+            account = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"
+            amount = 10_000_000_000_000
+            "#,
+        )
+        .unwrap();
+        assert_sibling_asset(registry);
+        assert!(AssetRegistry::from_toml("").unwrap().assets.is_empty());
+    }
+
+    #[test]
+    fn malformed_toml_registry_should_not_parse() {
+        let asset = |min_balance: &str| {
+            format!(
+                r#"
+                [[assets]]
+                location = {{ parents = 1, interior = [] }}
+                name = "Sibling"
+                symbol = "SIB"
+                decimals = 12
+                minBalance = {}
+                "#,
+                min_balance
+            )
+        };
+        assert!(AssetRegistry::from_toml(&asset("1")).is_ok());
+        // TOML integers are limited to 64 bits
+        assert!(
+            AssetRegistry::from_toml(&asset("340282366920938463463374607431768211455")).is_err()
+        );
+        assert!(AssetRegistry::from_toml(&asset("-1")).is_err());
+        assert!(AssetRegistry::from_toml(&asset("\"one\"")).is_err());
+        assert!(AssetRegistry::from_toml(&format!("{}\nisFungible = true", asset("1"))).is_err());
+        assert!(AssetRegistry::from_toml(r#"{ "assets": [] }"#).is_err());
+    }
+
+    #[test]
+    fn invalid_locations_should_be_rejected() {
+        let registry = AssetRegistry::from_json(
+            r#"{
+                "assets": [{
+                    "location": {
+                        "parents": 1,
+                        "interior": [
+                            { "parachain": 1 }, { "parachain": 2 }, { "parachain": 3 },
+                            { "parachain": 4 }, { "parachain": 5 }, { "parachain": 6 },
+                            { "parachain": 7 }, { "parachain": 8 }, { "parachain": 9 }
+                        ]
+                    },
+                    "name": "Sibling",
+                    "symbol": "SIB",
+                    "decimals": 12,
+                    "minBalance": 1
+                }]
+            }"#,
+        )
+        .unwrap();
+        assert!(registry.genesis_assets().is_err());
+    }
+
+    #[test]
+    fn registry_specs_should_split() {
+        assert_eq!(
+            split_registry_spec("calamari-dev:assets.toml"),
+            ("calamari-dev", Some("assets.toml"))
+        );
+        assert_eq!(
+            split_registry_spec("dolphin-localdev:/tmp/a:b.json"),
+            ("dolphin-localdev", Some("/tmp/a:b.json"))
+        );
+        assert_eq!(
+            split_registry_spec("dolphin-local:"),
+            ("dolphin-local", Some(""))
+        );
+        assert_eq!(split_registry_spec("calamari-dev"), ("calamari-dev", None));
+        // Other chain specifications and paths are kept as they are
+        assert_eq!(
+            split_registry_spec("manta-dev:assets.toml"),
+            ("manta-dev:assets.toml", None)
+        );
+        assert_eq!(
+            split_registry_spec("C:\\specs\\calamari.json"),
+            ("C:\\specs\\calamari.json", None)
+        );
+        assert_eq!(split_registry_spec("./spec.json"), ("./spec.json", None));
+    }
+
+    #[test]
+    fn balances_should_parse() {
+        assert_eq!(parse_balance("0").unwrap(), 0);
+        assert_eq!(parse_balance("1000").unwrap(), 1_000);
+        assert_eq!(parse_balance("\"1000\"").unwrap(), 1_000);
+        assert_eq!(parse_balance("\"1_000_000\"").unwrap(), 1_000_000);
+        assert_eq!(
+            parse_balance("\"340282366920938463463374607431768211455\"").unwrap(),
+            u128::MAX
+        );
+        assert!(parse_balance("\"340282366920938463463374607431768211456\"").is_err());
+        assert!(parse_balance("-1").is_err());
+        assert!(parse_balance("\"-1\"").is_err());
+        assert!(parse_balance("1.5").is_err());
+        assert!(parse_balance("\"1.5\"").is_err());
+        assert!(parse_balance("\"0x10\"").is_err());
+        assert!(parse_balance("\"\"").is_err());
+        assert!(parse_balance("null").is_err());
+    }
+}
@@ -52,7 +52,7 @@ pub fn calamari_properties() -> Properties {
 }
 
 /// Returns the Calamari development chainspec.
-pub fn calamari_development_config() -> CalamariChainSpec {
+pub fn calamari_development_config(genesis_assets: GenesisAssets) -> CalamariChainSpec {
     CalamariChainSpec::from_genesis(
         "Calamari Parachain Development",
         "calamari_dev",
@@ -71,6 +71,7 @@ pub fn calamari_development_config() -> CalamariChainSpec {
                     unchecked_account_id::<sr25519::Public>("Alice//stash"),
                     unchecked_account_id::<sr25519::Public>("Bob//stash"),
                 ],
+                genesis_assets.clone(),
             )
         },
         vec![],
@@ -86,7 +87,7 @@ pub fn calamari_development_config() -> CalamariChainSpec {
 }
 
 /// Returns the Calamari local chainspec.
-pub fn calamari_local_config(localdev: bool) -> CalamariChainSpec {
+pub fn calamari_local_config(localdev: bool, genesis_assets: GenesisAssets) -> CalamariChainSpec {
     let id = if localdev {
         "calamari_localdev"
     } else {
@@ -142,6 +143,7 @@ pub fn calamari_local_config(localdev: bool) -> CalamariChainSpec {
                     unchecked_account_id::<sr25519::Public>("Dave//stash"),
                     unchecked_account_id::<sr25519::Public>("Eve//stash"),
                 ],
+                genesis_assets.clone(),
             )
         },
         vec![],
@@ -161,6 +163,7 @@ fn calamari_dev_genesis(
 
     delegations: Vec<(AccountId, AccountId, Balance)>,
     endowed_accounts: Vec<AccountId>,
+    genesis_assets: GenesisAssets,
 ) -> GenesisConfig {
     GenesisConfig {
         system: calamari_runtime::SystemConfig {
@@ -230,7 +233,10 @@ fn calamari_dev_genesis(
         },
         council_membership: Default::default(),
         technical_membership: Default::default(),
-        asset_manager: Default::default(),
+        asset_manager: calamari_runtime::AssetManagerConfig {
+            assets: genesis_assets,
+            ..Default::default()
+        },
         parachain_system: Default::default(),
         polkadot_xcm: calamari_runtime::PolkadotXcmConfig {
             safe_xcm_version: Some(SAFE_XCM_VERSION),
@@ -47,7 +47,7 @@ pub fn dolphin_properties() -> Properties {
 }
 
 /// Returns the Dolphin development chainspec.
-pub fn dolphin_development_config() -> DolphinChainSpec {
+pub fn dolphin_development_config(genesis_assets: GenesisAssets) -> DolphinChainSpec {
     DolphinChainSpec::from_genesis(
         "Dolphin Parachain Development",
         "dolphin_dev",
@@ -65,6 +65,7 @@ pub fn dolphin_development_config() -> DolphinChainSpec {
                     unchecked_account_id::<sr25519::Public>("Alice//stash"),
                     unchecked_account_id::<sr25519::Public>("Bob//stash"),
                 ],
+                genesis_assets.clone(),
             )
         },
         vec![],
@@ -80,7 +81,7 @@ pub fn dolphin_development_config() -> DolphinChainSpec {
 }
 
 /// Returns the Dolphin local chainspec.
-pub fn dolphin_local_config(localdev: bool) -> DolphinChainSpec {
+pub fn dolphin_local_config(localdev: bool, genesis_assets: GenesisAssets) -> DolphinChainSpec {
     let id = if localdev {
         "dolphin_localdev"
     } else {
@@ -135,6 +136,7 @@ pub fn dolphin_local_config(localdev: bool) -> DolphinChainSpec {
                     unchecked_account_id::<sr25519::Public>("Dave//stash"),
                     unchecked_account_id::<sr25519::Public>("Eve//stash"),
                 ],
+                genesis_assets.clone(),
             )
         },
         vec![],
@@ -153,6 +155,7 @@ fn dolphin_dev_genesis(
     invulnerables: Vec<(AccountId, SessionKeys)>,
     root_key: AccountId,
     endowed_accounts: Vec<AccountId>,
+    genesis_assets: GenesisAssets,
 ) -> GenesisConfig {
     GenesisConfig {
         system: dolphin_runtime::SystemConfig {
@@ -207,7 +210,10 @@ fn dolphin_dev_genesis(
             members: endowed_accounts.iter().take(1).cloned().collect(),
             phantom: Default::default(),
         },
-        asset_manager: Default::default(),
+        asset_manager: dolphin_runtime::AssetManagerConfig {
+            assets: genesis_assets,
+            ..Default::default()
+        },
         council_membership: Default::default(),
         technical_membership: Default::default(),
         parachain_system: Default::default(),
@@ -30,11 +30,12 @@ use sc_service::{ChainType, Properties};
 use serde::{Deserialize, Serialize};
 use sp_core::sr25519;
 
+pub mod asset_registry;
 pub mod calamari;
 pub mod dolphin;
 pub mod manta;
 
-pub use self::{calamari::*, dolphin::*, manta::*};
+pub use self::{asset_registry::*, calamari::*, dolphin::*, manta::*};
 pub use calamari_runtime::currency::KMA;
 pub use dolphin_runtime::currency::DOL;
 pub use manta_runtime::currency::MANTA;
@@ -98,6 +98,11 @@ impl<T: sc_service::ChainSpec + 'static> IdentifyChain for T {
 }
 
 fn load_spec(id: &str) -> Result<Box<dyn sc_service::ChainSpec>, String> {
+    let (id, registry) = chain_specs::split_registry_spec(id);
+    let genesis_assets = match registry {
+        Some(registry) => chain_specs::AssetRegistry::from_file(registry)?.genesis_assets()?,
+        _ => Vec::new(),
+    };
     match id {
         // manta chainspec
         "manta-dev" => Ok(Box::new(chain_specs::manta_development_config())),
@@ -105,15 +110,31 @@ fn load_spec(id: &str) -> Result<Box<dyn sc_service::ChainSpec>, String> {
         "manta-testnet" => Ok(Box::new(chain_specs::manta_testnet_config()?)),
         "manta" => Ok(Box::new(chain_specs::manta_config()?)),
         // calamari chainspec
-        "calamari-dev" => Ok(Box::new(chain_specs::calamari_development_config())),
-        "calamari-local" => Ok(Box::new(chain_specs::calamari_local_config(false))),
-        "calamari-localdev" => Ok(Box::new(chain_specs::calamari_local_config(true))),
+        "calamari-dev" => Ok(Box::new(chain_specs::calamari_development_config(
+            genesis_assets,
+        ))),
+        "calamari-local" => Ok(Box::new(chain_specs::calamari_local_config(
+            false,
+            genesis_assets,
+        ))),
+        "calamari-localdev" => Ok(Box::new(chain_specs::calamari_local_config(
+            true,
+            genesis_assets,
+        ))),
         "calamari-testnet" => Ok(Box::new(chain_specs::calamari_testnet_config()?)),
         "calamari" => Ok(Box::new(chain_specs::calamari_config()?)),
         // dolphin chainspec
-        "dolphin-dev" => Ok(Box::new(chain_specs::dolphin_development_config())),
-        "dolphin-local" => Ok(Box::new(chain_specs::dolphin_local_config(false))),
-        "dolphin-localdev" => Ok(Box::new(chain_specs::dolphin_local_config(true))),
+        "dolphin-dev" => Ok(Box::new(chain_specs::dolphin_development_config(
+            genesis_assets,
+        ))),
+        "dolphin-local" => Ok(Box::new(chain_specs::dolphin_local_config(
+            false,
+            genesis_assets,
+        ))),
+        "dolphin-localdev" => Ok(Box::new(chain_specs::dolphin_local_config(
+            true,
+            genesis_assets,
+        ))),
         "dolphin-testnet" => Ok(Box::new(chain_specs::dolphin_testnet_config()?)),
         "dolphin-2085" => Ok(Box::new(chain_specs::dolphin_2085_config()?)),
         "dolphin-v3-staging" => Ok(Box::new(chain_specs::dolphin_v3_2085_staging_config()?)),
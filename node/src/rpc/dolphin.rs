@@ -17,9 +17,17 @@
 //! Dolphin RPC Extensions
 
 use super::*;
+use manta_primitives::{
+    assets::{AssetLocation, AssetRegistryMetadata},
+    types::DolphinAssetId,
+};
+use pallet_asset_manager::{
+    rpc::{Registry, RegistryApiServer},
+    runtime::{AssetDestroyApi, AssetRegistryApi},
+};
 use pallet_manta_pay::{
-    rpc::{Pull, PullApiServer},
-    runtime::PullLedgerDiffApi,
+    rpc::{Pull, PullApiServer, Snapshot, SnapshotApiServer},
+    runtime::{ForestSnapshotApi, PullLedgerDiffApi},
 };
 
 /// Instantiate all RPC extensions for dolphin.
@@ -36,6 +44,9 @@ where
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: BlockBuilder<Block>,
     C::Api: PullLedgerDiffApi<Block>,
+    C::Api: ForestSnapshotApi<Block>,
+    C::Api: AssetRegistryApi<Block, DolphinAssetId, AssetLocation, AssetRegistryMetadata<Balance>>,
+    C::Api: AssetDestroyApi<Block, DolphinAssetId, Balance>,
     P: TransactionPool + Sync + Send + 'static,
 {
     use frame_rpc_system::{System, SystemApiServer};
@@ -55,10 +66,22 @@ where
         .merge(TransactionPayment::new(client.clone()).into_rpc())
         .map_err(|e| sc_service::Error::Other(e.to_string()))?;
 
-    let manta_pay_rpc: jsonrpsee::RpcModule<Pull<Block, C>> = Pull::new(client).into_rpc();
+    let manta_pay_rpc: jsonrpsee::RpcModule<Pull<Block, C>> = Pull::new(client.clone()).into_rpc();
     module
         .merge(manta_pay_rpc)
         .map_err(|e| sc_service::Error::Other(e.to_string()))?;
 
+    let manta_pay_snapshot_rpc: jsonrpsee::RpcModule<Snapshot<Block, C>> =
+        Snapshot::new(client.clone()).into_rpc();
+    module
+        .merge(manta_pay_snapshot_rpc)
+        .map_err(|e| sc_service::Error::Other(e.to_string()))?;
+
+    let asset_registry_rpc: jsonrpsee::RpcModule<Registry<Block, C>> =
+        RegistryApiServer::<_, DolphinAssetId>::into_rpc(Registry::new(client));
+    module
+        .merge(asset_registry_rpc)
+        .map_err(|e| sc_service::Error::Other(e.to_string()))?;
+
     Ok(module)
 }
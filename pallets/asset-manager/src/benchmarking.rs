@@ -16,11 +16,15 @@
 
 #![cfg(feature = "runtime-benchmarks")]
 
-use crate::{Call, Config, Event, Pallet};
+use crate::{Call, Config, DepositBalanceOf, DestinationPolicy, Event, Pallet};
 use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
-use frame_support::traits::Get;
+use frame_support::traits::{
+    tokens::nonfungibles::{Create, Mutate},
+    Currency, EnsureOrigin, Get,
+};
 use frame_system::{EventRecord, RawOrigin};
-use manta_primitives::assets::{AssetConfig, TestingDefault, UnitsPerSecond};
+use manta_primitives::assets::{AssetConfig, AssetStatus, TestingDefault, UnitsPerSecond};
+use sp_runtime::traits::{Bounded, One};
 use xcm::latest::prelude::*;
 
 fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
@@ -30,8 +34,18 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
     assert_eq!(event, &system_event);
 }
 
+fn sibling_location<T: Config>(para_id: u32, index: u128) -> T::Location {
+    MultiLocation::new(1, X2(Parachain(para_id), GeneralIndex(index))).into()
+}
+
+fn funded_proposer<T: Config>() -> T::AccountId {
+    let caller: T::AccountId = whitelisted_caller();
+    T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T>::max_value() / 2u32.into());
+    caller
+}
+
 benchmarks! {
-    where_clause { where T::Location: From<MultiLocation>, <T as Config>::Balance: From<u32>, <T as Config>::AssetId: From<u32> }
+    where_clause { where T::Location: From<MultiLocation>, <T as Config>::Balance: From<u32>, <T as Config>::AssetId: From<u32>, T::CollectionId: From<u32>, T::ItemId: From<u32>, T::NonFungibles: Create<T::AccountId> + Mutate<T::AccountId> }
 
     register_asset {
         let location = T::Location::default();
@@ -139,6 +153,199 @@ benchmarks! {
     verify {
         assert_eq!(Pallet::<T>::get_min_xcm_fee(location), Some(min_xcm_fee));
     }
+
+    register_non_fungible_asset {
+        let owner: T::AccountId = whitelisted_caller();
+        let collection = T::CollectionId::from(0);
+        let item = T::ItemId::from(0);
+        T::NonFungibles::create_collection(&collection, &Pallet::<T>::account_id(), &Pallet::<T>::account_id())?;
+        T::NonFungibles::mint_into(&collection, &item, &owner)?;
+    }: _(RawOrigin::Root, collection, item)
+    verify {
+        assert_eq!(Pallet::<T>::asset_id_non_fungible(<T::AssetConfig as AssetConfig<T>>::StartNonNativeAssetId::get()), Some((collection, item)));
+    }
+
+    freeze_asset {
+        let location = T::Location::default();
+        let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::testing_default();
+        Pallet::<T>::register_asset(RawOrigin::Root.into(), location, metadata)?;
+        let asset_id = <T::AssetConfig as AssetConfig<T>>::StartNonNativeAssetId::get();
+    }: _(RawOrigin::Root, asset_id)
+    verify {
+        assert_last_event::<T>(Event::AssetFrozen { asset_id }.into());
+    }
+
+    destroy_asset {
+        let location: MultiLocation = MultiLocation::new(1, X1(Parachain(1000)));
+        let location = T::Location::from(location);
+        let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::testing_default();
+        Pallet::<T>::register_asset(RawOrigin::Root.into(), location.clone(), metadata)?;
+        let asset_id = <T::AssetConfig as AssetConfig<T>>::StartNonNativeAssetId::get();
+        Pallet::<T>::set_units_per_second(RawOrigin::Root.into(), asset_id, 10)?;
+        Pallet::<T>::freeze_asset(RawOrigin::Root.into(), asset_id)?;
+    }: _(RawOrigin::Root, asset_id)
+    verify {
+        assert_last_event::<T>(Event::AssetDestroyed { asset_id, location }.into());
+    }
+
+    set_asset_status {
+        let location = T::Location::default();
+        let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::testing_default();
+        Pallet::<T>::register_asset(RawOrigin::Root.into(), location, metadata)?;
+        let asset_id = <T::AssetConfig as AssetConfig<T>>::StartNonNativeAssetId::get();
+        let status = AssetStatus::Deprecated;
+    }: _(RawOrigin::Root, asset_id, status)
+    verify {
+        assert_last_event::<T>(Event::AssetStatusUpdated { asset_id, status }.into());
+    }
+
+    add_location_alias {
+        let location = T::Location::default();
+        let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::testing_default();
+        Pallet::<T>::register_asset(RawOrigin::Root.into(), location, metadata)?;
+        let asset_id = <T::AssetConfig as AssetConfig<T>>::StartNonNativeAssetId::get();
+        let alias = T::Location::from(MultiLocation::new(1, X1(Parachain(1000))));
+        let cutover = Some(10u32.into());
+    }: _(RawOrigin::Root, asset_id, alias.clone(), cutover)
+    verify {
+        assert_last_event::<T>(
+            Event::LocationAliasAdded { asset_id, location: alias, cutover }.into()
+        );
+    }
+
+    remove_location_alias {
+        let location = T::Location::default();
+        let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::testing_default();
+        Pallet::<T>::register_asset(RawOrigin::Root.into(), location, metadata)?;
+        let asset_id = <T::AssetConfig as AssetConfig<T>>::StartNonNativeAssetId::get();
+        let alias = T::Location::from(MultiLocation::new(1, X1(Parachain(1000))));
+        Pallet::<T>::add_location_alias(RawOrigin::Root.into(), asset_id, alias.clone(), None)?;
+    }: _(RawOrigin::Root, alias.clone())
+    verify {
+        assert_last_event::<T>(Event::LocationAliasRemoved { asset_id, location: alias }.into());
+    }
+
+    propose_asset {
+        let caller = funded_proposer::<T>();
+        let location = T::Location::default();
+        let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::testing_default();
+    }: _(RawOrigin::Signed(caller.clone()), location.clone(), metadata)
+    verify {
+        assert_last_event::<T>(Event::AssetProposed { proposal_id: 0, proposer: caller, location }.into());
+    }
+
+    approve_asset_proposal {
+        let caller = funded_proposer::<T>();
+        let location = T::Location::default();
+        let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::testing_default();
+        Pallet::<T>::propose_asset(RawOrigin::Signed(caller).into(), location, metadata)?;
+        let asset_id = <T::AssetConfig as AssetConfig<T>>::StartNonNativeAssetId::get();
+    }: _(RawOrigin::Root, 0)
+    verify {
+        assert_last_event::<T>(Event::AssetProposalApproved { proposal_id: 0, asset_id }.into());
+    }
+
+    reject_asset_proposal {
+        let caller = funded_proposer::<T>();
+        let location = T::Location::default();
+        let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::testing_default();
+        Pallet::<T>::propose_asset(RawOrigin::Signed(caller).into(), location, metadata)?;
+    }: _(RawOrigin::Root, 0, true)
+    verify {
+        assert_last_event::<T>(Event::AssetProposalRejected { proposal_id: 0, slashed: true }.into());
+    }
+
+    remove_expired_proposal {
+        let caller = funded_proposer::<T>();
+        let location = T::Location::default();
+        let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::testing_default();
+        Pallet::<T>::propose_asset(RawOrigin::Signed(caller.clone()).into(), location, metadata)?;
+        let expired = frame_system::Pallet::<T>::block_number() + T::ProposalLifetime::get() + 1u32.into();
+        frame_system::Pallet::<T>::set_block_number(expired);
+    }: _(RawOrigin::Signed(caller), 0)
+    verify {
+        assert_last_event::<T>(Event::AssetProposalExpired { proposal_id: 0 }.into());
+    }
+
+    request_sibling_registration {
+        let origin = T::SiblingOrigin::successful_origin();
+        let para_id = T::SiblingOrigin::ensure_origin(origin.clone()).unwrap();
+        let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::testing_default();
+        for index in 1..T::MaxSiblingRequests::get() {
+            Pallet::<T>::request_sibling_registration(
+                origin.clone(),
+                sibling_location::<T>(para_id, index.into()),
+                metadata.clone(),
+            )?;
+        }
+        let location = sibling_location::<T>(para_id, 0);
+    }: _<T::Origin>(origin, location.clone(), metadata)
+    verify {
+        assert_last_event::<T>(Event::SiblingRegistrationRequested { para_id, location }.into());
+    }
+
+    approve_sibling_registration {
+        let origin = T::SiblingOrigin::successful_origin();
+        let para_id = T::SiblingOrigin::ensure_origin(origin.clone()).unwrap();
+        let location = sibling_location::<T>(para_id, 0);
+        let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::testing_default();
+        Pallet::<T>::request_sibling_registration(origin, location.clone(), metadata)?;
+        let asset_id = Pallet::<T>::next_asset_id();
+    }: _(RawOrigin::Root, location)
+    verify {
+        assert_last_event::<T>(Event::SiblingRegistrationApproved { para_id, asset_id }.into());
+    }
+
+    reject_sibling_registration {
+        let origin = T::SiblingOrigin::successful_origin();
+        let para_id = T::SiblingOrigin::ensure_origin(origin.clone()).unwrap();
+        let location = sibling_location::<T>(para_id, 0);
+        let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::testing_default();
+        Pallet::<T>::request_sibling_registration(origin, location.clone(), metadata)?;
+    }: _(RawOrigin::Root, location.clone())
+    verify {
+        assert_last_event::<T>(Event::SiblingRegistrationRejected { para_id, location }.into());
+    }
+
+    set_mint_cap {
+        let location = T::Location::default();
+        let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::testing_default();
+        Pallet::<T>::register_asset(RawOrigin::Root.into(), location, metadata)?;
+        let asset_id = Pallet::<T>::next_asset_id() - One::one();
+        let cap = Some(<T as Config>::Balance::from(1_000u32));
+        let origin = T::MintCapOrigin::successful_origin();
+    }: _<T::Origin>(origin, asset_id, cap.clone())
+    verify {
+        assert_last_event::<T>(Event::MintCapUpdated { asset_id, cap }.into());
+    }
+
+    burn_asset {
+        let location = T::Location::default();
+        let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::testing_default();
+        Pallet::<T>::register_asset(RawOrigin::Root.into(), location, metadata)?;
+        let asset_id = Pallet::<T>::next_asset_id() - One::one();
+        let who: T::AccountId = whitelisted_caller();
+        Pallet::<T>::mint_asset(RawOrigin::Root.into(), asset_id, who.clone(), <T as Config>::Balance::from(100u32))?;
+        let amount = <T as Config>::Balance::from(50u32);
+    }: _(RawOrigin::Root, asset_id, who.clone(), amount.clone())
+    verify {
+        assert_last_event::<T>(Event::AssetBurned { asset_id, who, amount }.into());
+    }
+
+    set_destination_policy {
+        let location = T::Location::from(MultiLocation::new(1, X1(Parachain(2015))));
+        let policy = Some(DestinationPolicy::Deny);
+    }: _(RawOrigin::Root, location.clone(), policy)
+    verify {
+        assert_last_event::<T>(Event::DestinationPolicyUpdated { location, policy }.into());
+    }
+
+    set_teleport_trust {
+        let location = T::Location::from(MultiLocation::new(1, X1(Parachain(1000))));
+    }: _(RawOrigin::Root, location.clone(), true)
+    verify {
+        assert_last_event::<T>(Event::TeleportTrustUpdated { location, trusted: true }.into());
+    }
 }
 
 impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
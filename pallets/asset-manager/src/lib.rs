@@ -31,6 +31,12 @@ mod benchmarking;
 pub mod migrations;
 pub mod weights;
 
+#[cfg(feature = "rpc")]
+pub mod rpc;
+
+#[cfg(feature = "runtime")]
+pub mod runtime;
+
 #[cfg(test)]
 mod mock;
 
@@ -46,25 +52,31 @@ pub mod pallet {
     use crate::weights::WeightInfo;
     use frame_support::{
         pallet_prelude::*,
-        traits::{Contains, StorageVersion},
+        traits::{
+            tokens::nonfungibles, Contains, Currency, ExistenceRequirement, OnUnbalanced,
+            ReservableCurrency, StorageVersion,
+        },
         transactional, PalletId,
     };
     use frame_system::pallet_prelude::*;
     use manta_primitives::assets::{
         self, AssetConfig, AssetIdLocationMap, AssetIdType, AssetMetadata, AssetRegistry,
-        FungibleLedger, LocationType,
+        AssetStatus, AssetStatusMap, FungibleLedger, LocationType, NonFungibleAssetMap,
+        NormalizeLocation,
     };
     use orml_traits::GetByKey;
     use sp_runtime::{
         traits::{
             AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, MaybeSerializeDeserialize, One,
+            Saturating, Zero,
         },
         ArithmeticError,
     };
+    use sp_std::{collections::btree_map::BTreeMap, vec, vec::Vec};
     use xcm::latest::prelude::*;
 
     /// Storage Version
-    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
     /// Alias for the junction type `Parachain(#[codec(compact)] u32)`
     pub(crate) type ParaId = u32;
@@ -72,6 +84,172 @@ pub mod pallet {
     /// Asset Count Type
     pub(crate) type AssetCount = u32;
 
+    /// Registration Proposal Id Type
+    pub type ProposalId = u32;
+
+    /// Deposit Balance Type
+    pub type DepositBalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// Negative Imbalance Type of the Deposit Currency
+    pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+        <T as frame_system::Config>::AccountId,
+    >>::NegativeImbalance;
+
+    /// Asset Registration Proposal
+    #[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+    pub struct RegistrationProposal<AccountId, Location, Metadata, Balance, BlockNumber> {
+        /// Account which made the proposal and reserved the deposit
+        pub proposer: AccountId,
+
+        /// Location of the proposed asset
+        pub location: Location,
+
+        /// Metadata of the proposed asset
+        pub metadata: Metadata,
+
+        /// Reserved deposit
+        pub deposit: Balance,
+
+        /// Last block at which the proposal can be approved
+        pub deadline: BlockNumber,
+    }
+
+    /// Registration Proposal Type
+    pub type RegistrationProposalOf<T> = RegistrationProposal<
+        <T as frame_system::Config>::AccountId,
+        <T as Config>::Location,
+        <<T as Config>::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata,
+        DepositBalanceOf<T>,
+        <T as frame_system::Config>::BlockNumber,
+    >;
+
+    /// Asset Registry Entry
+    ///
+    /// Everything the asset manager knows about a single registered asset.
+    #[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+    pub struct AssetRegistryEntry<AssetId, Location, Metadata> {
+        /// Asset Id
+        pub asset_id: AssetId,
+
+        /// Location of the asset, if any
+        pub location: Option<Location>,
+
+        /// Metadata of the asset
+        pub metadata: Metadata,
+
+        /// XCM transfer cost of the asset, if set
+        pub units_per_second: Option<u128>,
+
+        /// Minimum XCM execution fee paid on the reserve chain of the asset, if set
+        pub min_xcm_fee: Option<u128>,
+
+        /// Parachain the asset is located at, if any
+        pub para_id: Option<ParaId>,
+
+        /// Number of assets associated to `para_id`, which allows it as a transfer destination
+        pub para_asset_count: Option<AssetCount>,
+    }
+
+    /// Asset Registry Entry Type
+    pub type AssetRegistryEntryOf<T> = AssetRegistryEntry<
+        <T as Config>::AssetId,
+        <T as Config>::Location,
+        <<T as Config>::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata,
+    >;
+
+    /// Genesis Asset
+    ///
+    /// An asset registered at genesis. Its location and metadata are SCALE encoded since their
+    /// types are not serializable.
+    #[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+    #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+    pub struct GenesisAsset<AccountId, Balance> {
+        /// SCALE encoded location of the asset
+        pub location: Vec<u8>,
+
+        /// SCALE encoded metadata of the asset
+        pub metadata: Vec<u8>,
+
+        /// XCM transfer cost of the asset
+        pub units_per_second: Option<u128>,
+
+        /// Minimum XCM execution fee paid on the reserve chain of the asset
+        pub min_xcm_fee: Option<u128>,
+
+        /// Initial balances minted to their accounts, recorded as governance mints
+        pub mints: Vec<(AccountId, Balance)>,
+    }
+
+    /// Asset Destruction Blocker
+    ///
+    /// Reports why an asset cannot be destroyed yet.
+    #[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+    #[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+    #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+    pub enum AssetDestroyBlocker<B> {
+        /// The asset is not registered.
+        UnknownAsset,
+
+        /// The native asset can never be destroyed.
+        NativeAsset,
+
+        /// The asset has to be frozen before it can be destroyed.
+        NotFrozen,
+
+        /// Some supply of the asset is still outstanding.
+        OutstandingSupply(B),
+
+        /// Some accounts still hold the asset.
+        OutstandingAccounts(u32),
+
+        /// Some approvals still spend the asset.
+        OutstandingApprovals(u32),
+    }
+
+    /// Destination Policy
+    ///
+    /// Governance decision on outbound XCM transfers to a destination chain or location.
+    #[derive(Clone, Copy, Debug, Decode, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+    pub enum DestinationPolicy {
+        /// Transfers to the destination are allowed.
+        Allow,
+
+        /// Transfers to the destination are denied.
+        Deny,
+    }
+
+    /// Mint Accounting
+    ///
+    /// Supply cap and cumulative totals of the governance mints and burns of an asset.
+    #[derive(Clone, Debug, Decode, Default, Encode, Eq, PartialEq, TypeInfo)]
+    pub struct MintAccounting<B> {
+        /// Maximum outstanding supply minted by governance, if any
+        pub cap: Option<B>,
+
+        /// Total amount minted by governance
+        pub minted: B,
+
+        /// Total amount of the supply minted by governance which was burned by governance
+        ///
+        /// A burn only counts against the governance mints for the part of it that the remaining
+        /// supply of the asset can no longer cover, so that burning supply received over XCM never
+        /// frees up room under the cap.
+        pub burned: B,
+    }
+
+    impl<B> MintAccounting<B>
+    where
+        B: Clone + Saturating,
+    {
+        /// Returns the supply minted by governance which has not been burned yet.
+        #[inline]
+        pub fn outstanding(&self) -> B {
+            self.minted.clone().saturating_sub(self.burned.clone())
+        }
+    }
+
     /// Pallet Configuration
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -87,14 +265,23 @@ pub mod pallet {
             + Copy;
 
         /// Balance Type
-        type Balance: Default + Member + Parameter + TypeInfo;
+        type Balance: CheckedAdd
+            + Default
+            + MaybeSerializeDeserialize
+            + Member
+            + Ord
+            + Parameter
+            + Saturating
+            + TypeInfo
+            + Zero;
 
         /// Location Type
         type Location: Default
             + Parameter
             + TypeInfo
             + From<MultiLocation>
-            + Into<Option<MultiLocation>>;
+            + Into<Option<MultiLocation>>
+            + NormalizeLocation;
 
         /// Asset Configuration
         type AssetConfig: AssetConfig<
@@ -104,10 +291,56 @@ pub mod pallet {
             Location = Self::Location,
         >;
 
+        /// Non-Fungible Collection Id Type
+        type CollectionId: Parameter + Copy;
+
+        /// Non-Fungible Item Id Type
+        type ItemId: Parameter + Copy;
+
+        /// Non-Fungible Assets
+        ///
+        /// The ledger of non-fungible items which can be registered as assets with a value of one.
+        type NonFungibles: nonfungibles::Inspect<
+            Self::AccountId,
+            CollectionId = Self::CollectionId,
+            ItemId = Self::ItemId,
+        >;
+
         /// The origin which may forcibly create or destroy an asset or otherwise alter privileged
         /// attributes.
         type ModifierOrigin: EnsureOrigin<Self::Origin>;
 
+        /// The origin which may set the mint caps of assets. This should be distinct from and
+        /// stricter than the `ModifierOrigin`, which mints within those caps, so that the caps
+        /// cannot be lifted by the origin they limit.
+        type MintCapOrigin: EnsureOrigin<Self::Origin>;
+
+        /// Maximum number of alias locations an asset can have next to its canonical location.
+        #[pallet::constant]
+        type MaxLocationAliases: Get<u32>;
+
+        /// Currency used for the deposits of registration proposals.
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// Deposit reserved from the proposer of an asset registration.
+        #[pallet::constant]
+        type ProposalDeposit: Get<DepositBalanceOf<Self>>;
+
+        /// Number of blocks during which a registration proposal can be approved or rejected.
+        #[pallet::constant]
+        type ProposalLifetime: Get<Self::BlockNumber>;
+
+        /// Handler for the deposits of rejected registration proposals which are slashed.
+        type OnProposalSlash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+        /// The origin of sibling parachains, which may request the registration of assets located
+        /// in their own interior.
+        type SiblingOrigin: EnsureOrigin<Self::Origin, Success = ParaId>;
+
+        /// Maximum number of pending registration requests of a single sibling parachain.
+        #[pallet::constant]
+        type MaxSiblingRequests: Get<u32>;
+
         /// Pallet ID
         type PalletId: Get<PalletId>;
 
@@ -146,7 +379,37 @@ pub mod pallet {
 
         #[inline]
         fn asset_id(location: &Self::Location) -> Option<Self::AssetId> {
-            LocationAssetId::<T>::get(location)
+            let location = location.clone().normalize();
+            LocationAssetId::<T>::get(&location).or_else(|| Self::alias_asset_id(&location))
+        }
+    }
+
+    impl<T> NonFungibleAssetMap for Pallet<T>
+    where
+        T: Config,
+    {
+        type CollectionId = T::CollectionId;
+        type ItemId = T::ItemId;
+
+        #[inline]
+        fn non_fungible(asset_id: &Self::AssetId) -> Option<(Self::CollectionId, Self::ItemId)> {
+            AssetIdNonFungible::<T>::get(asset_id)
+        }
+    }
+
+    impl<T> AssetStatusMap for Pallet<T>
+    where
+        T: Config,
+    {
+        #[inline]
+        fn status(asset_id: &Self::AssetId) -> AssetStatus {
+            AssetIdStatus::<T>::get(asset_id)
+        }
+
+        #[inline]
+        fn is_registered(asset_id: &Self::AssetId) -> bool {
+            AssetIdLocation::<T>::contains_key(asset_id)
+                || AssetIdNonFungible::<T>::contains_key(asset_id)
         }
     }
 
@@ -164,6 +427,7 @@ pub mod pallet {
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub start_id: T::AssetId,
+        pub assets: Vec<GenesisAsset<T::AccountId, T::Balance>>,
     }
 
     #[cfg(feature = "std")]
@@ -172,6 +436,7 @@ pub mod pallet {
         fn default() -> Self {
             Self {
                 start_id: <T::AssetConfig as AssetConfig<T>>::StartNonNativeAssetId::get(),
+                assets: Vec::new(),
             }
         }
     }
@@ -187,6 +452,34 @@ pub mod pallet {
             AssetIdLocation::<T>::insert(asset_id, &location);
             AssetIdMetadata::<T>::insert(asset_id, &metadata);
             LocationAssetId::<T>::insert(&location, asset_id);
+            for asset in &self.assets {
+                let location = T::Location::decode(&mut asset.location.as_slice())
+                    .expect("Genesis asset locations must be SCALE encoded.");
+                let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::decode(
+                    &mut asset.metadata.as_slice(),
+                )
+                .expect("Genesis asset metadata must be SCALE encoded.");
+                let asset_id = Pallet::<T>::do_register_asset(location.clone(), metadata)
+                    .expect("Genesis assets must have distinct locations.");
+                if let Some(units_per_second) = asset.units_per_second {
+                    UnitsPerSecond::<T>::insert(asset_id, units_per_second);
+                }
+                if let Some(min_xcm_fee) = asset.min_xcm_fee {
+                    MinXcmFee::<T>::insert(&location, min_xcm_fee);
+                }
+                for (account, amount) in &asset.mints {
+                    let minted =
+                        <T::AssetConfig as AssetConfig<T>>::FungibleLedger::deposit_minting(
+                            asset_id,
+                            account,
+                            amount.clone(),
+                        );
+                    assert!(minted.is_ok(), "Genesis asset mints must succeed.");
+                    AssetMintAccounting::<T>::mutate(asset_id, |accounting| {
+                        accounting.minted = accounting.minted.clone().saturating_add(amount.clone())
+                    });
+                }
+            }
         }
     }
 
@@ -253,6 +546,165 @@ pub mod pallet {
             /// Updated Minimum XCM Fee
             min_xcm_fee: u128,
         },
+
+        /// An asset was frozen
+        AssetFrozen {
+            /// Asset Id of the frozen Asset
+            asset_id: T::AssetId,
+        },
+
+        /// An asset was destroyed and removed from the asset manager
+        AssetDestroyed {
+            /// Asset Id of the destroyed Asset
+            asset_id: T::AssetId,
+
+            /// Location of the destroyed Asset
+            location: T::Location,
+        },
+
+        /// A non-fungible item was registered as a new asset
+        NonFungibleAssetRegistered {
+            /// Asset Id of new Asset
+            asset_id: T::AssetId,
+
+            /// Collection of the Item
+            collection: T::CollectionId,
+
+            /// Registered Item
+            item: T::ItemId,
+        },
+
+        /// An alias location was added to an asset
+        LocationAliasAdded {
+            /// Asset Id of the aliased Asset
+            asset_id: T::AssetId,
+
+            /// Alias Location
+            location: T::Location,
+
+            /// Block at which the alias stops being accepted
+            cutover: Option<T::BlockNumber>,
+        },
+
+        /// An alias location was removed from an asset
+        LocationAliasRemoved {
+            /// Asset Id of the aliased Asset
+            asset_id: T::AssetId,
+
+            /// Removed Alias Location
+            location: T::Location,
+        },
+
+        /// An asset registration was proposed
+        AssetProposed {
+            /// Id of the Proposal
+            proposal_id: ProposalId,
+
+            /// Account which made the Proposal
+            proposer: T::AccountId,
+
+            /// Location of the proposed Asset
+            location: T::Location,
+        },
+
+        /// An asset registration proposal was approved and the asset was registered
+        AssetProposalApproved {
+            /// Id of the Proposal
+            proposal_id: ProposalId,
+
+            /// Asset Id of the registered Asset
+            asset_id: T::AssetId,
+        },
+
+        /// An asset registration proposal was rejected
+        AssetProposalRejected {
+            /// Id of the Proposal
+            proposal_id: ProposalId,
+
+            /// Whether the deposit was slashed or returned
+            slashed: bool,
+        },
+
+        /// An asset registration proposal expired and its deposit was returned
+        AssetProposalExpired {
+            /// Id of the Proposal
+            proposal_id: ProposalId,
+        },
+
+        /// A sibling parachain requested the registration of one of its assets
+        SiblingRegistrationRequested {
+            /// Para Id of the requesting Sibling
+            para_id: ParaId,
+
+            /// Location of the requested Asset
+            location: T::Location,
+        },
+
+        /// A sibling registration request was approved and its asset registered
+        SiblingRegistrationApproved {
+            /// Para Id of the requesting Sibling
+            para_id: ParaId,
+
+            /// Asset Id of the registered Asset
+            asset_id: T::AssetId,
+        },
+
+        /// A sibling registration request was rejected
+        SiblingRegistrationRejected {
+            /// Para Id of the requesting Sibling
+            para_id: ParaId,
+
+            /// Location of the rejected Asset
+            location: T::Location,
+        },
+
+        /// Updated the lifecycle status of an asset
+        AssetStatusUpdated {
+            /// Asset Id of the updated Asset
+            asset_id: T::AssetId,
+
+            /// Updated Status of the Asset
+            status: AssetStatus,
+        },
+
+        /// Updated the mint cap of an asset
+        MintCapUpdated {
+            /// Asset Id of the updated Asset
+            asset_id: T::AssetId,
+
+            /// Updated Mint Cap, if any
+            cap: Option<T::Balance>,
+        },
+
+        /// Updated the policy of an outbound XCM transfer destination
+        DestinationPolicyUpdated {
+            /// Destination Chain or Location
+            location: T::Location,
+
+            /// Updated Policy, if any
+            policy: Option<DestinationPolicy>,
+        },
+
+        /// An asset was burned
+        AssetBurned {
+            /// Asset Id of the burned Asset
+            asset_id: T::AssetId,
+
+            /// Account the Asset was burned from
+            who: T::AccountId,
+
+            /// Amount Burned
+            amount: T::Balance,
+        },
+
+        /// Updated the teleport trust of a location for the native asset
+        TeleportTrustUpdated {
+            /// Trusted or Untrusted Location
+            location: T::Location,
+
+            /// Whether teleports of the native asset from the location are accepted
+            trusted: bool,
+        },
     }
 
     /// Asset Manager Error
@@ -278,6 +730,90 @@ pub mod pallet {
 
         /// An error occurred while updating the parachain id.
         UpdateParaIdError,
+
+        /// The non-fungible item does not exist.
+        UnknownNonFungible,
+
+        /// The non-fungible item is already registered as an asset.
+        NonFungibleAlreadyRegistered,
+
+        /// The collection of the non-fungible item is not owned by the account of this pallet.
+        UnrestrictedCollection,
+
+        /// Cannot Freeze or Destroy Native Asset
+        CannotDestroyNativeAsset,
+
+        /// Asset Already Frozen
+        AssetAlreadyFrozen,
+
+        /// The asset has to be frozen before it can be destroyed.
+        AssetNotFrozen,
+
+        /// The asset still has some outstanding supply.
+        OutstandingAssetSupply,
+
+        /// An error occurred while freezing an asset at the [`AssetRegistry`].
+        ErrorFreezingAsset,
+
+        /// An error occurred while destroying an asset at the [`AssetRegistry`]. This usually means
+        /// that there are still accounts or approvals for the asset.
+        ErrorDestroyingAsset,
+
+        /// The asset already has the maximum number of alias locations.
+        TooManyLocationAliases,
+
+        /// The location is not an alias of any asset.
+        UnknownLocationAlias,
+
+        /// The registration proposal does not exist.
+        UnknownProposal,
+
+        /// The registration proposal is past its deadline.
+        ProposalExpired,
+
+        /// The registration proposal can still be approved or rejected.
+        ProposalNotExpired,
+
+        /// The location is not in the interior of the requesting sibling parachain.
+        LocationNotInSiblingInterior,
+
+        /// A registration request for the location is already pending.
+        SiblingRequestAlreadyPending,
+
+        /// The sibling parachain has too many pending registration requests.
+        TooManySiblingRequests,
+
+        /// There is no pending sibling registration request for the location.
+        UnknownSiblingRequest,
+
+        /// The mint would take the outstanding governance supply of the asset above its cap.
+        MintCapExceeded,
+
+        /// An error occurred while burning an asset.
+        BurnError,
+
+        /// Some accounts still hold the asset.
+        OutstandingAssetAccounts,
+
+        /// Some approvals still spend the asset.
+        OutstandingAssetApprovals,
+    }
+
+    impl<T> From<AssetDestroyBlocker<T::Balance>> for Error<T>
+    where
+        T: Config,
+    {
+        #[inline]
+        fn from(blocker: AssetDestroyBlocker<T::Balance>) -> Self {
+            match blocker {
+                AssetDestroyBlocker::UnknownAsset => Self::UpdateNonExistentAsset,
+                AssetDestroyBlocker::NativeAsset => Self::CannotDestroyNativeAsset,
+                AssetDestroyBlocker::NotFrozen => Self::AssetNotFrozen,
+                AssetDestroyBlocker::OutstandingSupply(_) => Self::OutstandingAssetSupply,
+                AssetDestroyBlocker::OutstandingAccounts(_) => Self::OutstandingAssetAccounts,
+                AssetDestroyBlocker::OutstandingApprovals(_) => Self::OutstandingAssetApprovals,
+            }
+        }
     }
 
     /// [`AssetId`](AssetConfig::AssetId) to [`MultiLocation`] Map
@@ -325,6 +861,110 @@ pub mod pallet {
     #[pallet::getter(fn get_para_id)]
     pub type AllowedDestParaIds<T: Config> = StorageMap<_, Blake2_128Concat, ParaId, AssetCount>;
 
+    /// Destination Policies of Outbound XCM Transfers
+    ///
+    /// A policy set on a chain location applies to every account on that chain, while a policy set
+    /// on a full destination location overrides it for that destination only. Destinations without
+    /// any policy fall back to [`AllowedDestParaIds`].
+    #[pallet::storage]
+    #[pallet::getter(fn destination_policy)]
+    pub type DestinationPolicies<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::Location, DestinationPolicy>;
+
+    /// Locations Trusted to Teleport the Native Asset
+    ///
+    /// The native asset teleported out of this chain is tracked in the checking account, so that
+    /// these locations cannot teleport in more of it than was teleported out.
+    #[pallet::storage]
+    pub type TrustedTeleporters<T: Config> = StorageMap<_, Blake2_128Concat, T::Location, ()>;
+
+    /// [`AssetId`](AssetConfig::AssetId) to Non-Fungible Item Map
+    ///
+    /// Every registered item has its own asset id whose only valid amount is one.
+    #[pallet::storage]
+    #[pallet::getter(fn asset_id_non_fungible)]
+    pub(super) type AssetIdNonFungible<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, (T::CollectionId, T::ItemId)>;
+
+    /// Non-Fungible Item to [`AssetId`](AssetConfig::AssetId) Map
+    #[pallet::storage]
+    #[pallet::getter(fn non_fungible_asset_id)]
+    pub(super) type NonFungibleAssetId<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::CollectionId,
+        Blake2_128Concat,
+        T::ItemId,
+        T::AssetId,
+    >;
+
+    /// Frozen Assets
+    ///
+    /// Assets which were frozen by governance and are waiting to be destroyed.
+    #[pallet::storage]
+    #[pallet::getter(fn frozen_assets)]
+    pub(super) type FrozenAssets<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, ()>;
+
+    /// Alias Location to [`AssetId`](AssetConfig::AssetId) Map
+    ///
+    /// Alias locations are only accepted when resolving inbound assets, and only until their
+    /// optional cutover block.
+    #[pallet::storage]
+    #[pallet::getter(fn alias_location_asset_id)]
+    pub(super) type AliasLocationAssetId<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::Location, (T::AssetId, Option<T::BlockNumber>)>;
+
+    /// [`AssetId`](AssetConfig::AssetId) to Alias Locations Map
+    #[pallet::storage]
+    #[pallet::getter(fn location_aliases)]
+    pub(super) type LocationAliases<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AssetId,
+        BoundedVec<T::Location, T::MaxLocationAliases>,
+        ValueQuery,
+    >;
+
+    /// Next Registration Proposal Id
+    #[pallet::storage]
+    #[pallet::getter(fn next_proposal_id)]
+    pub type NextProposalId<T: Config> = StorageValue<_, ProposalId, ValueQuery>;
+
+    /// Pending Asset Registration Proposals
+    #[pallet::storage]
+    #[pallet::getter(fn registration_proposal)]
+    pub(super) type RegistrationProposals<T: Config> =
+        StorageMap<_, Twox64Concat, ProposalId, RegistrationProposalOf<T>>;
+
+    /// Pending Sibling Registration Requests
+    ///
+    /// Metadata proposed by sibling parachains for assets in their own interior, awaiting
+    /// ratification by the `ModifierOrigin`.
+    #[pallet::storage]
+    #[pallet::getter(fn sibling_registration_request)]
+    pub(super) type SiblingRegistrationRequests<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        ParaId,
+        Blake2_128Concat,
+        T::Location,
+        <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata,
+    >;
+
+    /// [`AssetId`](AssetConfig::AssetId) to [`AssetStatus`] Map
+    ///
+    /// Assets without an entry are [`Active`](AssetStatus::Active).
+    #[pallet::storage]
+    #[pallet::getter(fn asset_id_status)]
+    pub(super) type AssetIdStatus<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, AssetStatus, ValueQuery>;
+
+    /// [`AssetId`](AssetConfig::AssetId) to [`MintAccounting`] Map
+    #[pallet::storage]
+    #[pallet::getter(fn mint_accounting)]
+    pub(super) type AssetMintAccounting<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, MintAccounting<T::Balance>, ValueQuery>;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Register a new asset in the asset manager.
@@ -344,37 +984,9 @@ pub mod pallet {
             metadata: <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata,
         ) -> DispatchResult {
             T::ModifierOrigin::ensure_origin(origin)?;
-            ensure!(
-                !LocationAssetId::<T>::contains_key(&location),
-                Error::<T>::LocationAlreadyExists
-            );
-            let asset_id = Self::next_asset_id_and_increment()?;
-            <T::AssetConfig as AssetConfig<T>>::AssetRegistry::create_asset(
-                asset_id,
-                metadata.clone().into(),
-                metadata.min_balance().clone(),
-                metadata.is_sufficient(),
-            )
-            .map_err(|_| Error::<T>::ErrorCreatingAsset)?;
-            AssetIdLocation::<T>::insert(asset_id, &location);
-            AssetIdMetadata::<T>::insert(asset_id, &metadata);
-            LocationAssetId::<T>::insert(&location, asset_id);
-
-            // If it's a new para id, which will be inserted with AssetCount as 1.
-            // If not, AssetCount will increased by 1.
-            if let Some(para_id) =
-                Self::para_id_from_multilocation(location.clone().into().as_ref())
-            {
-                Self::increase_count_of_associated_assets(*para_id)?;
-            }
-
-            Self::deposit_event(Event::<T>::AssetRegistered {
-                asset_id,
-                location,
-                metadata,
-            });
-            Ok(())
-        }
+            Self::do_register_asset(location, metadata)?;
+            Ok(())
+        }
 
         /// Update an asset by its asset id in the asset manager.
         ///
@@ -391,12 +1003,13 @@ pub mod pallet {
         ) -> DispatchResult {
             // checks validity
             T::ModifierOrigin::ensure_origin(origin)?;
+            let location = location.normalize();
             ensure!(
                 AssetIdLocation::<T>::contains_key(asset_id),
                 Error::<T>::UpdateNonExistentAsset
             );
             ensure!(
-                !LocationAssetId::<T>::contains_key(&location),
+                !Self::is_location_used(&location),
                 Error::<T>::LocationAlreadyExists
             );
             // change the ledger state.
@@ -412,17 +1025,7 @@ pub mod pallet {
             if let Some(old_para_id) =
                 Self::para_id_from_multilocation(old_location.into().as_ref())
             {
-                if AllowedDestParaIds::<T>::get(old_para_id) <= Some(<AssetCount as One>::one()) {
-                    AllowedDestParaIds::<T>::remove(old_para_id);
-                } else {
-                    AllowedDestParaIds::<T>::try_mutate(old_para_id, |cnt| -> DispatchResult {
-                        let new_cnt = cnt
-                            .map(|c| c - <AssetCount as One>::one())
-                            .ok_or(Error::<T>::UpdateParaIdError)?;
-                        *cnt = Some(new_cnt);
-                        Ok(())
-                    })?;
-                }
+                Self::decrease_count_of_associated_assets(*old_para_id)?;
             }
 
             // If it's a new para id, which will be inserted with AssetCount as 1.
@@ -515,6 +1118,19 @@ pub mod pallet {
                 AssetIdLocation::<T>::contains_key(asset_id),
                 Error::<T>::UpdateNonExistentAsset
             );
+            AssetMintAccounting::<T>::try_mutate(asset_id, |accounting| -> DispatchResult {
+                accounting.minted = accounting
+                    .minted
+                    .checked_add(&amount)
+                    .ok_or(ArithmeticError::Overflow)?;
+                if let Some(cap) = &accounting.cap {
+                    ensure!(
+                        accounting.outstanding() <= *cap,
+                        Error::<T>::MintCapExceeded
+                    );
+                }
+                Ok(())
+            })?;
             <T::AssetConfig as AssetConfig<T>>::FungibleLedger::deposit_minting_with_check(
                 asset_id,
                 &beneficiary,
@@ -544,6 +1160,7 @@ pub mod pallet {
             #[pallet::compact] min_xcm_fee: u128,
         ) -> DispatchResult {
             T::ModifierOrigin::ensure_origin(origin)?;
+            let reserve_chain = reserve_chain.normalize();
             MinXcmFee::<T>::insert(&reserve_chain, min_xcm_fee);
             Self::deposit_event(Event::<T>::MinXcmFeeUpdated {
                 reserve_chain,
@@ -551,6 +1168,552 @@ pub mod pallet {
             });
             Ok(())
         }
+
+        /// Register an existing non-fungible item as a new asset with a value of one.
+        ///
+        /// Only items of collections owned by the account of this pallet can be registered, so
+        /// that the admin rights over the collection stay with governance.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ForceOrigin`.
+        /// * `collection`: Collection of the item.
+        /// * `item`: Item to be registered.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::register_non_fungible_asset())]
+        #[transactional]
+        pub fn register_non_fungible_asset(
+            origin: OriginFor<T>,
+            collection: T::CollectionId,
+            item: T::ItemId,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            ensure!(
+                !NonFungibleAssetId::<T>::contains_key(collection, item),
+                Error::<T>::NonFungibleAlreadyRegistered
+            );
+            ensure!(
+                T::NonFungibles::owner(&collection, &item).is_some(),
+                Error::<T>::UnknownNonFungible
+            );
+            ensure!(
+                T::NonFungibles::collection_owner(&collection) == Some(Self::account_id()),
+                Error::<T>::UnrestrictedCollection
+            );
+            let asset_id = Self::next_asset_id_and_increment()?;
+            AssetIdNonFungible::<T>::insert(asset_id, (collection, item));
+            NonFungibleAssetId::<T>::insert(collection, item, asset_id);
+            Self::deposit_event(Event::<T>::NonFungibleAssetRegistered {
+                asset_id,
+                collection,
+                item,
+            });
+            Ok(())
+        }
+
+        /// Freeze an asset by its `asset_id`, preventing any further transfers of it.
+        ///
+        /// This is the first step of retiring an asset, see [`destroy_asset`](Self::destroy_asset).
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ForceOrigin`.
+        /// * `asset_id`: AssetId to be frozen.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::freeze_asset())]
+        #[transactional]
+        pub fn freeze_asset(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            ensure!(
+                asset_id != <T::AssetConfig as AssetConfig<T>>::NativeAssetId::get(),
+                Error::<T>::CannotDestroyNativeAsset
+            );
+            ensure!(
+                AssetIdLocation::<T>::contains_key(asset_id),
+                Error::<T>::UpdateNonExistentAsset
+            );
+            ensure!(
+                !FrozenAssets::<T>::contains_key(asset_id),
+                Error::<T>::AssetAlreadyFrozen
+            );
+            <T::AssetConfig as AssetConfig<T>>::AssetRegistry::freeze_asset(&asset_id)
+                .map_err(|_| Error::<T>::ErrorFreezingAsset)?;
+            FrozenAssets::<T>::insert(asset_id, ());
+            Self::deposit_event(Event::<T>::AssetFrozen { asset_id });
+            Ok(())
+        }
+
+        /// Destroy a frozen asset by its `asset_id` and remove all of its mappings from the asset
+        /// manager.
+        ///
+        /// The asset must have no outstanding supply, accounts or approvals left. Use
+        /// [`check_destroy_asset`](Self::check_destroy_asset) to find out what blocks the
+        /// destruction, which fails with the error of the first blocker.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ForceOrigin`.
+        /// * `asset_id`: AssetId to be destroyed.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::destroy_asset())]
+        #[transactional]
+        pub fn destroy_asset(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            Self::check_destroy_asset(asset_id)
+                .map_err(|blockers| Error::<T>::from(blockers[0].clone()))?;
+            <T::AssetConfig as AssetConfig<T>>::AssetRegistry::destroy_asset(&asset_id)
+                .map_err(|_| Error::<T>::ErrorDestroyingAsset)?;
+            let location =
+                AssetIdLocation::<T>::take(asset_id).ok_or(Error::<T>::UpdateNonExistentAsset)?;
+            LocationAssetId::<T>::remove(&location);
+            AssetIdMetadata::<T>::remove(asset_id);
+            UnitsPerSecond::<T>::remove(asset_id);
+            FrozenAssets::<T>::remove(asset_id);
+            AssetIdStatus::<T>::remove(asset_id);
+            AssetMintAccounting::<T>::remove(asset_id);
+            for alias in LocationAliases::<T>::take(asset_id) {
+                AliasLocationAssetId::<T>::remove(&alias);
+            }
+            if let Some(para_id) =
+                Self::para_id_from_multilocation(location.clone().into().as_ref())
+            {
+                Self::decrease_count_of_associated_assets(*para_id)?;
+            }
+            Self::deposit_event(Event::<T>::AssetDestroyed { asset_id, location });
+            Ok(())
+        }
+
+        /// Update the lifecycle status of an asset by its `asset_id`.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ForceOrigin`.
+        /// * `asset_id`: AssetId to be updated.
+        /// * `status`: New status of the asset.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::set_asset_status())]
+        #[transactional]
+        pub fn set_asset_status(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+            status: AssetStatus,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            ensure!(
+                AssetIdLocation::<T>::contains_key(asset_id)
+                    || AssetIdNonFungible::<T>::contains_key(asset_id),
+                Error::<T>::UpdateNonExistentAsset
+            );
+            if status == AssetStatus::Active {
+                AssetIdStatus::<T>::remove(asset_id);
+            } else {
+                AssetIdStatus::<T>::insert(asset_id, status);
+            }
+            Self::deposit_event(Event::<T>::AssetStatusUpdated { asset_id, status });
+            Ok(())
+        }
+
+        /// Add an alias `location` to an asset, which is accepted for inbound transfers next to
+        /// its canonical location.
+        ///
+        /// Aliases with a `cutover` block stop being accepted from that block onwards, which can
+        /// be used to phase out an old location after
+        /// [`update_asset_location`](Self::update_asset_location). Expired aliases of the asset are
+        /// pruned by this call.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ForceOrigin`.
+        /// * `asset_id`: AssetId to be aliased.
+        /// * `location`: Alias location of the asset.
+        /// * `cutover`: Block at which the alias stops being accepted, if any.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::add_location_alias())]
+        #[transactional]
+        pub fn add_location_alias(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+            location: T::Location,
+            cutover: Option<T::BlockNumber>,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            let location = location.normalize();
+            ensure!(
+                AssetIdLocation::<T>::contains_key(asset_id),
+                Error::<T>::UpdateNonExistentAsset
+            );
+            ensure!(
+                !Self::is_location_used(&location),
+                Error::<T>::LocationAlreadyExists
+            );
+            LocationAliases::<T>::try_mutate(asset_id, |aliases| {
+                let now = frame_system::Pallet::<T>::block_number();
+                aliases.retain(|alias| match AliasLocationAssetId::<T>::get(alias) {
+                    Some((_, Some(cutover))) if cutover <= now => {
+                        AliasLocationAssetId::<T>::remove(alias);
+                        false
+                    }
+                    _ => true,
+                });
+                aliases
+                    .try_push(location.clone())
+                    .map_err(|_| Error::<T>::TooManyLocationAliases)
+            })?;
+            AliasLocationAssetId::<T>::insert(&location, (asset_id, cutover));
+            Self::deposit_event(Event::<T>::LocationAliasAdded {
+                asset_id,
+                location,
+                cutover,
+            });
+            Ok(())
+        }
+
+        /// Remove an alias `location` from the asset it belongs to.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ForceOrigin`.
+        /// * `location`: Alias location to be removed.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::remove_location_alias())]
+        #[transactional]
+        pub fn remove_location_alias(
+            origin: OriginFor<T>,
+            location: T::Location,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            let location = location.normalize();
+            let (asset_id, _) = AliasLocationAssetId::<T>::take(&location)
+                .ok_or(Error::<T>::UnknownLocationAlias)?;
+            LocationAliases::<T>::mutate(asset_id, |aliases| {
+                aliases.retain(|alias| alias != &location)
+            });
+            Self::deposit_event(Event::<T>::LocationAliasRemoved { asset_id, location });
+            Ok(())
+        }
+
+        /// Propose the registration of a new asset, reserving `ProposalDeposit` from `origin`.
+        ///
+        /// The proposal can be approved or rejected by `ModifierOrigin` until `ProposalLifetime`
+        /// blocks have passed.
+        ///
+        /// * `origin`: Proposer of the asset.
+        /// * `location`: Location of the asset.
+        /// * `metadata`: Asset metadata.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::propose_asset())]
+        #[transactional]
+        pub fn propose_asset(
+            origin: OriginFor<T>,
+            location: T::Location,
+            metadata: <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata,
+        ) -> DispatchResult {
+            let proposer = ensure_signed(origin)?;
+            let location = location.normalize();
+            ensure!(
+                !Self::is_location_used(&location),
+                Error::<T>::LocationAlreadyExists
+            );
+            let deposit = T::ProposalDeposit::get();
+            T::Currency::reserve(&proposer, deposit)?;
+            let proposal_id = NextProposalId::<T>::try_mutate(|current| {
+                let id = *current;
+                *current = current.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+                Ok::<_, DispatchError>(id)
+            })?;
+            let deadline = frame_system::Pallet::<T>::block_number()
+                .saturating_add(T::ProposalLifetime::get());
+            RegistrationProposals::<T>::insert(
+                proposal_id,
+                RegistrationProposal {
+                    proposer: proposer.clone(),
+                    location: location.clone(),
+                    metadata,
+                    deposit,
+                    deadline,
+                },
+            );
+            Self::deposit_event(Event::<T>::AssetProposed {
+                proposal_id,
+                proposer,
+                location,
+            });
+            Ok(())
+        }
+
+        /// Approve a registration proposal, registering its asset and returning the deposit to
+        /// the proposer.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ForceOrigin`.
+        /// * `proposal_id`: Id of the proposal to be approved.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::approve_asset_proposal())]
+        #[transactional]
+        pub fn approve_asset_proposal(
+            origin: OriginFor<T>,
+            #[pallet::compact] proposal_id: ProposalId,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            let proposal =
+                RegistrationProposals::<T>::take(proposal_id).ok_or(Error::<T>::UnknownProposal)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() <= proposal.deadline,
+                Error::<T>::ProposalExpired
+            );
+            let asset_id = Self::do_register_asset(proposal.location, proposal.metadata)?;
+            T::Currency::unreserve(&proposal.proposer, proposal.deposit);
+            Self::deposit_event(Event::<T>::AssetProposalApproved {
+                proposal_id,
+                asset_id,
+            });
+            Ok(())
+        }
+
+        /// Reject a registration proposal, slashing the deposit if `slash` is `true` or returning
+        /// it to the proposer otherwise.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ForceOrigin`.
+        /// * `proposal_id`: Id of the proposal to be rejected.
+        /// * `slash`: Whether the deposit should be slashed.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::reject_asset_proposal())]
+        #[transactional]
+        pub fn reject_asset_proposal(
+            origin: OriginFor<T>,
+            #[pallet::compact] proposal_id: ProposalId,
+            slash: bool,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            let proposal =
+                RegistrationProposals::<T>::take(proposal_id).ok_or(Error::<T>::UnknownProposal)?;
+            if slash {
+                let (imbalance, _) =
+                    T::Currency::slash_reserved(&proposal.proposer, proposal.deposit);
+                T::OnProposalSlash::on_unbalanced(imbalance);
+            } else {
+                T::Currency::unreserve(&proposal.proposer, proposal.deposit);
+            }
+            Self::deposit_event(Event::<T>::AssetProposalRejected {
+                proposal_id,
+                slashed: slash,
+            });
+            Ok(())
+        }
+
+        /// Remove a registration proposal which is past its deadline, returning the deposit to
+        /// the proposer.
+        ///
+        /// * `origin`: Any signed account.
+        /// * `proposal_id`: Id of the expired proposal.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::remove_expired_proposal())]
+        #[transactional]
+        pub fn remove_expired_proposal(
+            origin: OriginFor<T>,
+            #[pallet::compact] proposal_id: ProposalId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            let proposal =
+                RegistrationProposals::<T>::get(proposal_id).ok_or(Error::<T>::UnknownProposal)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() > proposal.deadline,
+                Error::<T>::ProposalNotExpired
+            );
+            RegistrationProposals::<T>::remove(proposal_id);
+            T::Currency::unreserve(&proposal.proposer, proposal.deposit);
+            Self::deposit_event(Event::<T>::AssetProposalExpired { proposal_id });
+            Ok(())
+        }
+
+        /// Request the registration of an asset located in the interior of the calling sibling
+        /// parachain, usually dispatched by the sibling through an XCM `Transact`.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `SiblingOrigin`.
+        /// * `location`: Location of the asset, in the interior of the sibling.
+        /// * `metadata`: Proposed metadata of the asset.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::request_sibling_registration())]
+        #[transactional]
+        pub fn request_sibling_registration(
+            origin: OriginFor<T>,
+            location: T::Location,
+            metadata: <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata,
+        ) -> DispatchResult {
+            let para_id = T::SiblingOrigin::ensure_origin(origin)?;
+            let location = location.normalize();
+            ensure!(
+                Self::sibling_para_id(&location) == Some(para_id),
+                Error::<T>::LocationNotInSiblingInterior
+            );
+            ensure!(
+                !Self::is_location_used(&location),
+                Error::<T>::LocationAlreadyExists
+            );
+            ensure!(
+                !SiblingRegistrationRequests::<T>::contains_key(para_id, &location),
+                Error::<T>::SiblingRequestAlreadyPending
+            );
+            ensure!(
+                (SiblingRegistrationRequests::<T>::iter_prefix(para_id).count() as u32)
+                    < T::MaxSiblingRequests::get(),
+                Error::<T>::TooManySiblingRequests
+            );
+            SiblingRegistrationRequests::<T>::insert(para_id, &location, metadata);
+            Self::deposit_event(Event::<T>::SiblingRegistrationRequested { para_id, location });
+            Ok(())
+        }
+
+        /// Approve the pending sibling registration request for `location`, registering the asset
+        /// with the proposed metadata.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ForceOrigin`.
+        /// * `location`: Location of the requested asset.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::approve_sibling_registration())]
+        #[transactional]
+        pub fn approve_sibling_registration(
+            origin: OriginFor<T>,
+            location: T::Location,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            let location = location.normalize();
+            let para_id =
+                Self::sibling_para_id(&location).ok_or(Error::<T>::UnknownSiblingRequest)?;
+            let metadata = SiblingRegistrationRequests::<T>::take(para_id, &location)
+                .ok_or(Error::<T>::UnknownSiblingRequest)?;
+            let asset_id = Self::do_register_asset(location, metadata)?;
+            Self::deposit_event(Event::<T>::SiblingRegistrationApproved { para_id, asset_id });
+            Ok(())
+        }
+
+        /// Reject the pending sibling registration request for `location`.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ForceOrigin`.
+        /// * `location`: Location of the requested asset.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::reject_sibling_registration())]
+        #[transactional]
+        pub fn reject_sibling_registration(
+            origin: OriginFor<T>,
+            location: T::Location,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            let location = location.normalize();
+            let para_id =
+                Self::sibling_para_id(&location).ok_or(Error::<T>::UnknownSiblingRequest)?;
+            SiblingRegistrationRequests::<T>::take(para_id, &location)
+                .ok_or(Error::<T>::UnknownSiblingRequest)?;
+            Self::deposit_event(Event::<T>::SiblingRegistrationRejected { para_id, location });
+            Ok(())
+        }
+
+        /// Set the cap on the outstanding supply of an asset minted by governance.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `MintCapOrigin`.
+        /// * `asset_id`: AssetId to be updated.
+        /// * `cap`: New mint cap, or `None` to mint without a cap.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::set_mint_cap())]
+        #[transactional]
+        pub fn set_mint_cap(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+            cap: Option<T::Balance>,
+        ) -> DispatchResult {
+            T::MintCapOrigin::ensure_origin(origin)?;
+            ensure!(
+                AssetIdLocation::<T>::contains_key(asset_id),
+                Error::<T>::UpdateNonExistentAsset
+            );
+            AssetMintAccounting::<T>::mutate(asset_id, |accounting| accounting.cap = cap.clone());
+            Self::deposit_event(Event::<T>::MintCapUpdated { asset_id, cap });
+            Ok(())
+        }
+
+        /// Burn asset by its asset id from an account.
+        ///
+        /// The burn only frees up room under the mint cap of the asset once the remaining supply
+        /// is lower than the outstanding supply minted by governance.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ForceOrigin`.
+        /// * `asset_id`: AssetId to be burned.
+        /// * `who`: Account to burn the asset from.
+        /// * `amount`: Amount of asset being burned.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::burn_asset())]
+        #[transactional]
+        pub fn burn_asset(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+            who: T::AccountId,
+            amount: T::Balance,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            ensure!(
+                AssetIdLocation::<T>::contains_key(asset_id),
+                Error::<T>::UpdateNonExistentAsset
+            );
+            <T::AssetConfig as AssetConfig<T>>::FungibleLedger::withdraw_burning(
+                asset_id,
+                &who,
+                amount.clone(),
+                ExistenceRequirement::AllowDeath,
+            )
+            .map_err(|_| Error::<T>::BurnError)?;
+            let supply = <T::AssetConfig as AssetConfig<T>>::AssetRegistry::total_supply(&asset_id);
+            AssetMintAccounting::<T>::mutate(asset_id, |accounting| {
+                let burned = accounting.outstanding().saturating_sub(supply);
+                accounting.burned = accounting.burned.clone().saturating_add(burned)
+            });
+            Self::deposit_event(Event::<T>::AssetBurned {
+                asset_id,
+                who,
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Set the policy of outbound XCM transfers to a destination chain or location.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ForceOrigin`.
+        /// * `location`: Destination chain, or full destination location.
+        /// * `policy`: New policy, or `None` to fall back to the registered assets.
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::set_destination_policy())]
+        #[transactional]
+        pub fn set_destination_policy(
+            origin: OriginFor<T>,
+            location: T::Location,
+            policy: Option<DestinationPolicy>,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            let location = location.normalize();
+            match policy {
+                Some(policy) => DestinationPolicies::<T>::insert(&location, policy),
+                None => DestinationPolicies::<T>::remove(&location),
+            }
+            Self::deposit_event(Event::<T>::DestinationPolicyUpdated { location, policy });
+            Ok(())
+        }
+
+        /// Set whether teleports of the native asset from a location, like a system parachain,
+        /// are accepted.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ForceOrigin`.
+        /// * `location`: Location teleporting the native asset to this chain.
+        /// * `trusted`: Whether teleports from the location are accepted.
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::set_teleport_trust())]
+        #[transactional]
+        pub fn set_teleport_trust(
+            origin: OriginFor<T>,
+            location: T::Location,
+            trusted: bool,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            let location = location.normalize();
+            if trusted {
+                TrustedTeleporters::<T>::insert(&location, ());
+            } else {
+                TrustedTeleporters::<T>::remove(&location);
+            }
+            Self::deposit_event(Event::<T>::TeleportTrustUpdated { location, trusted });
+            Ok(())
+        }
     }
 
     impl<T> Pallet<T>
@@ -569,6 +1732,72 @@ pub mod pallet {
             })
         }
 
+        /// Registers a new asset at `location` with `metadata`, returning its asset id.
+        #[inline]
+        fn do_register_asset(
+            location: T::Location,
+            metadata: <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata,
+        ) -> Result<T::AssetId, DispatchError> {
+            let location = location.normalize();
+            ensure!(
+                !Self::is_location_used(&location),
+                Error::<T>::LocationAlreadyExists
+            );
+            let asset_id = Self::next_asset_id_and_increment()?;
+            <T::AssetConfig as AssetConfig<T>>::AssetRegistry::create_asset(
+                asset_id,
+                metadata.clone().into(),
+                metadata.min_balance().clone(),
+                metadata.is_sufficient(),
+            )
+            .map_err(|_| Error::<T>::ErrorCreatingAsset)?;
+            AssetIdLocation::<T>::insert(asset_id, &location);
+            AssetIdMetadata::<T>::insert(asset_id, &metadata);
+            LocationAssetId::<T>::insert(&location, asset_id);
+
+            // If it's a new para id, which will be inserted with AssetCount as 1.
+            // If not, AssetCount will increased by 1.
+            if let Some(para_id) =
+                Self::para_id_from_multilocation(location.clone().into().as_ref())
+            {
+                Self::increase_count_of_associated_assets(*para_id)?;
+            }
+
+            Self::deposit_event(Event::<T>::AssetRegistered {
+                asset_id,
+                location,
+                metadata,
+            });
+            Ok(asset_id)
+        }
+
+        /// Returns the asset id `location` is an alias of, if the alias has not been cut over yet.
+        #[inline]
+        pub fn alias_asset_id(location: &T::Location) -> Option<T::AssetId> {
+            let (asset_id, cutover) = AliasLocationAssetId::<T>::get(location)?;
+            match cutover {
+                Some(cutover) if cutover <= frame_system::Pallet::<T>::block_number() => None,
+                _ => Some(asset_id),
+            }
+        }
+
+        /// Returns `true` if `location` is the canonical location or an alias location of any
+        /// asset.
+        #[inline]
+        fn is_location_used(location: &T::Location) -> bool {
+            LocationAssetId::<T>::contains_key(location)
+                || AliasLocationAssetId::<T>::contains_key(location)
+        }
+
+        /// Returns the [`ParaId`] of the sibling parachain whose interior contains `location`.
+        #[inline]
+        fn sibling_para_id(location: &T::Location) -> Option<ParaId> {
+            let location: Option<MultiLocation> = location.clone().into();
+            location
+                .filter(|location| location.parents == 1)
+                .and_then(|location| Self::para_id_from_multilocation(Some(&location)).copied())
+        }
+
         /// Returns the account identifier of the [`AssetManager`] pallet.
         #[inline]
         pub fn account_id() -> T::AccountId {
@@ -586,6 +1815,148 @@ pub mod pallet {
             })
         }
 
+        /// Returns the policy which applies to outbound XCM transfers to `dest`, if any.
+        #[inline]
+        pub fn effective_destination_policy(dest: &MultiLocation) -> Option<DestinationPolicy> {
+            DestinationPolicies::<T>::get(T::Location::from(dest.clone())).or_else(|| {
+                let (chain, _) = dest.clone().split_last_interior();
+                DestinationPolicies::<T>::get(T::Location::from(chain))
+            })
+        }
+
+        /// Returns `true` if outbound XCM transfers to `dest` are allowed.
+        #[inline]
+        pub fn is_allowed_destination(dest: &T::Location) -> bool {
+            let dest: Option<MultiLocation> = dest.clone().into();
+            dest.map(|dest| <Self as Contains<MultiLocation>>::contains(&dest))
+                .unwrap_or(false)
+        }
+
+        /// Returns all destination policies of outbound XCM transfers.
+        #[inline]
+        pub fn destination_policies() -> Vec<(T::Location, DestinationPolicy)> {
+            DestinationPolicies::<T>::iter().collect()
+        }
+
+        /// Returns the registry entry of `asset_id`, if it is registered.
+        #[inline]
+        pub fn registry_entry(asset_id: T::AssetId) -> Option<AssetRegistryEntryOf<T>> {
+            let metadata = AssetIdMetadata::<T>::get(asset_id)?;
+            let location = AssetIdLocation::<T>::get(asset_id);
+            let para_id = location.clone().and_then(|location| {
+                let location: Option<MultiLocation> = location.into();
+                Self::para_id_from_multilocation(location.as_ref()).copied()
+            });
+            Some(AssetRegistryEntry {
+                asset_id,
+                min_xcm_fee: location.as_ref().and_then(MinXcmFee::<T>::get),
+                location,
+                metadata,
+                units_per_second: UnitsPerSecond::<T>::get(asset_id),
+                para_asset_count: para_id.and_then(AllowedDestParaIds::<T>::get),
+                para_id,
+            })
+        }
+
+        /// Returns the registry entry of the asset at `location`, which can be its canonical
+        /// location or an active alias.
+        #[inline]
+        pub fn registry_entry_by_location(
+            location: &T::Location,
+        ) -> Option<AssetRegistryEntryOf<T>> {
+            Self::registry_entry(<Self as AssetIdLocationMap>::asset_id(location)?)
+        }
+
+        /// Returns the canonical locations which are stored under several encodings in
+        /// [`LocationAssetId`], together with the assets registered at each of those encodings.
+        pub fn duplicate_locations() -> Vec<(T::Location, Vec<T::AssetId>)> {
+            Self::canonical_location_entries()
+                .into_iter()
+                .filter(|(_, entries)| entries.len() > 1)
+                .map(|(location, entries)| {
+                    let mut asset_ids = entries
+                        .into_iter()
+                        .map(|(_, asset_id)| asset_id)
+                        .collect::<Vec<_>>();
+                    asset_ids.sort_unstable();
+                    (location, asset_ids)
+                })
+                .collect()
+        }
+
+        /// Returns the entries of [`LocationAssetId`] grouped by the canonical forms of their
+        /// locations, reading the map once.
+        pub(crate) fn canonical_location_entries(
+        ) -> Vec<(T::Location, Vec<(T::Location, T::AssetId)>)> {
+            let mut groups = BTreeMap::<Vec<u8>, (T::Location, Vec<_>)>::new();
+            for (location, asset_id) in LocationAssetId::<T>::iter() {
+                let canonical = location.clone().normalize();
+                groups
+                    .entry(canonical.encode())
+                    .or_insert_with(|| (canonical, Vec::new()))
+                    .1
+                    .push((location, asset_id));
+            }
+            groups.into_values().collect()
+        }
+
+        /// Returns at most `limit` registry entries in storage order, starting after the asset id
+        /// `start` if given.
+        ///
+        /// Passing the last asset id of a page as `start` returns the next page.
+        pub fn registry_entries(
+            start: Option<T::AssetId>,
+            limit: u32,
+        ) -> Vec<AssetRegistryEntryOf<T>> {
+            let asset_ids = match start {
+                Some(start) => AssetIdMetadata::<T>::iter_keys_from(
+                    AssetIdMetadata::<T>::hashed_key_for(start),
+                ),
+                _ => AssetIdMetadata::<T>::iter_keys(),
+            };
+            asset_ids
+                .take(limit as usize)
+                .filter_map(Self::registry_entry)
+                .collect()
+        }
+
+        /// Checks if `asset_id` can be destroyed, returning everything that blocks its destruction
+        /// otherwise.
+        #[inline]
+        pub fn check_destroy_asset(
+            asset_id: T::AssetId,
+        ) -> Result<(), Vec<AssetDestroyBlocker<T::Balance>>> {
+            if asset_id == <T::AssetConfig as AssetConfig<T>>::NativeAssetId::get() {
+                return Err(vec![AssetDestroyBlocker::NativeAsset]);
+            }
+            if !AssetIdLocation::<T>::contains_key(asset_id) {
+                return Err(vec![AssetDestroyBlocker::UnknownAsset]);
+            }
+            let mut blockers = Vec::new();
+            if !FrozenAssets::<T>::contains_key(asset_id) {
+                blockers.push(AssetDestroyBlocker::NotFrozen);
+            }
+            let supply = <T::AssetConfig as AssetConfig<T>>::AssetRegistry::total_supply(&asset_id);
+            if !supply.is_zero() {
+                blockers.push(AssetDestroyBlocker::OutstandingSupply(supply));
+            }
+            let (accounts, approvals) =
+                <T::AssetConfig as AssetConfig<T>>::AssetRegistry::accounts_and_approvals(
+                    &asset_id,
+                );
+            if accounts > 0 {
+                blockers.push(AssetDestroyBlocker::OutstandingAccounts(accounts));
+            }
+            if approvals > 0 {
+                blockers.push(AssetDestroyBlocker::OutstandingApprovals(approvals));
+            }
+            if blockers.is_empty() {
+                Ok(())
+            } else {
+                Err(blockers)
+            }
+        }
+
         /// Increases the count of associated assets for the para id.
         pub fn increase_count_of_associated_assets(para_id: ParaId) -> DispatchResult {
             // If it's a new para id, which will be inserted with AssetCount as 1.
@@ -603,6 +1974,23 @@ pub mod pallet {
                 Ok(())
             }
         }
+
+        /// Decreases the count of associated assets for the para id, removing it once no assets
+        /// are associated to it anymore.
+        pub fn decrease_count_of_associated_assets(para_id: ParaId) -> DispatchResult {
+            if AllowedDestParaIds::<T>::get(para_id) <= Some(<AssetCount as One>::one()) {
+                AllowedDestParaIds::<T>::remove(para_id);
+                Ok(())
+            } else {
+                AllowedDestParaIds::<T>::try_mutate(para_id, |count| -> DispatchResult {
+                    let new_count = count
+                        .map(|c| c - <AssetCount as One>::one())
+                        .ok_or(Error::<T>::UpdateParaIdError)?;
+                    *count = Some(new_count);
+                    Ok(())
+                })
+            }
+        }
     }
 
     /// Check the multilocation destination is supported by calamari/manta.
@@ -617,7 +2005,7 @@ pub mod pallet {
                 return false;
             }
 
-            match location.interior {
+            let has_assets = match location.interior {
                 // Send tokens back to relaychain.
                 Junctions::X1(Junction::AccountId32 { .. }) => true,
                 // Send tokens to sibling chain.
@@ -626,11 +2014,33 @@ pub mod pallet {
                     AllowedDestParaIds::<T>::contains_key(para_id)
                 }
                 // We don't support X3 or longer Junctions.
-                _ => false,
+                _ => return false,
+            };
+
+            match Self::effective_destination_policy(location) {
+                Some(DestinationPolicy::Allow) => true,
+                Some(DestinationPolicy::Deny) => false,
+                None => has_assets,
             }
         }
     }
 
+    /// Trusted Teleporter Filter
+    ///
+    /// Contains the locations trusted to teleport the native asset, as set in
+    /// [`TrustedTeleporters`].
+    pub struct IsTrustedTeleporter<T>(PhantomData<T>);
+
+    impl<T> Contains<MultiLocation> for IsTrustedTeleporter<T>
+    where
+        T: Config,
+    {
+        #[inline]
+        fn contains(location: &MultiLocation) -> bool {
+            TrustedTeleporters::<T>::contains_key(T::Location::from(location.clone()).normalize())
+        }
+    }
+
     /// Get min-xcm-fee for reserve chain by multilocation.
     impl<T> GetByKey<MultiLocation, Option<u128>> for Pallet<T>
     where
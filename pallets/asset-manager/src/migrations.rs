@@ -14,8 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with Manta.  If not, see <http://www.gnu.org/licenses/>.
 
-//! Do storage migration for AllowedDestParaIds which records the count of associated assets for
-//! each para id.
+//! Storage migrations for the asset manager:
+//!
+//! * AllowedDestParaIds which records the count of associated assets for each para id.
+//! * LocationAssetId which is keyed by the canonical form of each location.
 
 use super::*;
 use core::marker::PhantomData;
@@ -24,6 +26,8 @@ use frame_support::{
     pallet_prelude::Weight,
     traits::{Get, OnRuntimeUpgrade, PalletInfoAccess, StorageVersion},
 };
+use manta_primitives::assets::NormalizeLocation;
+use sp_std::vec::Vec;
 
 /// Storage migration to populate the existing assets'
 /// entries in the new AllowedDestParaIds storage item
@@ -94,3 +98,127 @@ where
         }
     }
 }
+
+/// Maximum number of locations rewritten by a single run of [`LocationNormalizationMigration`]
+pub const MAX_LOCATION_NORMALIZATIONS: u32 = 64;
+
+/// Storage migration to key the existing assets' entries in LocationAssetId by their canonical
+/// locations, reporting the locations under which several assets are registered
+///
+/// The storage version is only bumped to 3 once every entry is keyed by its canonical location.
+/// Duplicates, entries clashing with an alias and entries beyond [`MAX_LOCATION_NORMALIZATIONS`]
+/// leave it at 2, so that the migration runs again with the next runtime upgrade.
+pub struct LocationNormalizationMigration<T>(PhantomData<T>);
+
+impl<T> OnRuntimeUpgrade for LocationNormalizationMigration<T>
+where
+    T: Config,
+{
+    fn on_runtime_upgrade() -> Weight {
+        let storage_version = <Pallet<T> as GetStorageVersion>::on_chain_storage_version();
+        if storage_version != 2 {
+            log::info!("✅ no location normalization for asset-manager.");
+            return T::DbWeight::get().reads(1);
+        }
+        log::info!(target: "asset-manager", "Start to normalize the asset locations of asset-manager.");
+        let mut reads: Weight = 1;
+        let mut writes: Weight = 0;
+        let mut normalized = 0;
+        let mut complete = true;
+        for (canonical, entries) in Pallet::<T>::canonical_location_entries() {
+            reads += entries.len() as Weight;
+            let (location, asset_id) = match &entries[..] {
+                [(location, asset_id)] => (location.clone(), *asset_id),
+                _ => {
+                    // Duplicates are only reported, since merging their assets needs a
+                    // governance decision.
+                    log::warn!(
+                        target: "asset-manager",
+                        "Assets {:?} are registered at equivalent encodings of {:?}.",
+                        entries.iter().map(|(_, asset_id)| asset_id).collect::<Vec<_>>(),
+                        canonical
+                    );
+                    complete = false;
+                    continue;
+                }
+            };
+            if canonical == location {
+                continue;
+            }
+            if normalized == MAX_LOCATION_NORMALIZATIONS {
+                complete = false;
+                break;
+            }
+            reads += 1;
+            if AliasLocationAssetId::<T>::contains_key(&canonical) {
+                log::warn!(
+                    target: "asset-manager",
+                    "Asset {:?} is registered at an encoding of the alias {:?}.",
+                    asset_id,
+                    canonical
+                );
+                complete = false;
+                continue;
+            }
+            LocationAssetId::<T>::remove(&location);
+            LocationAssetId::<T>::insert(&canonical, asset_id);
+            AssetIdLocation::<T>::insert(asset_id, &canonical);
+            writes += 3;
+            normalized += 1;
+            // Locations which were not counted towards their para id before are counted now.
+            if Pallet::<T>::para_id_from_multilocation(location.into().as_ref()).is_none() {
+                if let Some(para_id) =
+                    Pallet::<T>::para_id_from_multilocation(canonical.into().as_ref())
+                {
+                    let _ = Pallet::<T>::increase_count_of_associated_assets(*para_id);
+                    reads += 1;
+                    writes += 1;
+                }
+            }
+        }
+        if complete {
+            StorageVersion::new(3u16).put::<Pallet<T>>();
+            writes += 1;
+        } else {
+            log::warn!(
+                target: "asset-manager",
+                "Location normalization is incomplete, the storage version stays at 2."
+            );
+        }
+        T::DbWeight::get()
+            .reads(reads)
+            .saturating_add(T::DbWeight::get().writes(writes))
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<(), &'static str> {
+        let storage_version = <Pallet<T> as GetStorageVersion>::on_chain_storage_version();
+        if storage_version != 2 {
+            return Err("Storage version is not 2, the migration won't be executed.");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade() -> Result<(), &'static str> {
+        let storage_version = <Pallet<T> as GetStorageVersion>::on_chain_storage_version();
+        let normalized = Pallet::<T>::duplicate_locations().is_empty()
+            && LocationAssetId::<T>::iter_keys()
+                .all(|location| location.clone().normalize() == location);
+        match (storage_version == 3, normalized) {
+            (true, true) => {
+                log::info!(
+                    "✅ Location normalization for asset-manager has been executed successfully."
+                );
+                Ok(())
+            }
+            (false, false) if storage_version == 2 => {
+                log::warn!(
+                    "Location normalization for asset-manager is incomplete and will run again."
+                );
+                Ok(())
+            }
+            _ => Err("Failed to executed location normalization for asset-manager."),
+        }
+    }
+}
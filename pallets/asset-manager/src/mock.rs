@@ -21,15 +21,21 @@
 
 use crate as pallet_asset_manager;
 use crate::mock::sp_api_hidden_includes_construct_runtime::hidden_include::traits::GenesisBuild;
+use codec::{Compact, Decode, Encode};
 use frame_support::{
-    construct_runtime, pallet_prelude::DispatchResult, parameter_types, traits::ConstU32, PalletId,
+    construct_runtime, ord_parameter_types,
+    pallet_prelude::DispatchResult,
+    parameter_types,
+    traits::{fungibles, AsEnsureOriginWithArg, ConstU128, ConstU32, EnsureOrigin},
+    PalletId,
 };
 use frame_system as system;
-use frame_system::EnsureRoot;
+use frame_system::{EnsureRoot, EnsureSigned, EnsureSignedBy, RawOrigin};
 use manta_primitives::{
     assets::{
         AssetConfig, AssetIdType, AssetLocation, AssetRegistry, AssetRegistryMetadata,
-        AssetStorageMetadata, BalanceType, LocationType, NativeAndNonNative,
+        AssetStorageMetadata, BalanceType, FungibleAndNonFungible, LocationType,
+        NativeAndNonNative,
     },
     constants::{ASSET_MANAGER_PALLET_ID, ASSET_STRING_LIMIT},
     types::{AccountId, Balance, BlockNumber, CalamariAssetId, Header},
@@ -117,6 +123,25 @@ impl pallet_balances::Config for Runtime {
     type ReserveIdentifier = [u8; 8];
 }
 
+impl pallet_uniques::Config for Runtime {
+    type Event = Event;
+    type CollectionId = u32;
+    type ItemId = u32;
+    type Currency = Balances;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+    type Locker = ();
+    type CollectionDeposit = ConstU128<0>;
+    type ItemDeposit = ConstU128<0>;
+    type MetadataDepositBase = ConstU128<0>;
+    type AttributeDepositBase = ConstU128<0>;
+    type DepositPerByte = ConstU128<0>;
+    type StringLimit = AssetsStringLimit;
+    type KeyLimit = ConstU32<32>;
+    type ValueLimit = ConstU32<64>;
+    type WeightInfo = ();
+}
+
 pub struct MantaAssetRegistry;
 impl BalanceType for MantaAssetRegistry {
     type Balance = Balance;
@@ -165,6 +190,38 @@ impl AssetRegistry for MantaAssetRegistry {
             metadata.is_frozen,
         )
     }
+
+    fn total_supply(asset_id: &CalamariAssetId) -> Balance {
+        <Assets as fungibles::Inspect<AccountId>>::total_issuance(*asset_id)
+    }
+
+    fn accounts_and_approvals(asset_id: &CalamariAssetId) -> (u32, u32) {
+        // NOTE: The fields of `DestroyWitness` are private, but it is encoded as the compact
+        //       numbers of accounts, sufficient accounts and approvals of the asset.
+        <Assets as fungibles::Destroy<AccountId>>::get_destroy_witness(asset_id)
+            .and_then(|witness| {
+                <(Compact<u32>, Compact<u32>, Compact<u32>)>::decode(
+                    &mut witness.encode().as_slice(),
+                )
+                .ok()
+            })
+            .map(|(accounts, _, approvals)| (accounts.0, approvals.0))
+            .unwrap_or_default()
+    }
+
+    fn freeze_asset(asset_id: &CalamariAssetId) -> DispatchResult {
+        Assets::freeze_asset(Origin::signed(AssetManager::account_id()), *asset_id)
+    }
+
+    fn destroy_asset(asset_id: &CalamariAssetId) -> DispatchResult {
+        // NOTE: `DestroyWitness` can only be built by decoding it. An empty witness makes
+        //       `pallet_assets` refuse the destruction while any accounts or approvals remain.
+        let witness = pallet_assets::DestroyWitness::decode(&mut [0u8; 3].as_slice())
+            .map_err(|_| sp_runtime::DispatchError::Other("Invalid destroy witness"))?;
+        Assets::destroy(Origin::root(), *asset_id, witness)
+            .map(|_| ())
+            .map_err(|err| err.error)
+    }
 }
 
 parameter_types! {
@@ -205,7 +262,55 @@ impl AssetConfig<Runtime> for MantaAssetConfig {
     type NativeAssetMetadata = NativeAssetMetadata;
     type StorageMetadata = AssetStorageMetadata;
     type AssetRegistry = MantaAssetRegistry;
-    type FungibleLedger = NativeAndNonNative<Runtime, MantaAssetConfig, Balances, Assets>;
+    type FungibleLedger = FungibleAndNonFungible<
+        NativeAndNonNative<Runtime, MantaAssetConfig, Balances, Assets>,
+        AssetManager,
+        Uniques,
+    >;
+    type AssetStatusMap = AssetManager;
+}
+
+/// Prefix of the accounts which act as sibling parachain origins
+pub const SIBLING_ACCOUNT_PREFIX: &[u8; 4] = b"sibl";
+
+/// Returns the account which acts as the origin of the sibling parachain `para_id`.
+pub fn sibling_account(para_id: u32) -> AccountId {
+    let mut account = [0u8; 32];
+    account[..4].copy_from_slice(SIBLING_ACCOUNT_PREFIX);
+    account[4..8].copy_from_slice(&para_id.to_le_bytes());
+    account.into()
+}
+
+/// Sibling Parachain Origin
+///
+/// Treats the signed origins of [`sibling_account`]s as the origins of their sibling parachains.
+pub struct EnsureSiblingParachain;
+
+impl EnsureOrigin<Origin> for EnsureSiblingParachain {
+    type Success = u32;
+
+    fn try_origin(o: Origin) -> Result<u32, Origin> {
+        Into::<Result<RawOrigin<AccountId>, Origin>>::into(o).and_then(|o| match o {
+            RawOrigin::Signed(account) => {
+                let bytes: &[u8; 32] = account.as_ref();
+                if bytes.starts_with(SIBLING_ACCOUNT_PREFIX) {
+                    Ok(u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]))
+                } else {
+                    Err(RawOrigin::Signed(account).into())
+                }
+            }
+            o => Err(o.into()),
+        })
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn successful_origin() -> Origin {
+        RawOrigin::Signed(sibling_account(2015)).into()
+    }
+}
+
+ord_parameter_types! {
+    pub const MintCapAdmin: AccountId = AccountId::new([2u8; 32]);
 }
 
 impl pallet_asset_manager::Config for Runtime {
@@ -214,7 +319,18 @@ impl pallet_asset_manager::Config for Runtime {
     type Balance = Balance;
     type Location = AssetLocation;
     type AssetConfig = MantaAssetConfig;
+    type CollectionId = u32;
+    type ItemId = u32;
+    type NonFungibles = Uniques;
     type ModifierOrigin = EnsureRoot<AccountId>;
+    type MintCapOrigin = EnsureSignedBy<MintCapAdmin, AccountId>;
+    type MaxLocationAliases = ConstU32<4>;
+    type Currency = Balances;
+    type ProposalDeposit = ConstU128<1_000>;
+    type ProposalLifetime = ConstU32<100>;
+    type OnProposalSlash = ();
+    type SiblingOrigin = EnsureSiblingParachain;
+    type MaxSiblingRequests = ConstU32<4>;
     type PalletId = AssetManagerPalletId;
     type WeightInfo = ();
 }
@@ -232,17 +348,25 @@ construct_runtime!(
         Assets: pallet_assets::{Pallet, Storage, Event<T>} = 1,
         AssetManager: pallet_asset_manager::{Pallet, Call, Storage, Event<T>} = 2,
         Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>} = 3,
+        Uniques: pallet_uniques::{Pallet, Call, Storage, Event<T>} = 4,
     }
 );
 
 pub const PALLET_BALANCES_INDEX: u8 = 3;
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
+    new_test_ext_with_assets(Vec::new())
+}
+
+pub fn new_test_ext_with_assets(
+    assets: Vec<pallet_asset_manager::GenesisAsset<AccountId, Balance>>,
+) -> sp_io::TestExternalities {
     let mut t = frame_system::GenesisConfig::default()
         .build_storage::<Runtime>()
         .unwrap();
     pallet_asset_manager::GenesisConfig::<Runtime> {
         start_id: <MantaAssetConfig as AssetConfig<Runtime>>::StartNonNativeAssetId::get(),
+        assets,
     }
     .assimilate_storage(&mut t)
     .unwrap();
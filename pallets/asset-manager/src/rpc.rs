@@ -0,0 +1,554 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Asset Manager RPC Interfaces
+
+use crate::{
+    runtime::{AssetDestroyApi, AssetRegistryApi},
+    AssetDestroyBlocker, AssetRegistryEntry,
+};
+use codec::{Codec, Decode, Encode};
+use core::marker::PhantomData;
+use frame_support::WeakBoundedVec;
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use manta_primitives::{
+    assets::{AssetLocation, AssetRegistryMetadata},
+    types::Balance,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, H160, H256};
+use sp_runtime::{generic::BlockId, traits::Block};
+use std::sync::Arc;
+use xcm::latest::{Junction, Junctions, MultiLocation, NetworkId};
+
+/// Asset Registry Error Code
+///
+/// Asset manager error codes start at `101` to stay distinct from the MantaPay RPC error codes.
+pub const ASSET_REGISTRY_ERROR: i32 = 101;
+
+/// Location Conversion Error Code
+pub const LOCATION_CONVERSION_ERROR: i32 = 102;
+
+/// Maximum number of registry entries returned in a single page
+pub const MAX_PAGE_SIZE: u32 = 256;
+
+/// Location Conversion Error
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LocationConversionError {
+    /// The location is not encoded with a supported XCM version.
+    UnsupportedVersion,
+
+    /// The location contains a plurality junction.
+    UnsupportedJunction,
+
+    /// The location has more junctions than a [`MultiLocation`] can hold.
+    TooManyJunctions,
+
+    /// A key or network name exceeds 32 bytes.
+    KeyTooLong,
+
+    /// The bytes are not a SCALE encoded [`AssetLocation`].
+    InvalidEncoding,
+}
+
+/// Network Id
+///
+/// Human-readable form of [`NetworkId`].
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum JsonNetworkId {
+    /// Any Network
+    Any,
+
+    /// Named Network
+    Named(Bytes),
+
+    /// Polkadot Relay Chain
+    Polkadot,
+
+    /// Kusama Relay Chain
+    Kusama,
+}
+
+impl TryFrom<NetworkId> for JsonNetworkId {
+    type Error = LocationConversionError;
+
+    #[inline]
+    fn try_from(network: NetworkId) -> Result<Self, Self::Error> {
+        Ok(match network {
+            NetworkId::Any => Self::Any,
+            NetworkId::Named(name) => Self::Named(name.to_vec().into()),
+            NetworkId::Polkadot => Self::Polkadot,
+            NetworkId::Kusama => Self::Kusama,
+        })
+    }
+}
+
+impl TryFrom<JsonNetworkId> for NetworkId {
+    type Error = LocationConversionError;
+
+    #[inline]
+    fn try_from(network: JsonNetworkId) -> Result<Self, Self::Error> {
+        Ok(match network {
+            JsonNetworkId::Any => Self::Any,
+            JsonNetworkId::Named(name) => Self::Named(
+                WeakBoundedVec::try_from(name.0)
+                    .map_err(|_| LocationConversionError::KeyTooLong)?,
+            ),
+            JsonNetworkId::Polkadot => Self::Polkadot,
+            JsonNetworkId::Kusama => Self::Kusama,
+        })
+    }
+}
+
+/// Junction
+///
+/// Human-readable form of [`Junction`] which does not support plurality junctions.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum JsonJunction {
+    /// Parachain
+    Parachain(u32),
+
+    /// 32-byte Account Id
+    AccountId32 {
+        /// Network
+        network: JsonNetworkId,
+
+        /// Account Id
+        id: H256,
+    },
+
+    /// Account Index
+    AccountIndex64 {
+        /// Network
+        network: JsonNetworkId,
+
+        /// Account Index
+        index: u64,
+    },
+
+    /// 20-byte Account Key
+    AccountKey20 {
+        /// Network
+        network: JsonNetworkId,
+
+        /// Account Key
+        key: H160,
+    },
+
+    /// Pallet Instance
+    PalletInstance(u8),
+
+    /// General Index
+    GeneralIndex(u128),
+
+    /// General Key
+    GeneralKey(Bytes),
+
+    /// Only Child
+    OnlyChild,
+}
+
+impl TryFrom<Junction> for JsonJunction {
+    type Error = LocationConversionError;
+
+    #[inline]
+    fn try_from(junction: Junction) -> Result<Self, Self::Error> {
+        Ok(match junction {
+            Junction::Parachain(para_id) => Self::Parachain(para_id),
+            Junction::AccountId32 { network, id } => Self::AccountId32 {
+                network: network.try_into()?,
+                id: id.into(),
+            },
+            Junction::AccountIndex64 { network, index } => Self::AccountIndex64 {
+                network: network.try_into()?,
+                index,
+            },
+            Junction::AccountKey20 { network, key } => Self::AccountKey20 {
+                network: network.try_into()?,
+                key: key.into(),
+            },
+            Junction::PalletInstance(index) => Self::PalletInstance(index),
+            Junction::GeneralIndex(index) => Self::GeneralIndex(index),
+            Junction::GeneralKey(key) => Self::GeneralKey(key.to_vec().into()),
+            Junction::OnlyChild => Self::OnlyChild,
+            Junction::Plurality { .. } => return Err(LocationConversionError::UnsupportedJunction),
+        })
+    }
+}
+
+impl TryFrom<JsonJunction> for Junction {
+    type Error = LocationConversionError;
+
+    #[inline]
+    fn try_from(junction: JsonJunction) -> Result<Self, Self::Error> {
+        Ok(match junction {
+            JsonJunction::Parachain(para_id) => Self::Parachain(para_id),
+            JsonJunction::AccountId32 { network, id } => Self::AccountId32 {
+                network: network.try_into()?,
+                id: id.into(),
+            },
+            JsonJunction::AccountIndex64 { network, index } => Self::AccountIndex64 {
+                network: network.try_into()?,
+                index,
+            },
+            JsonJunction::AccountKey20 { network, key } => Self::AccountKey20 {
+                network: network.try_into()?,
+                key: key.into(),
+            },
+            JsonJunction::PalletInstance(index) => Self::PalletInstance(index),
+            JsonJunction::GeneralIndex(index) => Self::GeneralIndex(index),
+            JsonJunction::GeneralKey(key) => Self::GeneralKey(
+                WeakBoundedVec::try_from(key.0).map_err(|_| LocationConversionError::KeyTooLong)?,
+            ),
+            JsonJunction::OnlyChild => Self::OnlyChild,
+        })
+    }
+}
+
+/// Location
+///
+/// Human-readable form of a [`MultiLocation`], listing its interior junctions from the outermost
+/// to the innermost one.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonLocation {
+    /// Number of parent hops
+    pub parents: u8,
+
+    /// Interior Junctions
+    pub interior: Vec<JsonJunction>,
+}
+
+impl TryFrom<MultiLocation> for JsonLocation {
+    type Error = LocationConversionError;
+
+    #[inline]
+    fn try_from(location: MultiLocation) -> Result<Self, Self::Error> {
+        Ok(Self {
+            parents: location.parents,
+            interior: location
+                .interior
+                .iter()
+                .cloned()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl TryFrom<JsonLocation> for MultiLocation {
+    type Error = LocationConversionError;
+
+    #[inline]
+    fn try_from(location: JsonLocation) -> Result<Self, Self::Error> {
+        let interior =
+            location
+                .interior
+                .into_iter()
+                .try_fold(Junctions::Here, |interior, junction| {
+                    interior
+                        .pushed_with(junction.try_into()?)
+                        .map_err(|_| LocationConversionError::TooManyJunctions)
+                })?;
+        Ok(MultiLocation::new(location.parents, interior))
+    }
+}
+
+impl TryFrom<AssetLocation> for JsonLocation {
+    type Error = LocationConversionError;
+
+    #[inline]
+    fn try_from(location: AssetLocation) -> Result<Self, Self::Error> {
+        Option::<MultiLocation>::from(location)
+            .ok_or(LocationConversionError::UnsupportedVersion)?
+            .try_into()
+    }
+}
+
+impl TryFrom<JsonLocation> for AssetLocation {
+    type Error = LocationConversionError;
+
+    #[inline]
+    fn try_from(location: JsonLocation) -> Result<Self, Self::Error> {
+        Ok(MultiLocation::try_from(location)?.into())
+    }
+}
+
+/// Registry Entry
+///
+/// Human-readable form of an [`AssetRegistryEntry`].
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegistryEntry<AssetId> {
+    /// Asset Id
+    pub asset_id: AssetId,
+
+    /// Location of the asset, if any
+    pub location: Option<JsonLocation>,
+
+    /// Asset Name
+    pub name: String,
+
+    /// Asset Symbol
+    pub symbol: String,
+
+    /// Number of Decimals
+    pub decimals: u8,
+
+    /// Minimum Balance
+    pub min_balance: Balance,
+
+    /// Frozen Flag
+    pub is_frozen: bool,
+
+    /// Sufficiency Flag
+    pub is_sufficient: bool,
+
+    /// XCM transfer cost of the asset, if set
+    pub units_per_second: Option<u128>,
+
+    /// Minimum XCM execution fee paid on the reserve chain of the asset, if set
+    pub min_xcm_fee: Option<u128>,
+
+    /// Parachain the asset is located at, if any
+    pub para_id: Option<u32>,
+
+    /// Number of assets associated to `para_id`
+    pub para_asset_count: Option<u32>,
+}
+
+impl<AssetId> TryFrom<AssetRegistryEntry<AssetId, AssetLocation, AssetRegistryMetadata<Balance>>>
+    for RegistryEntry<AssetId>
+{
+    type Error = LocationConversionError;
+
+    #[inline]
+    fn try_from(
+        entry: AssetRegistryEntry<AssetId, AssetLocation, AssetRegistryMetadata<Balance>>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            asset_id: entry.asset_id,
+            location: entry.location.map(TryInto::try_into).transpose()?,
+            name: String::from_utf8_lossy(&entry.metadata.metadata.name).into_owned(),
+            symbol: String::from_utf8_lossy(&entry.metadata.metadata.symbol).into_owned(),
+            decimals: entry.metadata.metadata.decimals,
+            min_balance: entry.metadata.min_balance,
+            is_frozen: entry.metadata.metadata.is_frozen,
+            is_sufficient: entry.metadata.is_sufficient,
+            units_per_second: entry.units_per_second,
+            min_xcm_fee: entry.min_xcm_fee,
+            para_id: entry.para_id,
+            para_asset_count: entry.para_asset_count,
+        })
+    }
+}
+
+/// Asset Registry API
+#[rpc(server)]
+pub trait RegistryApi<BlockHash, AssetId> {
+    /// Returns at most `limit` registry entries in storage order, starting after the asset id
+    /// `start` if given, at the block `at` or at the last finalized block if `at` is not given.
+    ///
+    /// Pages are capped at [`MAX_PAGE_SIZE`] entries. Passing the last asset id of a page as
+    /// `start` returns the next page.
+    #[method(name = "assetManager_registry_entries", blocking)]
+    fn registry_entries(
+        &self,
+        start: Option<AssetId>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<RegistryEntry<AssetId>>>;
+
+    /// Returns the registry entry of `asset_id`.
+    #[method(name = "assetManager_registry_entry", blocking)]
+    fn registry_entry(
+        &self,
+        asset_id: AssetId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<RegistryEntry<AssetId>>>;
+
+    /// Returns the registry entry of the asset at `location`, which can be its canonical
+    /// location or an active alias.
+    #[method(name = "assetManager_registry_entry_by_location", blocking)]
+    fn registry_entry_by_location(
+        &self,
+        location: JsonLocation,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<RegistryEntry<AssetId>>>;
+
+    /// Returns everything that blocks the destruction of `asset_id`, which is empty if the asset
+    /// can be destroyed.
+    #[method(name = "assetManager_destroy_blockers", blocking)]
+    fn destroy_blockers(
+        &self,
+        asset_id: AssetId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<AssetDestroyBlocker<Balance>>>;
+
+    /// Returns the SCALE encoding of `location` as an [`AssetLocation`].
+    #[method(name = "assetManager_encode_location")]
+    fn encode_location(&self, location: JsonLocation) -> RpcResult<Bytes>;
+
+    /// Returns the human-readable form of the SCALE encoded [`AssetLocation`] `location`.
+    #[method(name = "assetManager_decode_location")]
+    fn decode_location(&self, location: Bytes) -> RpcResult<JsonLocation>;
+}
+
+/// Asset Registry RPC API Implementation
+pub struct Registry<B, C> {
+    /// Client
+    client: Arc<C>,
+
+    /// Type Parameter Marker
+    __: PhantomData<B>,
+}
+
+impl<B, C> Registry<B, C> {
+    /// Builds a new [`Registry`] RPC API implementation.
+    #[inline]
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            __: PhantomData,
+        }
+    }
+}
+
+impl<B, C> Registry<B, C>
+where
+    B: Block,
+    C: HeaderBackend<B>,
+{
+    /// Returns the block `at`, defaulting to the last finalized block.
+    #[inline]
+    fn at(&self, at: Option<B::Hash>) -> BlockId<B> {
+        BlockId::hash(at.unwrap_or_else(|| self.client.info().finalized_hash))
+    }
+}
+
+/// Builds the RPC error for a failed runtime API call.
+#[inline]
+fn registry_error<E>(err: E) -> jsonrpsee::core::Error
+where
+    E: core::fmt::Debug,
+{
+    CallError::Custom(ErrorObject::owned(
+        ASSET_REGISTRY_ERROR,
+        "Unable to query the asset registry",
+        Some(format!("{err:?}")),
+    ))
+    .into()
+}
+
+/// Builds the RPC error for a failed location conversion.
+#[inline]
+fn conversion_error(err: LocationConversionError) -> jsonrpsee::core::Error {
+    CallError::Custom(ErrorObject::owned(
+        LOCATION_CONVERSION_ERROR,
+        "Unable to convert the location",
+        Some(format!("{err:?}")),
+    ))
+    .into()
+}
+
+#[async_trait]
+impl<B, C, AssetId> RegistryApiServer<B::Hash, AssetId> for Registry<B, C>
+where
+    B: Block,
+    C: 'static + ProvideRuntimeApi<B> + HeaderBackend<B>,
+    C::Api: AssetRegistryApi<B, AssetId, AssetLocation, AssetRegistryMetadata<Balance>>
+        + AssetDestroyApi<B, AssetId, Balance>,
+    AssetId: 'static + Codec + DeserializeOwned + Send + Serialize + Sync,
+{
+    #[inline]
+    fn registry_entries(
+        &self,
+        start: Option<AssetId>,
+        limit: u32,
+        at: Option<B::Hash>,
+    ) -> RpcResult<Vec<RegistryEntry<AssetId>>> {
+        self.client
+            .runtime_api()
+            .asset_registry_entries(&self.at(at), start, limit.min(MAX_PAGE_SIZE))
+            .map_err(registry_error)?
+            .into_iter()
+            .map(|entry| entry.try_into().map_err(conversion_error))
+            .collect()
+    }
+
+    #[inline]
+    fn registry_entry(
+        &self,
+        asset_id: AssetId,
+        at: Option<B::Hash>,
+    ) -> RpcResult<Option<RegistryEntry<AssetId>>> {
+        self.client
+            .runtime_api()
+            .asset_registry_entry(&self.at(at), asset_id)
+            .map_err(registry_error)?
+            .map(|entry| entry.try_into().map_err(conversion_error))
+            .transpose()
+    }
+
+    #[inline]
+    fn registry_entry_by_location(
+        &self,
+        location: JsonLocation,
+        at: Option<B::Hash>,
+    ) -> RpcResult<Option<RegistryEntry<AssetId>>> {
+        let location = location.try_into().map_err(conversion_error)?;
+        self.client
+            .runtime_api()
+            .asset_registry_entry_by_location(&self.at(at), location)
+            .map_err(registry_error)?
+            .map(|entry| entry.try_into().map_err(conversion_error))
+            .transpose()
+    }
+
+    #[inline]
+    fn destroy_blockers(
+        &self,
+        asset_id: AssetId,
+        at: Option<B::Hash>,
+    ) -> RpcResult<Vec<AssetDestroyBlocker<Balance>>> {
+        self.client
+            .runtime_api()
+            .destroy_blockers(&self.at(at), asset_id)
+            .map_err(registry_error)
+    }
+
+    #[inline]
+    fn encode_location(&self, location: JsonLocation) -> RpcResult<Bytes> {
+        let location = AssetLocation::try_from(location).map_err(conversion_error)?;
+        Ok(location.encode().into())
+    }
+
+    #[inline]
+    fn decode_location(&self, location: Bytes) -> RpcResult<JsonLocation> {
+        AssetLocation::decode(&mut location.as_ref())
+            .map_err(|_| conversion_error(LocationConversionError::InvalidEncoding))?
+            .try_into()
+            .map_err(conversion_error)
+    }
+}
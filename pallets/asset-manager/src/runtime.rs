@@ -0,0 +1,70 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Asset Manager Runtime APIs
+
+use crate::{AssetDestroyBlocker, AssetRegistryEntry, DestinationPolicy, MintAccounting};
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait AssetRegistryApi<AssetId, Location, Metadata>
+    where
+        AssetId: Codec,
+        Location: Codec,
+        Metadata: Codec,
+    {
+        /// Returns at most `limit` registry entries in storage order, starting after the asset
+        /// id `start` if given.
+        fn asset_registry_entries(start: Option<AssetId>, limit: u32) -> Vec<AssetRegistryEntry<AssetId, Location, Metadata>>;
+
+        /// Returns the registry entry of `asset_id`.
+        fn asset_registry_entry(asset_id: AssetId) -> Option<AssetRegistryEntry<AssetId, Location, Metadata>>;
+
+        /// Returns the registry entry of the asset at `location`.
+        fn asset_registry_entry_by_location(location: Location) -> Option<AssetRegistryEntry<AssetId, Location, Metadata>>;
+    }
+
+    pub trait AssetMintApi<AssetId, Balance>
+    where
+        AssetId: Codec,
+        Balance: Codec,
+    {
+        /// Returns the mint cap and the governance minted and burned totals of `asset_id`.
+        fn mint_accounting(asset_id: AssetId) -> MintAccounting<Balance>;
+    }
+
+    pub trait AssetDestroyApi<AssetId, Balance>
+    where
+        AssetId: Codec,
+        Balance: Codec,
+    {
+        /// Returns everything that blocks the destruction of `asset_id`, which is empty if the
+        /// asset can be destroyed.
+        fn destroy_blockers(asset_id: AssetId) -> Vec<AssetDestroyBlocker<Balance>>;
+    }
+
+    pub trait XcmDestinationApi<Location>
+    where
+        Location: Codec,
+    {
+        /// Returns all destination policies of outbound XCM transfers.
+        fn destination_policies() -> Vec<(Location, DestinationPolicy)>;
+
+        /// Returns `true` if outbound XCM transfers to `dest` are allowed.
+        fn is_allowed_destination(dest: Location) -> bool;
+    }
+}
@@ -17,18 +17,34 @@
 //! unit tests for asset-manager
 
 use crate::{
-    self as asset_manager, AssetIdLocation, AssetIdMetadata, Error, LocationAssetId, UnitsPerSecond,
+    self as asset_manager, migrations::LocationNormalizationMigration, AllowedDestParaIds,
+    AssetDestroyBlocker, AssetIdLocation, AssetIdMetadata, AssetIdNonFungible, AssetIdStatus,
+    DestinationPolicy, Error, Event as AssetManagerEvent, IsTrustedTeleporter, LocationAssetId,
+    MintAccounting, NonFungibleAssetId, UnitsPerSecond,
 };
 use asset_manager::mock::*;
+use codec::Encode;
 use frame_support::{
     assert_noop, assert_ok,
-    traits::{fungibles::InspectMetadata, Contains},
+    traits::{
+        fungibles::InspectMetadata,
+        nonfungibles::{Create, Inspect, Mutate},
+        BalanceStatus, Contains, Currency, ExistenceRequirement, GetStorageVersion,
+        OnRuntimeUpgrade, ReservableCurrency, StorageVersion,
+    },
     WeakBoundedVec,
 };
-use manta_primitives::assets::{AssetConfig, AssetLocation, FungibleLedger};
+use manta_primitives::assets::{
+    AssetConfig, AssetIdLocationMap, AssetLocation, AssetStatus, AssetStatusMap, FungibleLedger,
+    FungibleLedgerError, TransferLeg,
+};
 use orml_traits::GetByKey;
-use sp_runtime::traits::BadOrigin;
-use xcm::{latest::prelude::*, VersionedMultiLocation};
+use sp_runtime::{traits::BadOrigin, TokenError};
+use xcm::{
+    latest::prelude::*,
+    v0::{Junction as V0Junction, MultiLocation as V0MultiLocation},
+    VersionedMultiLocation,
+};
 
 pub const ALICE: sp_runtime::AccountId32 = sp_runtime::AccountId32::new([0u8; 32]);
 
@@ -543,3 +559,1433 @@ fn set_min_xcm_fee_should_work() {
         );
     })
 }
+
+#[test]
+fn register_non_fungible_asset_should_work() {
+    let (collection, item) = (0, 7);
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            AssetManager::register_non_fungible_asset(Origin::root(), collection, item),
+            Error::<Runtime>::UnknownNonFungible
+        );
+        assert_ok!(Uniques::create_collection(&collection, &ALICE, &ALICE));
+        assert_ok!(Uniques::mint_into(&collection, &item, &ALICE));
+        assert_noop!(
+            AssetManager::register_non_fungible_asset(Origin::root(), collection, item),
+            Error::<Runtime>::UnrestrictedCollection
+        );
+        let (collection, item) = (1, 7);
+        assert_ok!(Uniques::create_collection(
+            &collection,
+            &AssetManager::account_id(),
+            &AssetManager::account_id()
+        ));
+        assert_ok!(Uniques::mint_into(&collection, &item, &ALICE));
+        assert_noop!(
+            AssetManager::register_non_fungible_asset(
+                Origin::signed([1u8; 32].into()),
+                collection,
+                item
+            ),
+            BadOrigin
+        );
+        let asset_id = <MantaAssetConfig as AssetConfig<Runtime>>::StartNonNativeAssetId::get();
+        assert_ok!(AssetManager::register_non_fungible_asset(
+            Origin::root(),
+            collection,
+            item
+        ));
+        assert_eq!(
+            AssetIdNonFungible::<Runtime>::get(asset_id),
+            Some((collection, item))
+        );
+        assert_eq!(
+            NonFungibleAssetId::<Runtime>::get(collection, item),
+            Some(asset_id)
+        );
+        assert_eq!(AssetManager::next_asset_id(), asset_id + 1);
+        assert_noop!(
+            AssetManager::register_non_fungible_asset(Origin::root(), collection, item),
+            Error::<Runtime>::NonFungibleAlreadyRegistered
+        );
+    });
+}
+
+#[test]
+fn non_fungible_ledger_should_work() {
+    let (collection, item) = (0, 7);
+    let bob: sp_runtime::AccountId32 = [1u8; 32].into();
+    new_test_ext().execute_with(|| {
+        assert_ok!(Uniques::create_collection(
+            &collection,
+            &AssetManager::account_id(),
+            &AssetManager::account_id()
+        ));
+        assert_ok!(Uniques::mint_into(&collection, &item, &ALICE));
+        assert_ok!(AssetManager::register_non_fungible_asset(
+            Origin::root(),
+            collection,
+            item
+        ));
+        let asset_id = AssetManager::non_fungible_asset_id(collection, item).unwrap();
+        type Ledger = <MantaAssetConfig as AssetConfig<Runtime>>::FungibleLedger;
+        assert_ok!(Ledger::can_deposit(asset_id, &bob, 1, false));
+        assert_eq!(
+            Ledger::can_withdraw(asset_id, &ALICE, &2, ExistenceRequirement::KeepAlive),
+            Err(FungibleLedgerError::InvalidNonFungibleAmount(2))
+        );
+        assert_eq!(
+            Ledger::can_withdraw(asset_id, &bob, &1, ExistenceRequirement::KeepAlive),
+            Err(FungibleLedgerError::CannotWithdrawMoreThan(0))
+        );
+        assert!(Ledger::deposit_minting(asset_id, &bob, 1).is_err());
+        assert_ok!(Ledger::transfer(
+            asset_id,
+            &ALICE,
+            &bob,
+            1,
+            ExistenceRequirement::KeepAlive
+        ));
+        assert_eq!(
+            <Uniques as Inspect<_>>::owner(&collection, &item),
+            Some(bob.clone())
+        );
+        assert_eq!(
+            Ledger::transfer(asset_id, &ALICE, &bob, 1, ExistenceRequirement::KeepAlive),
+            Err(FungibleLedgerError::CannotWithdrawMoreThan(0))
+        );
+    });
+}
+
+#[test]
+fn fungible_ledger_reserves_and_holds_should_work() {
+    let asset_metadata = create_asset_metadata("Kusama", "KSM", 12, 1u128, false, true);
+    let source_location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::parent()));
+    let bob: sp_runtime::AccountId32 = [1u8; 32].into();
+    let reason = *b"escrow00";
+    new_test_ext().execute_with(|| {
+        type Ledger = <MantaAssetConfig as AssetConfig<Runtime>>::FungibleLedger;
+        let native_asset_id = <MantaAssetConfig as AssetConfig<Runtime>>::NativeAssetId::get();
+        Balances::make_free_balance_be(&ALICE, 1_000);
+        Balances::make_free_balance_be(&bob, 1_000);
+        assert_ok!(Ledger::reserve(native_asset_id, &ALICE, 300));
+        assert_ok!(Ledger::hold(native_asset_id, &reason, &ALICE, 200));
+        assert_eq!(Balances::free_balance(&ALICE), 500);
+        assert_eq!(Balances::reserved_balance(&ALICE), 500);
+        // Like for non-native assets, holds are not part of the reserved balance
+        assert_eq!(Ledger::reserved_balance(native_asset_id, &ALICE), Ok(300));
+        assert_eq!(
+            Ledger::unreserve(native_asset_id, &ALICE, 301),
+            Err(FungibleLedgerError::CannotUnreserveMoreThan(300))
+        );
+        assert_eq!(
+            Ledger::balance_on_hold(native_asset_id, &reason, &ALICE),
+            Ok(200)
+        );
+        assert_eq!(
+            Ledger::release(native_asset_id, &reason, &ALICE, 201),
+            Err(FungibleLedgerError::CannotUnreserveMoreThan(200))
+        );
+        assert_ok!(Ledger::transfer_on_hold(
+            native_asset_id,
+            &reason,
+            &ALICE,
+            &bob,
+            150,
+            BalanceStatus::Reserved
+        ));
+        assert_eq!(
+            Ledger::balance_on_hold(native_asset_id, &reason, &bob),
+            Ok(150)
+        );
+        assert_ok!(Ledger::release(native_asset_id, &reason, &ALICE, 50));
+        assert_ok!(Ledger::unreserve(native_asset_id, &ALICE, 300));
+        assert_eq!(Balances::free_balance(&ALICE), 850);
+
+        let asset_id = <MantaAssetConfig as AssetConfig<Runtime>>::StartNonNativeAssetId::get();
+        assert_ok!(AssetManager::register_asset(
+            Origin::root(),
+            source_location,
+            asset_metadata
+        ));
+        assert_ok!(Ledger::deposit_minting(asset_id, &ALICE, 1_000));
+        assert_ok!(Ledger::reserve(asset_id, &ALICE, 300));
+        assert_ok!(Ledger::hold(asset_id, &reason, &ALICE, 200));
+        assert_eq!(Assets::balance(asset_id, &ALICE), 500);
+        assert_eq!(Ledger::reserved_balance(asset_id, &ALICE), Ok(300));
+        assert_eq!(Ledger::balance_on_hold(asset_id, &reason, &ALICE), Ok(200));
+        assert_eq!(
+            Ledger::unreserve(asset_id, &ALICE, 301),
+            Err(FungibleLedgerError::CannotUnreserveMoreThan(300))
+        );
+        assert_ok!(Ledger::repatriate_reserved(
+            asset_id,
+            &ALICE,
+            &bob,
+            100,
+            BalanceStatus::Free
+        ));
+        assert_eq!(Assets::balance(asset_id, &bob), 100);
+        assert_ok!(Ledger::transfer_on_hold(
+            asset_id,
+            &reason,
+            &ALICE,
+            &bob,
+            150,
+            BalanceStatus::Reserved
+        ));
+        assert_eq!(Ledger::balance_on_hold(asset_id, &reason, &bob), Ok(150));
+        assert_ok!(Ledger::release(asset_id, &reason, &bob, 150));
+        assert_eq!(Assets::balance(asset_id, &bob), 250);
+        assert_ok!(Ledger::unreserve(asset_id, &ALICE, 200));
+        assert_ok!(Ledger::release(asset_id, &reason, &ALICE, 50));
+        assert_eq!(Assets::balance(asset_id, &ALICE), 750);
+        assert_eq!(Ledger::reserved_balance(asset_id, &ALICE), Ok(0));
+    });
+}
+
+#[test]
+fn fungible_ledger_holds_below_minimum_balance_should_fail() {
+    let asset_metadata = create_asset_metadata("Kusama", "KSM", 12, 100u128, false, true);
+    let source_location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::parent()));
+    let bob: sp_runtime::AccountId32 = [1u8; 32].into();
+    let reason = *b"escrow00";
+    new_test_ext().execute_with(|| {
+        type Ledger = <MantaAssetConfig as AssetConfig<Runtime>>::FungibleLedger;
+        let asset_id = <MantaAssetConfig as AssetConfig<Runtime>>::StartNonNativeAssetId::get();
+        assert_ok!(AssetManager::register_asset(
+            Origin::root(),
+            source_location,
+            asset_metadata
+        ));
+        assert_ok!(Ledger::deposit_minting(asset_id, &ALICE, 1_000));
+        assert_eq!(
+            Ledger::reserve(asset_id, &ALICE, 99),
+            Err(FungibleLedgerError::HoldBelowMinimum(100))
+        );
+        assert_eq!(
+            Ledger::hold(asset_id, &reason, &ALICE, 99),
+            Err(FungibleLedgerError::HoldBelowMinimum(100))
+        );
+        // Once the holding account exists, smaller amounts can be added to it
+        assert_ok!(Ledger::reserve(asset_id, &ALICE, 100));
+        assert_ok!(Ledger::reserve(asset_id, &ALICE, 1));
+        assert_eq!(Ledger::reserved_balance(asset_id, &ALICE), Ok(101));
+        // Reserves keep the account of the owner alive
+        assert!(Ledger::reserve(asset_id, &ALICE, 850).is_err());
+        assert_eq!(
+            Ledger::repatriate_reserved(asset_id, &ALICE, &bob, 50, BalanceStatus::Reserved),
+            Err(FungibleLedgerError::HoldBelowMinimum(100))
+        );
+        // Releasing part of a reserve releases the dust left behind in the holding account
+        assert_ok!(Ledger::unreserve(asset_id, &ALICE, 50));
+        assert_eq!(Ledger::reserved_balance(asset_id, &ALICE), Ok(0));
+        assert_eq!(Assets::balance(asset_id, &ALICE), 1_000);
+    });
+}
+
+#[test]
+fn fungible_ledger_batch_transfers_should_be_atomic() {
+    let asset_metadata = create_asset_metadata("Kusama", "KSM", 12, 1u128, false, true);
+    let source_location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::parent()));
+    let bob: sp_runtime::AccountId32 = [1u8; 32].into();
+    new_test_ext().execute_with(|| {
+        type Ledger = <MantaAssetConfig as AssetConfig<Runtime>>::FungibleLedger;
+        let native_asset_id = <MantaAssetConfig as AssetConfig<Runtime>>::NativeAssetId::get();
+        let asset_id = <MantaAssetConfig as AssetConfig<Runtime>>::StartNonNativeAssetId::get();
+        assert_ok!(AssetManager::register_asset(
+            Origin::root(),
+            source_location,
+            asset_metadata
+        ));
+        Balances::make_free_balance_be(&ALICE, 1_000);
+        assert_ok!(Ledger::deposit_minting(asset_id, &bob, 1_000));
+        let leg = |asset_id, source, destination, amount| TransferLeg {
+            asset_id,
+            source,
+            destination,
+            amount,
+        };
+        // The second leg cannot be covered by the balance of `bob`.
+        assert_eq!(
+            Ledger::transfer_batch(
+                &[
+                    leg(native_asset_id, ALICE, bob.clone(), 500),
+                    leg(asset_id, bob.clone(), ALICE, 2_000),
+                ],
+                ExistenceRequirement::AllowDeath
+            ),
+            Err(FungibleLedgerError::InvalidTransferLeg(
+                1,
+                TokenError::NoFunds.into()
+            ))
+        );
+        // Both legs can be covered on their own, but not together.
+        assert!(matches!(
+            Ledger::transfer_batch(
+                &[
+                    leg(asset_id, bob.clone(), ALICE, 600),
+                    leg(asset_id, bob.clone(), ALICE, 600),
+                ],
+                ExistenceRequirement::AllowDeath
+            ),
+            Err(FungibleLedgerError::InvalidTransferLeg(1, _))
+        ));
+        assert_eq!(Balances::free_balance(&ALICE), 1_000);
+        assert_eq!(Assets::balance(asset_id, &ALICE), 0);
+        assert_eq!(Assets::balance(asset_id, &bob), 1_000);
+        assert_ok!(Ledger::transfer_batch(
+            &[
+                leg(native_asset_id, ALICE, bob.clone(), 500),
+                leg(asset_id, bob.clone(), ALICE, 600),
+            ],
+            ExistenceRequirement::AllowDeath
+        ));
+        assert_eq!(Balances::free_balance(&ALICE), 500);
+        assert_eq!(Balances::free_balance(&bob), 500);
+        assert_eq!(Assets::balance(asset_id, &ALICE), 600);
+        assert_eq!(Assets::balance(asset_id, &bob), 400);
+    });
+}
+
+#[test]
+fn destroy_asset_should_work() {
+    let para_id = 2015;
+    let asset_metadata = create_asset_metadata("Manta", "MANTA", 18, 1u128, false, true);
+    let location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+        1,
+        X2(
+            Parachain(para_id),
+            GeneralKey(WeakBoundedVec::force_from(b"MANTA".to_vec(), None)),
+        ),
+    )));
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let native_asset_id = <MantaAssetConfig as AssetConfig<Runtime>>::NativeAssetId::get();
+        assert_noop!(
+            AssetManager::freeze_asset(Origin::root(), native_asset_id),
+            Error::<Runtime>::CannotDestroyNativeAsset
+        );
+        assert_eq!(
+            AssetManager::check_destroy_asset(native_asset_id),
+            Err(vec![AssetDestroyBlocker::NativeAsset])
+        );
+        let asset_id = AssetManager::next_asset_id();
+        assert_eq!(
+            AssetManager::check_destroy_asset(asset_id),
+            Err(vec![AssetDestroyBlocker::UnknownAsset])
+        );
+        assert_ok!(AssetManager::register_asset(
+            Origin::root(),
+            location.clone(),
+            asset_metadata
+        ));
+        assert_ok!(AssetManager::set_units_per_second(
+            Origin::root(),
+            asset_id,
+            125
+        ));
+        assert_eq!(AllowedDestParaIds::<Runtime>::get(para_id), Some(1));
+        assert_eq!(
+            AssetManager::check_destroy_asset(asset_id),
+            Err(vec![AssetDestroyBlocker::NotFrozen])
+        );
+        assert_noop!(
+            AssetManager::destroy_asset(Origin::root(), asset_id),
+            Error::<Runtime>::AssetNotFrozen
+        );
+        assert_noop!(
+            AssetManager::freeze_asset(Origin::signed([1u8; 32].into()), asset_id),
+            BadOrigin
+        );
+        assert_ok!(AssetManager::freeze_asset(Origin::root(), asset_id));
+        assert_noop!(
+            AssetManager::freeze_asset(Origin::root(), asset_id),
+            Error::<Runtime>::AssetAlreadyFrozen
+        );
+        assert_eq!(AssetManager::check_destroy_asset(asset_id), Ok(()));
+        assert_ok!(AssetManager::destroy_asset(Origin::root(), asset_id));
+        System::assert_last_event(
+            AssetManagerEvent::AssetDestroyed {
+                asset_id,
+                location: location.clone(),
+            }
+            .into(),
+        );
+        assert_eq!(AssetIdLocation::<Runtime>::get(asset_id), None);
+        assert_eq!(LocationAssetId::<Runtime>::get(&location), None);
+        assert_eq!(AssetIdMetadata::<Runtime>::get(asset_id), None);
+        assert_eq!(UnitsPerSecond::<Runtime>::get(asset_id), None);
+        assert_eq!(AssetManager::frozen_assets(asset_id), None);
+        assert_eq!(AllowedDestParaIds::<Runtime>::get(para_id), None);
+        assert_eq!(
+            AssetManager::check_destroy_asset(asset_id),
+            Err(vec![AssetDestroyBlocker::UnknownAsset])
+        );
+    });
+}
+
+#[test]
+fn destroy_asset_with_outstanding_supply_should_not_work() {
+    let asset_metadata = create_asset_metadata("Kusama", "KSM", 12, 1u128, false, true);
+    let location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::parent()));
+    new_test_ext().execute_with(|| {
+        let asset_id = AssetManager::next_asset_id();
+        assert_ok!(AssetManager::register_asset(
+            Origin::root(),
+            location,
+            asset_metadata
+        ));
+        assert_ok!(AssetManager::mint_asset(
+            Origin::root(),
+            asset_id,
+            ALICE,
+            1_000
+        ));
+        assert_ok!(Assets::approve_transfer(
+            Origin::signed(ALICE),
+            asset_id,
+            [1u8; 32].into(),
+            100
+        ));
+        assert_eq!(
+            AssetManager::check_destroy_asset(asset_id),
+            Err(vec![
+                AssetDestroyBlocker::NotFrozen,
+                AssetDestroyBlocker::OutstandingSupply(1_000),
+                AssetDestroyBlocker::OutstandingAccounts(1),
+                AssetDestroyBlocker::OutstandingApprovals(1),
+            ])
+        );
+        assert_ok!(AssetManager::freeze_asset(Origin::root(), asset_id));
+        assert_eq!(
+            AssetManager::check_destroy_asset(asset_id),
+            Err(vec![
+                AssetDestroyBlocker::OutstandingSupply(1_000),
+                AssetDestroyBlocker::OutstandingAccounts(1),
+                AssetDestroyBlocker::OutstandingApprovals(1),
+            ])
+        );
+        assert_noop!(
+            AssetManager::destroy_asset(Origin::root(), asset_id),
+            Error::<Runtime>::OutstandingAssetSupply
+        );
+    });
+}
+
+#[test]
+fn set_asset_status_should_work() {
+    let asset_metadata = create_asset_metadata("Kusama", "KSM", 12, 1u128, false, true);
+    let location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::parent()));
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let asset_id = AssetManager::next_asset_id();
+        assert_noop!(
+            AssetManager::set_asset_status(Origin::root(), asset_id, AssetStatus::Deprecated),
+            Error::<Runtime>::UpdateNonExistentAsset
+        );
+        assert_ok!(AssetManager::register_asset(
+            Origin::root(),
+            location,
+            asset_metadata
+        ));
+        assert_eq!(AssetManager::status(&asset_id), AssetStatus::Active);
+        assert_noop!(
+            AssetManager::set_asset_status(
+                Origin::signed([1u8; 32].into()),
+                asset_id,
+                AssetStatus::Deprecated
+            ),
+            BadOrigin
+        );
+        for status in [
+            AssetStatus::XcmSuspended,
+            AssetStatus::ShieldingSuspended,
+            AssetStatus::Deprecated,
+        ] {
+            assert_ok!(AssetManager::set_asset_status(
+                Origin::root(),
+                asset_id,
+                status
+            ));
+            System::assert_last_event(
+                AssetManagerEvent::AssetStatusUpdated { asset_id, status }.into(),
+            );
+            assert_eq!(AssetManager::status(&asset_id), status);
+        }
+        let status = AssetManager::status(&asset_id);
+        assert!(!status.can_receive_over_xcm());
+        assert!(status.can_send_over_xcm());
+        assert!(!status.can_shield());
+        assert!(status.can_unshield());
+        assert_ok!(AssetManager::set_asset_status(
+            Origin::root(),
+            asset_id,
+            AssetStatus::Active
+        ));
+        assert!(!AssetIdStatus::<Runtime>::contains_key(asset_id));
+        assert_eq!(AssetManager::status(&asset_id), AssetStatus::Active);
+    });
+}
+
+#[test]
+fn location_aliases_should_work() {
+    let para_id = 2015;
+    let asset_metadata = create_asset_metadata("Manta", "MANTA", 18, 1u128, false, true);
+    let old_location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+        1,
+        X2(
+            Parachain(para_id),
+            GeneralKey(WeakBoundedVec::force_from(b"MANTA".to_vec(), None)),
+        ),
+    )));
+    let new_location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+        1,
+        X2(Parachain(para_id), PalletInstance(PALLET_BALANCES_INDEX)),
+    )));
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let asset_id = AssetManager::next_asset_id();
+        assert_noop!(
+            AssetManager::add_location_alias(Origin::root(), asset_id, old_location.clone(), None),
+            Error::<Runtime>::UpdateNonExistentAsset
+        );
+        assert_ok!(AssetManager::register_asset(
+            Origin::root(),
+            old_location.clone(),
+            asset_metadata.clone()
+        ));
+        // Move the asset to its new location and keep the old one around until block 10
+        assert_ok!(AssetManager::update_asset_location(
+            Origin::root(),
+            asset_id,
+            new_location.clone()
+        ));
+        assert_noop!(
+            AssetManager::add_location_alias(
+                Origin::signed([1u8; 32].into()),
+                asset_id,
+                old_location.clone(),
+                Some(10)
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            AssetManager::add_location_alias(Origin::root(), asset_id, new_location.clone(), None),
+            Error::<Runtime>::LocationAlreadyExists
+        );
+        assert_ok!(AssetManager::add_location_alias(
+            Origin::root(),
+            asset_id,
+            old_location.clone(),
+            Some(10)
+        ));
+        System::assert_last_event(
+            AssetManagerEvent::LocationAliasAdded {
+                asset_id,
+                location: old_location.clone(),
+                cutover: Some(10),
+            }
+            .into(),
+        );
+        assert_eq!(AssetManager::asset_id(&old_location), Some(asset_id));
+        assert_eq!(AssetManager::asset_id(&new_location), Some(asset_id));
+        assert_eq!(
+            AssetManager::location(&asset_id),
+            Some(new_location.clone())
+        );
+        // Aliases cannot be registered as new assets
+        assert_noop!(
+            AssetManager::register_asset(
+                Origin::root(),
+                old_location.clone(),
+                asset_metadata.clone()
+            ),
+            Error::<Runtime>::LocationAlreadyExists
+        );
+        // The alias is no longer accepted after the cutover
+        System::set_block_number(10);
+        assert_eq!(AssetManager::asset_id(&old_location), None);
+        assert_eq!(AssetManager::asset_id(&new_location), Some(asset_id));
+        assert_ok!(AssetManager::remove_location_alias(
+            Origin::root(),
+            old_location.clone()
+        ));
+        System::assert_last_event(
+            AssetManagerEvent::LocationAliasRemoved {
+                asset_id,
+                location: old_location.clone(),
+            }
+            .into(),
+        );
+        assert!(AssetManager::location_aliases(asset_id).is_empty());
+        assert_noop!(
+            AssetManager::remove_location_alias(Origin::root(), old_location),
+            Error::<Runtime>::UnknownLocationAlias
+        );
+    });
+}
+
+#[test]
+fn too_many_location_aliases_should_not_work() {
+    let asset_metadata = create_asset_metadata("Kusama", "KSM", 12, 1u128, false, true);
+    let location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::parent()));
+    let alias = |index: u8| {
+        AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+            1,
+            X1(GeneralIndex(index.into())),
+        )))
+    };
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let asset_id = AssetManager::next_asset_id();
+        assert_ok!(AssetManager::register_asset(
+            Origin::root(),
+            location,
+            asset_metadata
+        ));
+        assert_ok!(AssetManager::add_location_alias(
+            Origin::root(),
+            asset_id,
+            alias(0),
+            Some(2)
+        ));
+        for index in 1..4 {
+            assert_ok!(AssetManager::add_location_alias(
+                Origin::root(),
+                asset_id,
+                alias(index),
+                None
+            ));
+        }
+        assert_noop!(
+            AssetManager::add_location_alias(Origin::root(), asset_id, alias(4), None),
+            Error::<Runtime>::TooManyLocationAliases
+        );
+        // Expired aliases are pruned to make room for new ones
+        System::set_block_number(2);
+        assert_ok!(AssetManager::add_location_alias(
+            Origin::root(),
+            asset_id,
+            alias(4),
+            None
+        ));
+        assert_eq!(AssetManager::alias_location_asset_id(alias(0)), None);
+        assert_eq!(AssetManager::location_aliases(asset_id).len(), 4);
+    });
+}
+
+#[test]
+fn asset_registration_proposals_should_work() {
+    let asset_metadata = create_asset_metadata("Kusama", "KSM", 12, 1u128, false, true);
+    let location = |para_id| {
+        AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+            1,
+            X1(Parachain(para_id)),
+        )))
+    };
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&ALICE, 10_000);
+        assert_noop!(
+            AssetManager::propose_asset(Origin::root(), location(1), asset_metadata.clone()),
+            BadOrigin
+        );
+        assert_ok!(AssetManager::propose_asset(
+            Origin::signed(ALICE),
+            location(1),
+            asset_metadata.clone()
+        ));
+        assert_eq!(Balances::reserved_balance(&ALICE), 1_000);
+        let proposal = AssetManager::registration_proposal(0).unwrap();
+        assert_eq!(proposal.deadline, 101);
+        // Only the modifier origin may approve proposals
+        assert_noop!(
+            AssetManager::approve_asset_proposal(Origin::signed(ALICE), 0),
+            BadOrigin
+        );
+        assert_noop!(
+            AssetManager::approve_asset_proposal(Origin::root(), 1),
+            Error::<Runtime>::UnknownProposal
+        );
+        let asset_id = AssetManager::next_asset_id();
+        assert_ok!(AssetManager::approve_asset_proposal(Origin::root(), 0));
+        assert_eq!(AssetManager::registration_proposal(0), None);
+        assert_eq!(AssetIdLocation::<Runtime>::get(asset_id), Some(location(1)));
+        assert_eq!(Balances::reserved_balance(&ALICE), 0);
+        System::assert_last_event(
+            AssetManagerEvent::AssetProposalApproved {
+                proposal_id: 0,
+                asset_id,
+            }
+            .into(),
+        );
+        // A registered location cannot be proposed again
+        assert_noop!(
+            AssetManager::propose_asset(Origin::signed(ALICE), location(1), asset_metadata.clone()),
+            Error::<Runtime>::LocationAlreadyExists
+        );
+        // Proposals cannot be approved after their deadline
+        assert_ok!(AssetManager::propose_asset(
+            Origin::signed(ALICE),
+            location(2),
+            asset_metadata.clone()
+        ));
+        System::set_block_number(102);
+        assert_noop!(
+            AssetManager::approve_asset_proposal(Origin::root(), 1),
+            Error::<Runtime>::ProposalExpired
+        );
+    });
+}
+
+#[test]
+fn rejected_and_expired_proposals_should_release_or_slash_deposit() {
+    let asset_metadata = create_asset_metadata("Kusama", "KSM", 12, 1u128, false, true);
+    let location = |para_id| {
+        AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+            1,
+            X1(Parachain(para_id)),
+        )))
+    };
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&ALICE, 10_000);
+        for para_id in 1..4 {
+            assert_ok!(AssetManager::propose_asset(
+                Origin::signed(ALICE),
+                location(para_id),
+                asset_metadata.clone()
+            ));
+        }
+        assert_eq!(Balances::reserved_balance(&ALICE), 3_000);
+        // Rejecting without slashing returns the deposit
+        assert_ok!(AssetManager::reject_asset_proposal(
+            Origin::root(),
+            0,
+            false
+        ));
+        assert_eq!(Balances::reserved_balance(&ALICE), 2_000);
+        assert_eq!(Balances::free_balance(&ALICE), 8_000);
+        // Rejecting with slashing burns the deposit
+        assert_ok!(AssetManager::reject_asset_proposal(Origin::root(), 1, true));
+        assert_eq!(Balances::reserved_balance(&ALICE), 1_000);
+        assert_eq!(Balances::free_balance(&ALICE), 8_000);
+        System::assert_last_event(
+            AssetManagerEvent::AssetProposalRejected {
+                proposal_id: 1,
+                slashed: true,
+            }
+            .into(),
+        );
+        assert_noop!(
+            AssetManager::reject_asset_proposal(Origin::root(), 1, true),
+            Error::<Runtime>::UnknownProposal
+        );
+        // Anyone can clean up an expired proposal, returning the deposit to the proposer
+        assert_noop!(
+            AssetManager::remove_expired_proposal(Origin::signed(ALICE), 2),
+            Error::<Runtime>::ProposalNotExpired
+        );
+        System::set_block_number(102);
+        assert_ok!(AssetManager::remove_expired_proposal(
+            Origin::signed(sp_runtime::AccountId32::new([1u8; 32])),
+            2
+        ));
+        assert_eq!(AssetManager::registration_proposal(2), None);
+        assert_eq!(Balances::reserved_balance(&ALICE), 0);
+        assert_eq!(Balances::free_balance(&ALICE), 9_000);
+    });
+}
+
+#[test]
+fn registry_entries_should_work() {
+    let location = |para_id| {
+        AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+            1,
+            X1(Parachain(para_id)),
+        )))
+    };
+    new_test_ext().execute_with(|| {
+        let native_asset_id = <MantaAssetConfig as AssetConfig<Runtime>>::NativeAssetId::get();
+        let asset_id = AssetManager::next_asset_id();
+        let other_location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+            1,
+            X2(Parachain(2001), PalletInstance(PALLET_BALANCES_INDEX)),
+        )));
+        for location in [location(2000), location(2001), other_location] {
+            assert_ok!(AssetManager::register_asset(
+                Origin::root(),
+                location,
+                create_asset_metadata("Token", "TKN", 12, 1u128, false, true)
+            ));
+        }
+        assert_ok!(AssetManager::set_units_per_second(
+            Origin::root(),
+            asset_id,
+            125
+        ));
+        assert_ok!(AssetManager::set_min_xcm_fee(
+            Origin::root(),
+            location(2000),
+            10
+        ));
+        let entry = AssetManager::registry_entry(asset_id).unwrap();
+        assert_eq!(entry.location, Some(location(2000)));
+        assert_eq!(entry.units_per_second, Some(125));
+        assert_eq!(entry.min_xcm_fee, Some(10));
+        assert_eq!(entry.para_id, Some(2000));
+        assert_eq!(entry.para_asset_count, Some(1));
+        assert_eq!(
+            AssetManager::registry_entry(asset_id + 1)
+                .unwrap()
+                .para_asset_count,
+            Some(2)
+        );
+        assert_eq!(
+            AssetManager::registry_entry_by_location(&location(2000)),
+            Some(entry)
+        );
+        assert_eq!(
+            AssetManager::registry_entry_by_location(&location(3000)),
+            None
+        );
+        assert_eq!(AssetManager::registry_entry(asset_id + 3), None);
+        // Entries are paged in storage order, each page starting after the previous one
+        let asset_ids = |start, limit| {
+            AssetManager::registry_entries(start, limit)
+                .into_iter()
+                .map(|entry| entry.asset_id)
+                .collect::<Vec<_>>()
+        };
+        let all = asset_ids(None, 10);
+        let mut sorted = all.clone();
+        sorted.sort_unstable();
+        assert_eq!(
+            sorted,
+            vec![native_asset_id, asset_id, asset_id + 1, asset_id + 2]
+        );
+        let first = asset_ids(None, 2);
+        assert_eq!(first, all[..2]);
+        let second = asset_ids(first.last().copied(), 2);
+        assert_eq!(second, all[2..]);
+        assert_eq!(asset_ids(second.last().copied(), 2), vec![]);
+    });
+}
+
+#[test]
+fn sibling_registration_requests_should_work() {
+    let para_id = 2015;
+    let location = |para_id, index| {
+        AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+            1,
+            X2(Parachain(para_id), GeneralIndex(index)),
+        )))
+    };
+    let asset_metadata = create_asset_metadata("Manta", "MANTA", 18, 1u128, false, true);
+    let sibling = || Origin::signed(sibling_account(para_id));
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_noop!(
+            AssetManager::request_sibling_registration(
+                Origin::signed(ALICE),
+                location(para_id, 0),
+                asset_metadata.clone()
+            ),
+            BadOrigin
+        );
+        // Siblings can only request assets in their own interior
+        assert_noop!(
+            AssetManager::request_sibling_registration(
+                sibling(),
+                location(para_id + 1, 0),
+                asset_metadata.clone()
+            ),
+            Error::<Runtime>::LocationNotInSiblingInterior
+        );
+        assert_noop!(
+            AssetManager::request_sibling_registration(
+                sibling(),
+                AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+                    0,
+                    X2(Parachain(para_id), GeneralIndex(0)),
+                ))),
+                asset_metadata.clone()
+            ),
+            Error::<Runtime>::LocationNotInSiblingInterior
+        );
+        assert_ok!(AssetManager::request_sibling_registration(
+            sibling(),
+            location(para_id, 0),
+            asset_metadata.clone()
+        ));
+        System::assert_last_event(
+            AssetManagerEvent::SiblingRegistrationRequested {
+                para_id,
+                location: location(para_id, 0),
+            }
+            .into(),
+        );
+        assert_noop!(
+            AssetManager::request_sibling_registration(
+                sibling(),
+                location(para_id, 0),
+                asset_metadata.clone()
+            ),
+            Error::<Runtime>::SiblingRequestAlreadyPending
+        );
+        for index in 1..4 {
+            assert_ok!(AssetManager::request_sibling_registration(
+                sibling(),
+                location(para_id, index),
+                asset_metadata.clone()
+            ));
+        }
+        assert_noop!(
+            AssetManager::request_sibling_registration(
+                sibling(),
+                location(para_id, 4),
+                asset_metadata.clone()
+            ),
+            Error::<Runtime>::TooManySiblingRequests
+        );
+        // Only the modifier origin can ratify requests
+        assert_noop!(
+            AssetManager::approve_sibling_registration(sibling(), location(para_id, 0)),
+            BadOrigin
+        );
+        let asset_id = AssetManager::next_asset_id();
+        assert_ok!(AssetManager::approve_sibling_registration(
+            Origin::root(),
+            location(para_id, 0)
+        ));
+        System::assert_last_event(
+            AssetManagerEvent::SiblingRegistrationApproved { para_id, asset_id }.into(),
+        );
+        assert_eq!(
+            AssetIdLocation::<Runtime>::get(asset_id),
+            Some(location(para_id, 0))
+        );
+        assert_eq!(
+            AssetIdMetadata::<Runtime>::get(asset_id),
+            Some(asset_metadata.clone())
+        );
+        assert_eq!(
+            AssetManager::sibling_registration_request(para_id, location(para_id, 0)),
+            None
+        );
+        // Registered locations cannot be requested again
+        assert_noop!(
+            AssetManager::request_sibling_registration(
+                sibling(),
+                location(para_id, 0),
+                asset_metadata.clone()
+            ),
+            Error::<Runtime>::LocationAlreadyExists
+        );
+        assert_ok!(AssetManager::reject_sibling_registration(
+            Origin::root(),
+            location(para_id, 1)
+        ));
+        assert_noop!(
+            AssetManager::reject_sibling_registration(Origin::root(), location(para_id, 1)),
+            Error::<Runtime>::UnknownSiblingRequest
+        );
+        assert_noop!(
+            AssetManager::approve_sibling_registration(Origin::root(), location(para_id, 1)),
+            Error::<Runtime>::UnknownSiblingRequest
+        );
+    });
+}
+
+#[test]
+fn genesis_assets_should_be_registered() {
+    let asset_metadata = create_asset_metadata("Kusama", "KSM", 12, 1u128, false, true);
+    let source_location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::parent()));
+    let genesis_asset = crate::GenesisAsset {
+        location: source_location.encode(),
+        metadata: asset_metadata.encode(),
+        units_per_second: Some(1_000),
+        min_xcm_fee: Some(100),
+        mints: vec![(ALICE, 1_000_000)],
+    };
+    new_test_ext_with_assets(vec![genesis_asset]).execute_with(|| {
+        let asset_id = <MantaAssetConfig as AssetConfig<Runtime>>::StartNonNativeAssetId::get();
+        assert_eq!(
+            AssetIdLocation::<Runtime>::get(asset_id),
+            Some(source_location.clone())
+        );
+        assert_eq!(
+            AssetIdMetadata::<Runtime>::get(asset_id),
+            Some(asset_metadata.clone())
+        );
+        assert_eq!(
+            LocationAssetId::<Runtime>::get(&source_location),
+            Some(asset_id)
+        );
+        assert_eq!(UnitsPerSecond::<Runtime>::get(asset_id), Some(1_000));
+        assert_eq!(AssetManager::get_min_xcm_fee(&source_location), Some(100));
+        assert_eq!(Assets::balance(asset_id, &ALICE), 1_000_000);
+        // Genesis mints count as governance mints
+        assert_eq!(
+            AssetManager::mint_accounting(asset_id),
+            MintAccounting {
+                cap: None,
+                minted: 1_000_000,
+                burned: 0,
+            }
+        );
+        assert_eq!(AssetManager::next_asset_id(), asset_id + 1);
+    });
+}
+
+#[test]
+fn modifier_origin_should_not_set_mint_caps() {
+    let asset_metadata = create_asset_metadata("Kusama", "KSM", 12, 1u128, false, true);
+    let source_location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::parent()));
+    new_test_ext().execute_with(|| {
+        let asset_id = <MantaAssetConfig as AssetConfig<Runtime>>::StartNonNativeAssetId::get();
+        assert_ok!(AssetManager::register_asset(
+            Origin::root(),
+            source_location,
+            asset_metadata
+        ));
+        assert_ok!(AssetManager::set_mint_cap(
+            Origin::signed(MintCapAdmin::get()),
+            asset_id,
+            Some(1_000)
+        ));
+        // The `ModifierOrigin` mints within the caps but can neither raise nor lift them
+        assert_noop!(
+            AssetManager::set_mint_cap(Origin::root(), asset_id, Some(2_000)),
+            BadOrigin
+        );
+        assert_noop!(
+            AssetManager::set_mint_cap(Origin::root(), asset_id, None),
+            BadOrigin
+        );
+        assert_noop!(
+            AssetManager::mint_asset(Origin::root(), asset_id, ALICE, 1_001),
+            Error::<Runtime>::MintCapExceeded
+        );
+        assert_eq!(AssetManager::mint_accounting(asset_id).cap, Some(1_000));
+    });
+}
+
+#[test]
+fn mint_caps_should_limit_governance_mints() {
+    let asset_metadata = create_asset_metadata("Kusama", "KSM", 12, 1u128, false, true);
+    let source_location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::parent()));
+    new_test_ext().execute_with(|| {
+        let asset_id = <MantaAssetConfig as AssetConfig<Runtime>>::StartNonNativeAssetId::get();
+        assert_noop!(
+            AssetManager::set_mint_cap(Origin::signed(MintCapAdmin::get()), asset_id, Some(1_000)),
+            Error::<Runtime>::UpdateNonExistentAsset
+        );
+        assert_ok!(AssetManager::register_asset(
+            Origin::root(),
+            source_location,
+            asset_metadata
+        ));
+        assert_noop!(
+            AssetManager::set_mint_cap(Origin::signed(ALICE), asset_id, Some(1_000)),
+            BadOrigin
+        );
+        assert_ok!(AssetManager::set_mint_cap(
+            Origin::signed(MintCapAdmin::get()),
+            asset_id,
+            Some(1_000)
+        ));
+        System::assert_last_event(
+            AssetManagerEvent::MintCapUpdated {
+                asset_id,
+                cap: Some(1_000),
+            }
+            .into(),
+        );
+        assert_ok!(AssetManager::mint_asset(
+            Origin::root(),
+            asset_id,
+            ALICE,
+            800
+        ));
+        assert_noop!(
+            AssetManager::mint_asset(Origin::root(), asset_id, ALICE, 201),
+            Error::<Runtime>::MintCapExceeded
+        );
+        // Burning frees up room under the cap
+        assert_ok!(AssetManager::burn_asset(
+            Origin::root(),
+            asset_id,
+            ALICE,
+            300
+        ));
+        System::assert_last_event(
+            AssetManagerEvent::AssetBurned {
+                asset_id,
+                who: ALICE,
+                amount: 300,
+            }
+            .into(),
+        );
+        assert_ok!(AssetManager::mint_asset(
+            Origin::root(),
+            asset_id,
+            ALICE,
+            500
+        ));
+        assert_eq!(Assets::balance(asset_id, &ALICE), 1_000);
+        assert_eq!(
+            AssetManager::mint_accounting(asset_id),
+            MintAccounting {
+                cap: Some(1_000),
+                minted: 1_300,
+                burned: 300,
+            }
+        );
+        assert_noop!(
+            AssetManager::burn_asset(Origin::root(), asset_id, ALICE, 1_001),
+            Error::<Runtime>::BurnError
+        );
+        // Lifting the cap allows unlimited mints again
+        assert_ok!(AssetManager::set_mint_cap(
+            Origin::signed(MintCapAdmin::get()),
+            asset_id,
+            None
+        ));
+        assert_ok!(AssetManager::mint_asset(
+            Origin::root(),
+            asset_id,
+            ALICE,
+            10_000
+        ));
+        assert_eq!(
+            AssetManager::mint_accounting(asset_id).outstanding(),
+            11_000
+        );
+    });
+}
+
+#[test]
+fn burning_xcm_supply_should_not_free_mint_caps() {
+    let asset_metadata = create_asset_metadata("Kusama", "KSM", 12, 1u128, false, true);
+    let source_location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::parent()));
+    let bob = sp_runtime::AccountId32::new([1u8; 32]);
+    new_test_ext().execute_with(|| {
+        let asset_id = <MantaAssetConfig as AssetConfig<Runtime>>::StartNonNativeAssetId::get();
+        assert_ok!(AssetManager::register_asset(
+            Origin::root(),
+            source_location,
+            asset_metadata
+        ));
+        assert_ok!(AssetManager::set_mint_cap(
+            Origin::signed(MintCapAdmin::get()),
+            asset_id,
+            Some(1_000)
+        ));
+        assert_ok!(AssetManager::mint_asset(
+            Origin::root(),
+            asset_id,
+            ALICE,
+            1_000
+        ));
+        // Supply deposited over XCM is not minted by governance
+        assert_ok!(
+            <MantaAssetConfig as AssetConfig<Runtime>>::FungibleLedger::deposit_minting(
+                asset_id, &bob, 500
+            )
+        );
+        assert_ok!(AssetManager::burn_asset(
+            Origin::root(),
+            asset_id,
+            bob.clone(),
+            400
+        ));
+        assert_eq!(AssetManager::mint_accounting(asset_id).outstanding(), 1_000);
+        assert_noop!(
+            AssetManager::mint_asset(Origin::root(), asset_id, ALICE, 1),
+            Error::<Runtime>::MintCapExceeded
+        );
+        // Once the remaining supply no longer covers the governance mints, burns count against them
+        assert_ok!(AssetManager::burn_asset(
+            Origin::root(),
+            asset_id,
+            ALICE,
+            300
+        ));
+        assert_eq!(AssetManager::mint_accounting(asset_id).outstanding(), 800);
+        assert_ok!(AssetManager::mint_asset(
+            Origin::root(),
+            asset_id,
+            ALICE,
+            200
+        ));
+    });
+}
+
+#[test]
+fn destination_policies_should_override_registered_assets() {
+    let para_id = 2015;
+    let manta_asset_metadata = create_asset_metadata("Manta", "MANTA", 18, 1u128, false, false);
+    let manta_location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+        1,
+        X1(Parachain(para_id)),
+    )));
+    let account = |para_id, id: [u8; 32]| {
+        MultiLocation::new(
+            1,
+            X2(
+                Parachain(para_id),
+                AccountId32 {
+                    network: NetworkId::Any,
+                    id,
+                },
+            ),
+        )
+    };
+    let chain = |para_id| {
+        AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+            1,
+            X1(Parachain(para_id)),
+        )))
+    };
+    new_test_ext().execute_with(|| {
+        assert_ok!(AssetManager::register_asset(
+            Origin::root(),
+            manta_location,
+            manta_asset_metadata
+        ));
+        assert!(AssetManager::contains(&account(para_id, [1; 32])));
+        assert!(!AssetManager::contains(&account(para_id + 1, [1; 32])));
+        assert_noop!(
+            AssetManager::set_destination_policy(
+                Origin::signed(ALICE),
+                chain(para_id),
+                Some(DestinationPolicy::Deny)
+            ),
+            BadOrigin
+        );
+        // Denying a chain overrides its registered assets
+        assert_ok!(AssetManager::set_destination_policy(
+            Origin::root(),
+            chain(para_id),
+            Some(DestinationPolicy::Deny)
+        ));
+        System::assert_last_event(
+            AssetManagerEvent::DestinationPolicyUpdated {
+                location: chain(para_id),
+                policy: Some(DestinationPolicy::Deny),
+            }
+            .into(),
+        );
+        assert!(!AssetManager::contains(&account(para_id, [1; 32])));
+        // A full destination location overrides the policy of its chain
+        let allowed = AssetLocation(VersionedMultiLocation::V1(account(para_id, [2; 32])));
+        assert_ok!(AssetManager::set_destination_policy(
+            Origin::root(),
+            allowed.clone(),
+            Some(DestinationPolicy::Allow)
+        ));
+        assert!(AssetManager::contains(&account(para_id, [2; 32])));
+        assert!(AssetManager::is_allowed_destination(&allowed));
+        // Chains without assets can be allowed explicitly
+        assert_ok!(AssetManager::set_destination_policy(
+            Origin::root(),
+            chain(para_id + 1),
+            Some(DestinationPolicy::Allow)
+        ));
+        assert!(AssetManager::contains(&account(para_id + 1, [1; 32])));
+        // Unsupported destination shapes stay filtered
+        assert!(!AssetManager::contains(&MultiLocation::new(
+            1,
+            X1(Parachain(para_id + 1))
+        )));
+        assert_eq!(AssetManager::destination_policies().len(), 3);
+        // Removing the policy falls back to the registered assets
+        assert_ok!(AssetManager::set_destination_policy(
+            Origin::root(),
+            chain(para_id),
+            None
+        ));
+        assert!(AssetManager::contains(&account(para_id, [1; 32])));
+        assert_eq!(AssetManager::destination_policy(chain(para_id)), None);
+    });
+}
+
+#[test]
+fn equivalent_locations_should_be_normalized() {
+    let asset_metadata = create_asset_metadata("Kusama", "KSM", 12, 1u128, false, true);
+    let v0_location = |junctions| AssetLocation(VersionedMultiLocation::V0(junctions));
+    let canonical = AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+        1,
+        X1(Parachain(1000)),
+    )));
+    new_test_ext().execute_with(|| {
+        let asset_id = <MantaAssetConfig as AssetConfig<Runtime>>::StartNonNativeAssetId::get();
+        assert_ok!(AssetManager::register_asset(
+            Origin::root(),
+            v0_location(V0MultiLocation::X2(
+                V0Junction::Parent,
+                V0Junction::Parachain(1000)
+            )),
+            asset_metadata.clone()
+        ));
+        assert_eq!(
+            AssetIdLocation::<Runtime>::get(asset_id),
+            Some(canonical.clone())
+        );
+        assert_eq!(LocationAssetId::<Runtime>::get(&canonical), Some(asset_id));
+        assert_noop!(
+            AssetManager::register_asset(Origin::root(), canonical.clone(), asset_metadata),
+            Error::<Runtime>::LocationAlreadyExists
+        );
+        // A parent junction cancels out the junction before it
+        assert_eq!(
+            AssetManager::asset_id(&v0_location(V0MultiLocation::X4(
+                V0Junction::Parent,
+                V0Junction::Parachain(2000),
+                V0Junction::Parent,
+                V0Junction::Parachain(1000)
+            ))),
+            Some(asset_id)
+        );
+        assert_eq!(
+            AssetManager::registry_entry_by_location(&canonical).map(|entry| entry.asset_id),
+            Some(asset_id)
+        );
+    });
+}
+
+#[test]
+fn location_keyed_settings_should_be_normalized() {
+    let v0_location = AssetLocation(VersionedMultiLocation::V0(V0MultiLocation::X2(
+        V0Junction::Parent,
+        V0Junction::Parachain(1000),
+    )));
+    let canonical = AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+        1,
+        X1(Parachain(1000)),
+    )));
+    new_test_ext().execute_with(|| {
+        assert_ok!(AssetManager::set_min_xcm_fee(
+            Origin::root(),
+            v0_location.clone(),
+            100
+        ));
+        System::assert_last_event(
+            AssetManagerEvent::MinXcmFeeUpdated {
+                reserve_chain: canonical.clone(),
+                min_xcm_fee: 100,
+            }
+            .into(),
+        );
+        assert_eq!(AssetManager::get_min_xcm_fee(&canonical), Some(100));
+        assert_eq!(AssetManager::get_min_xcm_fee(&v0_location), None);
+        assert_ok!(AssetManager::set_destination_policy(
+            Origin::root(),
+            v0_location.clone(),
+            Some(DestinationPolicy::Deny)
+        ));
+        assert_eq!(
+            AssetManager::destination_policy(&canonical),
+            Some(DestinationPolicy::Deny)
+        );
+        assert_eq!(AssetManager::destination_policy(&v0_location), None);
+        assert_ok!(AssetManager::set_destination_policy(
+            Origin::root(),
+            v0_location,
+            None
+        ));
+        assert_eq!(AssetManager::destination_policy(&canonical), None);
+    });
+}
+
+#[test]
+fn location_normalization_migration_should_work() {
+    let v0_location = |para_id| {
+        AssetLocation(VersionedMultiLocation::V0(V0MultiLocation::X2(
+            V0Junction::Parent,
+            V0Junction::Parachain(para_id),
+        )))
+    };
+    let v1_location = |para_id| {
+        AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+            1,
+            X1(Parachain(para_id)),
+        )))
+    };
+    new_test_ext().execute_with(|| {
+        // Entries stored before locations were normalized
+        for (location, asset_id) in [(v0_location(1000), 8), (v0_location(2000), 9)] {
+            AssetIdLocation::<Runtime>::insert(asset_id, &location);
+            LocationAssetId::<Runtime>::insert(&location, asset_id);
+        }
+        AssetIdLocation::<Runtime>::insert(10, v1_location(2000));
+        LocationAssetId::<Runtime>::insert(v1_location(2000), 10);
+        assert_eq!(
+            AssetManager::duplicate_locations(),
+            vec![(v1_location(2000), vec![9, 10])]
+        );
+        StorageVersion::new(2).put::<AssetManager>();
+        LocationNormalizationMigration::<Runtime>::on_runtime_upgrade();
+        assert_eq!(LocationAssetId::<Runtime>::get(v1_location(1000)), Some(8));
+        assert_eq!(LocationAssetId::<Runtime>::get(v0_location(1000)), None);
+        assert_eq!(AssetIdLocation::<Runtime>::get(8), Some(v1_location(1000)));
+        assert_eq!(AllowedDestParaIds::<Runtime>::get(1000), Some(1));
+        // Duplicates are left in place and keep the migration pending
+        assert_eq!(LocationAssetId::<Runtime>::get(v0_location(2000)), Some(9));
+        assert_eq!(LocationAssetId::<Runtime>::get(v1_location(2000)), Some(10));
+        assert_eq!(
+            AssetManager::on_chain_storage_version(),
+            StorageVersion::new(2)
+        );
+        // Once the duplicate is resolved, the next run completes the migration
+        LocationAssetId::<Runtime>::remove(v1_location(2000));
+        AssetIdLocation::<Runtime>::remove(10);
+        LocationNormalizationMigration::<Runtime>::on_runtime_upgrade();
+        assert_eq!(LocationAssetId::<Runtime>::get(v1_location(2000)), Some(9));
+        assert_eq!(LocationAssetId::<Runtime>::get(v0_location(2000)), None);
+        assert_eq!(AssetIdLocation::<Runtime>::get(9), Some(v1_location(2000)));
+        assert_eq!(LocationAssetId::<Runtime>::get(v1_location(1000)), Some(8));
+        assert_eq!(
+            AssetManager::on_chain_storage_version(),
+            StorageVersion::new(3)
+        );
+    });
+}
+
+#[test]
+fn set_teleport_trust_should_work() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let asset_hub = MultiLocation::new(1, X1(Parachain(1000)));
+        let location = AssetLocation(VersionedMultiLocation::V1(asset_hub.clone()));
+        assert!(!IsTrustedTeleporter::<Runtime>::contains(&asset_hub));
+        assert_noop!(
+            AssetManager::set_teleport_trust(Origin::signed(ALICE), location.clone(), true),
+            BadOrigin
+        );
+        assert_ok!(AssetManager::set_teleport_trust(
+            Origin::root(),
+            location.clone(),
+            true
+        ));
+        System::assert_last_event(
+            AssetManagerEvent::TeleportTrustUpdated {
+                location: location.clone(),
+                trusted: true,
+            }
+            .into(),
+        );
+        assert!(IsTrustedTeleporter::<Runtime>::contains(&asset_hub));
+        assert!(!IsTrustedTeleporter::<Runtime>::contains(
+            &MultiLocation::parent()
+        ));
+        assert_ok!(AssetManager::set_teleport_trust(
+            Origin::root(),
+            location,
+            false
+        ));
+        assert!(!IsTrustedTeleporter::<Runtime>::contains(&asset_hub));
+    });
+}
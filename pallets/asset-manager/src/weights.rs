@@ -51,12 +51,30 @@ pub trait WeightInfo {
     fn update_asset_metadata() -> Weight;
     fn mint_asset() -> Weight;
     fn set_min_xcm_fee() -> Weight;
+    fn register_non_fungible_asset() -> Weight;
+    fn freeze_asset() -> Weight;
+    fn destroy_asset() -> Weight;
+    fn set_asset_status() -> Weight;
+    fn add_location_alias() -> Weight;
+    fn remove_location_alias() -> Weight;
+    fn propose_asset() -> Weight;
+    fn approve_asset_proposal() -> Weight;
+    fn reject_asset_proposal() -> Weight;
+    fn remove_expired_proposal() -> Weight;
+    fn request_sibling_registration() -> Weight;
+    fn approve_sibling_registration() -> Weight;
+    fn reject_sibling_registration() -> Weight;
+    fn set_mint_cap() -> Weight;
+    fn burn_asset() -> Weight;
+    fn set_destination_policy() -> Weight;
+    fn set_teleport_trust() -> Weight;
 }
 
 /// Weights for pallet_asset_manager using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     // Storage: AssetManager LocationAssetId (r:1 w:1)
+    // Storage: AssetManager AliasLocationAssetId (r:1 w:0)
     // Storage: AssetManager NextAssetId (r:1 w:1)
     // Storage: Assets Asset (r:1 w:1)
     // Storage: Assets Metadata (r:1 w:1)
@@ -64,7 +82,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     // Storage: AssetManager AssetIdLocation (r:0 w:1)
     fn register_asset() -> Weight {
         (43_430_000 as Weight)
-            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
             .saturating_add(T::DbWeight::get().writes(6 as Weight))
     }
     // Storage: AssetManager AssetIdLocation (r:1 w:0)
@@ -76,10 +94,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     }
     // Storage: AssetManager AssetIdLocation (r:1 w:1)
     // Storage: AssetManager LocationAssetId (r:1 w:2)
+    // Storage: AssetManager AliasLocationAssetId (r:1 w:0)
     // Storage: AssetManager AllowedDestParaIds (r:1 w:1)
     fn update_asset_location() -> Weight {
         (72_974_000 as Weight)
-            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
             .saturating_add(T::DbWeight::get().writes(4 as Weight))
     }
     // Storage: AssetManager AssetIdLocation (r:1 w:0)
@@ -94,21 +113,171 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     // Storage: AssetManager AssetIdLocation (r:1 w:0)
     // Storage: Assets Asset (r:1 w:1)
     // Storage: Assets Account (r:1 w:1)
+    // Storage: AssetManager AssetMintAccounting (r:1 w:1)
     fn mint_asset() -> Weight {
         (85_480_000 as Weight)
-            .saturating_add(T::DbWeight::get().reads(3 as Weight))
-            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
     }
     // Storage: AssetManager MinXcmFee (r:0 w:1)
     fn set_min_xcm_fee() -> Weight {
         (49_509_000 as Weight)
             .saturating_add(T::DbWeight::get().writes(1 as Weight))
     }
+    // Storage: AssetManager NonFungibleAssetId (r:1 w:1)
+    // Storage: Uniques Asset (r:1 w:0)
+    // Storage: Uniques Class (r:1 w:0)
+    // Storage: AssetManager NextAssetId (r:1 w:1)
+    // Storage: AssetManager AssetIdNonFungible (r:0 w:1)
+    fn register_non_fungible_asset() -> Weight {
+        (33_204_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    // Storage: AssetManager AssetIdLocation (r:1 w:0)
+    // Storage: AssetManager FrozenAssets (r:1 w:1)
+    // Storage: Assets Asset (r:1 w:1)
+    fn freeze_asset() -> Weight {
+        (38_112_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    // Storage: AssetManager AssetIdLocation (r:1 w:1)
+    // Storage: AssetManager FrozenAssets (r:1 w:1)
+    // Storage: Assets Asset (r:1 w:1)
+    // Storage: Assets Metadata (r:1 w:1)
+    // Storage: AssetManager AllowedDestParaIds (r:1 w:1)
+    // Storage: AssetManager LocationAssetId (r:0 w:1)
+    // Storage: AssetManager AssetIdMetadata (r:0 w:1)
+    // Storage: AssetManager UnitsPerSecond (r:0 w:1)
+    // Storage: AssetManager LocationAliases (r:1 w:1)
+    // Storage: AssetManager AssetMintAccounting (r:0 w:1)
+    fn destroy_asset() -> Weight {
+        (71_560_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(6 as Weight))
+            .saturating_add(T::DbWeight::get().writes(10 as Weight))
+    }
+    // Storage: AssetManager AssetIdLocation (r:1 w:0)
+    // Storage: AssetManager AssetIdNonFungible (r:1 w:0)
+    // Storage: AssetManager AssetIdStatus (r:0 w:1)
+    fn set_asset_status() -> Weight {
+        (24_817_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    // Storage: AssetManager AssetIdLocation (r:1 w:0)
+    // Storage: AssetManager LocationAssetId (r:1 w:0)
+    // Storage: AssetManager AliasLocationAssetId (r:1 w:1)
+    // Storage: AssetManager LocationAliases (r:1 w:1)
+    fn add_location_alias() -> Weight {
+        (31_092_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    // Storage: AssetManager AliasLocationAssetId (r:1 w:1)
+    // Storage: AssetManager LocationAliases (r:1 w:1)
+    fn remove_location_alias() -> Weight {
+        (27_435_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    // Storage: AssetManager LocationAssetId (r:1 w:0)
+    // Storage: AssetManager AliasLocationAssetId (r:1 w:0)
+    // Storage: System Account (r:1 w:1)
+    // Storage: AssetManager NextProposalId (r:1 w:1)
+    // Storage: AssetManager RegistrationProposals (r:0 w:1)
+    fn propose_asset() -> Weight {
+        (41_205_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    // Storage: AssetManager RegistrationProposals (r:1 w:1)
+    // Storage: AssetManager LocationAssetId (r:1 w:1)
+    // Storage: AssetManager AliasLocationAssetId (r:1 w:0)
+    // Storage: AssetManager NextAssetId (r:1 w:1)
+    // Storage: Assets Asset (r:1 w:1)
+    // Storage: Assets Metadata (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+    // Storage: AssetManager AssetIdMetadata (r:0 w:1)
+    // Storage: AssetManager AssetIdLocation (r:0 w:1)
+    fn approve_asset_proposal() -> Weight {
+        (78_391_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(7 as Weight))
+            .saturating_add(T::DbWeight::get().writes(8 as Weight))
+    }
+    // Storage: AssetManager RegistrationProposals (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+    fn reject_asset_proposal() -> Weight {
+        (36_870_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    // Storage: AssetManager RegistrationProposals (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+    fn remove_expired_proposal() -> Weight {
+        (33_514_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    // Storage: AssetManager LocationAssetId (r:1 w:0)
+    // Storage: AssetManager AliasLocationAssetId (r:1 w:0)
+    // Storage: AssetManager SiblingRegistrationRequests (r:5 w:1)
+    fn request_sibling_registration() -> Weight {
+        (39_284_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(7 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    // Storage: AssetManager SiblingRegistrationRequests (r:1 w:1)
+    // Storage: AssetManager LocationAssetId (r:1 w:1)
+    // Storage: AssetManager AliasLocationAssetId (r:1 w:0)
+    // Storage: AssetManager NextAssetId (r:1 w:1)
+    // Storage: Assets Asset (r:1 w:1)
+    // Storage: Assets Metadata (r:1 w:1)
+    // Storage: AssetManager AssetIdLocation (r:0 w:1)
+    // Storage: AssetManager AssetIdMetadata (r:0 w:1)
+    fn approve_sibling_registration() -> Weight {
+        (75_906_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(6 as Weight))
+            .saturating_add(T::DbWeight::get().writes(7 as Weight))
+    }
+    // Storage: AssetManager SiblingRegistrationRequests (r:1 w:1)
+    fn reject_sibling_registration() -> Weight {
+        (27_418_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    // Storage: AssetManager AssetIdLocation (r:1 w:0)
+    // Storage: AssetManager AssetMintAccounting (r:1 w:1)
+    fn set_mint_cap() -> Weight {
+        (27_142_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    // Storage: AssetManager AssetIdLocation (r:1 w:0)
+    // Storage: Assets Asset (r:1 w:1)
+    // Storage: Assets Account (r:1 w:1)
+    // Storage: AssetManager AssetMintAccounting (r:1 w:1)
+    fn burn_asset() -> Weight {
+        (79_361_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    // Storage: AssetManager DestinationPolicies (r:0 w:1)
+    fn set_destination_policy() -> Weight {
+        (21_873_000 as Weight)
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    // Storage: AssetManager TrustedTeleporters (r:0 w:1)
+    fn set_teleport_trust() -> Weight {
+        (22_651_000 as Weight)
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
     // Storage: AssetManager LocationAssetId (r:1 w:1)
+    // Storage: AssetManager AliasLocationAssetId (r:1 w:0)
     // Storage: AssetManager NextAssetId (r:1 w:1)
     // Storage: Assets Asset (r:1 w:1)
     // Storage: Assets Metadata (r:1 w:1)
@@ -116,7 +285,7 @@ impl WeightInfo for () {
     // Storage: AssetManager AssetIdLocation (r:0 w:1)
     fn register_asset() -> Weight {
         (43_430_000 as Weight)
-            .saturating_add(RocksDbWeight::get().reads(4 as Weight))
+            .saturating_add(RocksDbWeight::get().reads(5 as Weight))
             .saturating_add(RocksDbWeight::get().writes(6 as Weight))
     }
     // Storage: AssetManager AssetIdLocation (r:1 w:0)
@@ -128,10 +297,11 @@ impl WeightInfo for () {
     }
     // Storage: AssetManager AssetIdLocation (r:1 w:1)
     // Storage: AssetManager LocationAssetId (r:1 w:2)
+    // Storage: AssetManager AliasLocationAssetId (r:1 w:0)
     // Storage: AssetManager AllowedDestParaIds (r:1 w:1)
     fn update_asset_location() -> Weight {
         (72_974_000 as Weight)
-            .saturating_add(RocksDbWeight::get().reads(3 as Weight))
+            .saturating_add(RocksDbWeight::get().reads(4 as Weight))
             .saturating_add(RocksDbWeight::get().writes(4 as Weight))
     }
     // Storage: AssetManager AssetIdLocation (r:1 w:0)
@@ -146,14 +316,163 @@ impl WeightInfo for () {
     // Storage: AssetManager AssetIdLocation (r:1 w:0)
     // Storage: Assets Asset (r:1 w:1)
     // Storage: Assets Account (r:1 w:1)
+    // Storage: AssetManager AssetMintAccounting (r:1 w:1)
     fn mint_asset() -> Weight {
         (85_480_000 as Weight)
-            .saturating_add(RocksDbWeight::get().reads(3 as Weight))
-            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
+            .saturating_add(RocksDbWeight::get().reads(4 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(3 as Weight))
     }
     // Storage: AssetManager MinXcmFee (r:0 w:1)
     fn set_min_xcm_fee() -> Weight {
         (49_509_000 as Weight)
             .saturating_add(RocksDbWeight::get().writes(1 as Weight))
     }
+    // Storage: AssetManager NonFungibleAssetId (r:1 w:1)
+    // Storage: Uniques Asset (r:1 w:0)
+    // Storage: Uniques Class (r:1 w:0)
+    // Storage: AssetManager NextAssetId (r:1 w:1)
+    // Storage: AssetManager AssetIdNonFungible (r:0 w:1)
+    fn register_non_fungible_asset() -> Weight {
+        (33_204_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(4 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(3 as Weight))
+    }
+    // Storage: AssetManager AssetIdLocation (r:1 w:0)
+    // Storage: AssetManager FrozenAssets (r:1 w:1)
+    // Storage: Assets Asset (r:1 w:1)
+    fn freeze_asset() -> Weight {
+        (38_112_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(3 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
+    }
+    // Storage: AssetManager AssetIdLocation (r:1 w:1)
+    // Storage: AssetManager FrozenAssets (r:1 w:1)
+    // Storage: Assets Asset (r:1 w:1)
+    // Storage: Assets Metadata (r:1 w:1)
+    // Storage: AssetManager AllowedDestParaIds (r:1 w:1)
+    // Storage: AssetManager LocationAssetId (r:0 w:1)
+    // Storage: AssetManager AssetIdMetadata (r:0 w:1)
+    // Storage: AssetManager UnitsPerSecond (r:0 w:1)
+    // Storage: AssetManager LocationAliases (r:1 w:1)
+    // Storage: AssetManager AssetMintAccounting (r:0 w:1)
+    fn destroy_asset() -> Weight {
+        (71_560_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(6 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(10 as Weight))
+    }
+    // Storage: AssetManager AssetIdLocation (r:1 w:0)
+    // Storage: AssetManager AssetIdNonFungible (r:1 w:0)
+    // Storage: AssetManager AssetIdStatus (r:0 w:1)
+    fn set_asset_status() -> Weight {
+        (24_817_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    // Storage: AssetManager AssetIdLocation (r:1 w:0)
+    // Storage: AssetManager LocationAssetId (r:1 w:0)
+    // Storage: AssetManager AliasLocationAssetId (r:1 w:1)
+    // Storage: AssetManager LocationAliases (r:1 w:1)
+    fn add_location_alias() -> Weight {
+        (31_092_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(4 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
+    }
+    // Storage: AssetManager AliasLocationAssetId (r:1 w:1)
+    // Storage: AssetManager LocationAliases (r:1 w:1)
+    fn remove_location_alias() -> Weight {
+        (27_435_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
+    }
+    // Storage: AssetManager LocationAssetId (r:1 w:0)
+    // Storage: AssetManager AliasLocationAssetId (r:1 w:0)
+    // Storage: System Account (r:1 w:1)
+    // Storage: AssetManager NextProposalId (r:1 w:1)
+    // Storage: AssetManager RegistrationProposals (r:0 w:1)
+    fn propose_asset() -> Weight {
+        (41_205_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(4 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(3 as Weight))
+    }
+    // Storage: AssetManager RegistrationProposals (r:1 w:1)
+    // Storage: AssetManager LocationAssetId (r:1 w:1)
+    // Storage: AssetManager AliasLocationAssetId (r:1 w:0)
+    // Storage: AssetManager NextAssetId (r:1 w:1)
+    // Storage: Assets Asset (r:1 w:1)
+    // Storage: Assets Metadata (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+    // Storage: AssetManager AssetIdMetadata (r:0 w:1)
+    // Storage: AssetManager AssetIdLocation (r:0 w:1)
+    fn approve_asset_proposal() -> Weight {
+        (78_391_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(7 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(8 as Weight))
+    }
+    // Storage: AssetManager RegistrationProposals (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+    fn reject_asset_proposal() -> Weight {
+        (36_870_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
+    }
+    // Storage: AssetManager RegistrationProposals (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+    fn remove_expired_proposal() -> Weight {
+        (33_514_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
+    }
+    // Storage: AssetManager LocationAssetId (r:1 w:0)
+    // Storage: AssetManager AliasLocationAssetId (r:1 w:0)
+    // Storage: AssetManager SiblingRegistrationRequests (r:5 w:1)
+    fn request_sibling_registration() -> Weight {
+        (39_284_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(7 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    // Storage: AssetManager SiblingRegistrationRequests (r:1 w:1)
+    // Storage: AssetManager LocationAssetId (r:1 w:1)
+    // Storage: AssetManager AliasLocationAssetId (r:1 w:0)
+    // Storage: AssetManager NextAssetId (r:1 w:1)
+    // Storage: Assets Asset (r:1 w:1)
+    // Storage: Assets Metadata (r:1 w:1)
+    // Storage: AssetManager AssetIdLocation (r:0 w:1)
+    // Storage: AssetManager AssetIdMetadata (r:0 w:1)
+    fn approve_sibling_registration() -> Weight {
+        (75_906_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(6 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(7 as Weight))
+    }
+    // Storage: AssetManager SiblingRegistrationRequests (r:1 w:1)
+    fn reject_sibling_registration() -> Weight {
+        (27_418_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(1 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    // Storage: AssetManager AssetIdLocation (r:1 w:0)
+    // Storage: AssetManager AssetMintAccounting (r:1 w:1)
+    fn set_mint_cap() -> Weight {
+        (27_142_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    // Storage: AssetManager AssetIdLocation (r:1 w:0)
+    // Storage: Assets Asset (r:1 w:1)
+    // Storage: Assets Account (r:1 w:1)
+    // Storage: AssetManager AssetMintAccounting (r:1 w:1)
+    fn burn_asset() -> Weight {
+        (79_361_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(4 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(3 as Weight))
+    }
+    // Storage: AssetManager DestinationPolicies (r:0 w:1)
+    fn set_destination_policy() -> Weight {
+        (21_873_000 as Weight)
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    // Storage: AssetManager TrustedTeleporters (r:0 w:1)
+    fn set_teleport_trust() -> Weight {
+        (22_651_000 as Weight)
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
 }
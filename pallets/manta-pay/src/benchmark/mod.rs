@@ -18,7 +18,7 @@ use crate::{
     benchmark::precomputed_coins::{
         PRIVATE_TRANSFER, PRIVATE_TRANSFER_INPUT, TO_PRIVATE, TO_PUBLIC, TO_PUBLIC_INPUT,
     },
-    types::{asset_value_decode, asset_value_encode, Asset},
+    types::{asset_value_decode, asset_value_encode, Asset, UnshieldLimit},
     Call, Config, Event, Pallet, StandardAssetId, TransferPost,
 };
 use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
@@ -144,6 +144,17 @@ benchmarks! {
         // FIXME: add balance checking
         assert_last_event::<T, _>(Event::Transfer { asset, source: caller.clone(), sink });
     }
+
+    set_unshield_limit {
+        let asset_id = <T::AssetConfig as AssetConfig<T>>::NativeAssetId::get();
+        let limit = UnshieldLimit { amount: INITIAL_VALUE, period: 100u32.into() };
+    }: set_unshield_limit (
+        RawOrigin::Root,
+        asset_id,
+        Some(limit)
+    ) verify {
+        assert_last_event::<T, _>(Event::UnshieldLimitSet { asset_id, limit: Some(limit) });
+    }
 }
 
 impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
@@ -28,6 +28,9 @@
 //! * Private Asset Transfer (see [`private_transfer`]
 //! * Public Asset Transfer (see [`public_transfer`])
 //!
+//! Non-fungible items registered in the asset manager are shielded and unshielded like any other
+//! asset with a value of exactly one, provided the runtime's fungible ledger supports them.
+//!
 //! To use it in your runtime, you need to implement the assets [`Config`].
 //!
 //! The supported dispatchable functions are documented in the [`Call`] enum.
@@ -40,6 +43,8 @@
 //! * [`to_private`]: Converts a private asset back into a public one.
 //! * [`private_transfer`]: Transfers assets between two private accounts.
 //! * [`public_transfer`]: Transfers assets between two public accounts.
+//! * [`set_unshield_limit`]: Sets the maximum amount of an asset that can be converted back into
+//!   public assets per period.
 //!
 //! Please refer to the [`Call`] enum and its associated variants for documentation on each
 //! function.
@@ -50,6 +55,7 @@
 //! [`to_public`]: Pallet::to_public
 //! [`private_transfer`]: Pallet::private_transfer
 //! [`public_transfer`]: Pallet::public_transfer
+//! [`set_unshield_limit`]: Pallet::set_unshield_limit
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(doc_cfg, feature(doc_cfg))]
@@ -59,8 +65,9 @@ extern crate alloc;
 
 use crate::types::{
     asset_value_decode, asset_value_encode, fp_decode, fp_encode, Asset, AssetValue,
-    FullIncomingNote, NullifierCommitment, OutgoingNote, ReceiverChunk, SenderChunk, TransferPost,
-    Utxo, UtxoAccumulatorOutput, UtxoMerkleTreePath,
+    FullIncomingNote, NullifierCommitment, OutgoingNote, RawForestSnapshot, ReceiverChunk,
+    SenderChunk, ShardSnapshot, TransferPost, UnshieldLimit, UnshieldWindow, Utxo,
+    UtxoAccumulatorOutput, UtxoMerkleTreePath,
 };
 use alloc::{vec, vec::Vec};
 use core::marker::PhantomData;
@@ -81,7 +88,7 @@ use manta_pay::{
     manta_util::codec::Decode as _,
     parameters::load_transfer_parameters,
 };
-use manta_primitives::assets::{self, AssetConfig, FungibleLedger as _};
+use manta_primitives::assets::{self, AssetConfig, AssetStatusMap as _, FungibleLedger as _};
 use manta_util::{
     codec::{self, Encode},
     into_array_unchecked, Array,
@@ -122,7 +129,7 @@ pub mod pallet {
     use super::*;
     use frame_support::{pallet_prelude::*, traits::StorageVersion};
     use frame_system::pallet_prelude::*;
-    use sp_runtime::traits::AccountIdConversion;
+    use sp_runtime::traits::{AccountIdConversion, Saturating, Zero};
 
     const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
@@ -146,6 +153,9 @@ pub mod pallet {
 
         /// Pallet ID
         type PalletId: Get<PalletId>;
+
+        /// The origin which may set the unshielding limits of assets.
+        type UnshieldLimitOrigin: EnsureOrigin<Self::Origin>;
     }
 
     /// Fungible Ledger Implementation for [`Config`]
@@ -195,6 +205,18 @@ pub mod pallet {
     #[pallet::storage]
     pub(super) type NullifierSetSize<T: Config> = StorageValue<_, u64, ValueQuery>;
 
+    /// Unshielding Limits by Asset
+    #[pallet::storage]
+    #[pallet::getter(fn unshield_limit)]
+    pub(super) type UnshieldLimits<T: Config> =
+        StorageMap<_, Twox64Concat, StandardAssetId, UnshieldLimit<T::BlockNumber>>;
+
+    /// Current Unshielding Window by Asset
+    #[pallet::storage]
+    #[pallet::getter(fn unshield_window)]
+    pub(super) type UnshieldWindows<T: Config> =
+        StorageMap<_, Twox64Concat, StandardAssetId, UnshieldWindow<T::BlockNumber>>;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Transforms some public assets into private ones using `post`, withdrawing the public
@@ -217,6 +239,12 @@ pub mod pallet {
                     Error::<T>::ZeroTransfer
                 );
             }
+            if let Some(asset_id) = post.asset_id {
+                ensure!(
+                    Self::asset_status(asset_id)?.can_shield(),
+                    Error::<T>::AssetShieldingSuspended
+                );
+            }
             Self::post_transaction(None, vec![origin], vec![], post)
         }
 
@@ -237,6 +265,18 @@ pub mod pallet {
             for sink in post.sinks.iter() {
                 ensure!(asset_value_decode(*sink) > 0u128, Error::<T>::ZeroTransfer);
             }
+            if let Some(asset_id) = post.asset_id {
+                ensure!(
+                    Self::asset_status(asset_id)?.can_unshield(),
+                    Error::<T>::AssetShieldingSuspended
+                );
+                Self::note_unshield(
+                    Self::id_from_field(asset_id).ok_or(Error::<T>::InvalidAssetId)?,
+                    post.sinks.iter().fold(0, |total: AssetValue, sink| {
+                        total.saturating_add(asset_value_decode(*sink))
+                    }),
+                )?;
+            }
             Self::post_transaction(None, vec![], vec![origin], post)
         }
 
@@ -289,6 +329,70 @@ pub mod pallet {
             });
             Ok(().into())
         }
+
+        /// Sets the maximum amount of `asset_id` that can be converted back into public assets
+        /// over any rolling window of the period of the `limit`, removing the limit if `limit` is
+        /// `None`.
+        ///
+        /// The current unshielding window of `asset_id` is reset by this call.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::set_unshield_limit())]
+        #[transactional]
+        pub fn set_unshield_limit(
+            origin: OriginFor<T>,
+            asset_id: StandardAssetId,
+            limit: Option<UnshieldLimit<T::BlockNumber>>,
+        ) -> DispatchResultWithPostInfo {
+            T::UnshieldLimitOrigin::ensure_origin(origin)?;
+            ensure!(
+                <T::AssetConfig as AssetConfig<T>>::AssetStatusMap::is_registered(&asset_id),
+                Error::<T>::UnregisteredAsset
+            );
+            if let Some(limit) = &limit {
+                ensure!(!limit.period.is_zero(), Error::<T>::InvalidUnshieldLimit);
+            }
+            UnshieldLimits::<T>::set(asset_id, limit);
+            UnshieldWindows::<T>::remove(asset_id);
+            Self::deposit_event(Event::UnshieldLimitSet { asset_id, limit });
+            Ok(().into())
+        }
+
+        /// Atomically swaps private assets between two parties, each of which contributes one of
+        /// the private transfer legs `first` and `second`.
+        ///
+        /// Both legs are posted in this call or neither is. Each leg is proven on its own, which
+        /// enforces the balance of its asset, and deposits the same event as a
+        /// [`private_transfer`](Pallet::private_transfer).
+        ///
+        /// # Note
+        ///
+        /// In this transaction, `origin` is just signing the legs and is not necessarily related to
+        /// any of the participants in the swap itself.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::private_transfer().saturating_mul(2))]
+        #[transactional]
+        pub fn private_swap(
+            origin: OriginFor<T>,
+            first: TransferPost,
+            second: TransferPost,
+        ) -> DispatchResultWithPostInfo {
+            let origin = ensure_signed(origin)?;
+            for leg in [&first, &second] {
+                ensure!(
+                    leg.sources.is_empty()
+                        && leg.sender_posts.len() == 2
+                        && leg.receiver_posts.len() == 2
+                        && leg.sinks.is_empty(),
+                    Error::<T>::InvalidShape
+                );
+            }
+            let first = Self::post(vec![], vec![], first)?;
+            let second = Self::post(vec![], vec![], second)?;
+            Self::deposit_event(first.convert(Some(origin.clone())));
+            Self::deposit_event(second.convert(Some(origin.clone())));
+            Self::deposit_event(Event::PrivateSwap { origin });
+            Ok(().into())
+        }
     }
 
     /// Event
@@ -330,6 +434,21 @@ pub mod pallet {
             /// Sink Account
             sink: T::AccountId,
         },
+
+        /// Unshield Limit Set Event
+        UnshieldLimitSet {
+            /// Asset Id
+            asset_id: StandardAssetId,
+
+            /// New Unshield Limit
+            limit: Option<UnshieldLimit<T::BlockNumber>>,
+        },
+
+        /// Private Swap Event
+        PrivateSwap {
+            /// Origin Account
+            origin: T::AccountId,
+        },
     }
 
     /// Error
@@ -442,6 +561,31 @@ pub mod pallet {
 
         /// Encode Error
         EncodeError,
+
+        /// Unshield Limit Exceeded
+        ///
+        /// The transfer would convert more of the asset back into public assets than its unshield
+        /// limit allows for the current period.
+        UnshieldLimitExceeded,
+
+        /// Invalid Unshield Limit
+        ///
+        /// Unshield limits must have a non-zero period.
+        InvalidUnshieldLimit,
+
+        /// Unregistered Asset
+        ///
+        /// Unshield limits can only be set for assets registered in the asset manager.
+        UnregisteredAsset,
+
+        /// [`InvalidNonFungibleAmount`](FungibleLedgerError::InvalidNonFungibleAmount) from
+        /// [`FungibleLedgerError`]
+        PublicUpdateInvalidNonFungibleAmount,
+
+        /// Asset Shielding Suspended
+        ///
+        /// The asset is currently not allowed to be converted between public and private assets.
+        AssetShieldingSuspended,
     }
 
     impl<T> From<InvalidAuthorizationSignature> for Error<T>
@@ -532,6 +676,14 @@ pub mod pallet {
                 FungibleLedgerError::InvalidBurn(_) => Self::PublicUpdateInvalidBurn,
                 FungibleLedgerError::InvalidTransfer(_) => Self::PublicUpdateInvalidTransfer,
                 FungibleLedgerError::EncodeError => Self::EncodeError,
+                FungibleLedgerError::InvalidNonFungibleAmount(_) => {
+                    Self::PublicUpdateInvalidNonFungibleAmount
+                }
+                FungibleLedgerError::InvalidReserve(_)
+                | FungibleLedgerError::CannotUnreserveMoreThan(_)
+                | FungibleLedgerError::InvalidUnreserve(_)
+                | FungibleLedgerError::HoldBelowMinimum(_) => Self::InternalLedgerError,
+                FungibleLedgerError::InvalidTransferLeg(_, _) => Self::PublicUpdateInvalidTransfer,
             }
         }
     }
@@ -679,6 +831,66 @@ pub mod pallet {
             }
         }
 
+        /// Returns a snapshot of the shard trees of the UTXO accumulator forest and their current
+        /// accumulator outputs, together with the checkpoint matching them.
+        ///
+        /// Returns `None` if the stored ledger state or the accumulator parameters could not be
+        /// decoded.
+        #[inline]
+        pub fn forest_snapshot() -> Option<RawForestSnapshot> {
+            let utxo_accumulator_model = config::UtxoAccumulatorModel::decode(
+                manta_parameters::pay::parameters::UtxoAccumulatorModel::get()?,
+            )
+            .ok()?;
+            let mut receiver_index = [0; MerkleTreeConfiguration::FOREST_WIDTH];
+            let mut shards = Vec::with_capacity(MerkleTreeConfiguration::FOREST_WIDTH);
+            for (shard_index, index) in receiver_index.iter_mut().enumerate() {
+                let tree = ShardTrees::<T>::get(shard_index as u8);
+                let utxo_accumulator_output = match tree.leaf_digest {
+                    Some(leaf_digest) => {
+                        *index = tree.current_path.leaf_index as u64 + 1;
+                        let current_path: merkle_tree::CurrentPath<MerkleTreeConfiguration> =
+                            tree.current_path.clone().try_into().ok()?;
+                        let root = current_path.root(
+                            &utxo_accumulator_model,
+                            &fp_decode(leaf_digest.to_vec()).ok()?,
+                        );
+                        Some(fp_encode(root).ok()?)
+                    }
+                    _ => None,
+                };
+                shards.push(ShardSnapshot {
+                    tree,
+                    utxo_accumulator_output,
+                });
+            }
+            Some(RawForestSnapshot {
+                shards,
+                checkpoint: RawCheckpoint::new(receiver_index, NullifierSetSize::<T>::get()),
+            })
+        }
+
+        /// Records that `value` of `asset_id` is converted back into public assets, failing if it
+        /// exceeds the unshield limit of `asset_id` over its rolling window.
+        #[inline]
+        fn note_unshield(asset_id: StandardAssetId, value: AssetValue) -> DispatchResult {
+            let limit = match UnshieldLimits::<T>::get(asset_id) {
+                Some(limit) => limit,
+                _ => return Ok(()),
+            };
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut window =
+                UnshieldWindows::<T>::get(asset_id).unwrap_or_else(|| UnshieldWindow::new(now));
+            window.advance(now, &limit);
+            window.unshielded = window
+                .unshielded
+                .checked_add(value)
+                .filter(|unshielded| *unshielded <= limit.amount)
+                .ok_or(Error::<T>::UnshieldLimitExceeded)?;
+            UnshieldWindows::<T>::insert(asset_id, window);
+            Ok(())
+        }
+
         /// Returns the account ID of this pallet.
         #[inline]
         pub fn account_id() -> T::AccountId {
@@ -694,22 +906,39 @@ pub mod pallet {
             sinks: Vec<T::AccountId>,
             post: TransferPost,
         ) -> DispatchResultWithPostInfo {
-            Self::deposit_event(
-                config::TransferPost::try_from(post)
-                    .map_err(|_| Error::<T>::InvalidSerializedForm)?
-                    .post(
-                        &load_transfer_parameters(),
-                        &mut Ledger(PhantomData),
-                        &(),
-                        sources,
-                        sinks,
-                    )
-                    .map_err(Error::<T>::from)?
-                    .convert(origin),
-            );
+            Self::deposit_event(Self::post(sources, sinks, post)?.convert(origin));
             Ok(().into())
         }
 
+        /// Posts the transaction encoded in `post` to the ledger like
+        /// [`post_transaction`](Self::post_transaction), returning its event instead of depositing
+        /// it.
+        #[inline]
+        fn post(
+            sources: Vec<T::AccountId>,
+            sinks: Vec<T::AccountId>,
+            post: TransferPost,
+        ) -> Result<PreprocessedEvent<T>, Error<T>> {
+            config::TransferPost::try_from(post)
+                .map_err(|_| Error::<T>::InvalidSerializedForm)?
+                .post(
+                    &load_transfer_parameters(),
+                    &mut Ledger(PhantomData),
+                    &(),
+                    sources,
+                    sinks,
+                )
+                .map_err(Error::<T>::from)
+        }
+
+        /// Returns the [`AssetStatus`](assets::AssetStatus) of the asset encoded in `id`.
+        #[inline]
+        fn asset_status(id: [u8; 32]) -> Result<assets::AssetStatus, Error<T>> {
+            Ok(<T::AssetConfig as AssetConfig<T>>::AssetStatusMap::status(
+                &Self::id_from_field(id).ok_or(Error::<T>::InvalidAssetId)?,
+            ))
+        }
+
         ///
         #[inline]
         pub fn id_from_field(id: [u8; 32]) -> Option<StandardAssetId> {
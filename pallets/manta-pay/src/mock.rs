@@ -17,18 +17,20 @@
 use frame_support::{
     pallet_prelude::DispatchResult,
     parameter_types,
-    traits::{ConstU32, Everything},
+    traits::{fungibles, AsEnsureOriginWithArg, ConstU128, ConstU32, Everything, GenesisBuild},
     PalletId,
 };
-use frame_system::EnsureRoot;
+use frame_system::{EnsureNever, EnsureRoot, EnsureSigned};
 use manta_primitives::{
     assets::{
         AssetConfig, AssetIdType, AssetLocation, AssetRegistry, AssetRegistryMetadata,
-        AssetStorageMetadata, BalanceType, LocationType, NativeAndNonNative,
+        AssetStorageMetadata, BalanceType, FungibleAndNonFungible, LocationType,
+        NativeAndNonNative, PalletItemLocker,
     },
     constants::{ASSET_MANAGER_PALLET_ID, MANTA_PAY_PALLET_ID},
     types::{Balance, BlockNumber, Header},
 };
+use scale_codec::{Compact, Decode, Encode};
 use sp_core::H256;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
@@ -56,6 +58,7 @@ frame_support::construct_runtime!(
         Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
         Assets: pallet_assets::{Pallet, Storage, Event<T>},
         AssetManager: pallet_asset_manager::{Pallet, Call, Storage, Event<T>},
+        Uniques: pallet_uniques::{Pallet, Call, Storage, Event<T>},
     }
 );
 
@@ -136,6 +139,25 @@ impl pallet_assets::Config for Test {
     type WeightInfo = pallet_assets::weights::SubstrateWeight<Test>;
 }
 
+impl pallet_uniques::Config for Test {
+    type Event = Event;
+    type CollectionId = u32;
+    type ItemId = u32;
+    type Currency = Balances;
+    type ForceOrigin = EnsureRoot<AccountId32>;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId32>>;
+    type Locker = PalletItemLocker<MantaPayPalletId, AccountId32, Uniques>;
+    type CollectionDeposit = ConstU128<0>;
+    type ItemDeposit = ConstU128<0>;
+    type MetadataDepositBase = ConstU128<0>;
+    type AttributeDepositBase = ConstU128<0>;
+    type DepositPerByte = ConstU128<0>;
+    type StringLimit = AssetsStringLimit;
+    type KeyLimit = ConstU32<32>;
+    type ValueLimit = ConstU32<64>;
+    type WeightInfo = pallet_uniques::weights::SubstrateWeight<Test>;
+}
+
 pub struct MantaAssetRegistry;
 impl BalanceType for MantaAssetRegistry {
     type Balance = Balance;
@@ -196,6 +218,38 @@ impl AssetRegistry for MantaAssetRegistry {
             metadata.is_frozen,
         )
     }
+
+    fn total_supply(asset_id: &StandardAssetId) -> Balance {
+        <Assets as fungibles::Inspect<AccountId32>>::total_issuance(*asset_id)
+    }
+
+    fn accounts_and_approvals(asset_id: &StandardAssetId) -> (u32, u32) {
+        // NOTE: The fields of `DestroyWitness` are private, but it is encoded as the compact
+        //       numbers of accounts, sufficient accounts and approvals of the asset.
+        <Assets as fungibles::Destroy<AccountId32>>::get_destroy_witness(asset_id)
+            .and_then(|witness| {
+                <(Compact<u32>, Compact<u32>, Compact<u32>)>::decode(
+                    &mut witness.encode().as_slice(),
+                )
+                .ok()
+            })
+            .map(|(accounts, _, approvals)| (accounts.0, approvals.0))
+            .unwrap_or_default()
+    }
+
+    fn freeze_asset(asset_id: &StandardAssetId) -> DispatchResult {
+        Assets::freeze_asset(Origin::signed(AssetManager::account_id()), *asset_id)
+    }
+
+    fn destroy_asset(asset_id: &StandardAssetId) -> DispatchResult {
+        // NOTE: `DestroyWitness` can only be built by decoding it. An empty witness makes
+        //       `pallet_assets` refuse the destruction while any accounts or approvals remain.
+        let witness = pallet_assets::DestroyWitness::decode(&mut [0u8; 3].as_slice())
+            .map_err(|_| sp_runtime::DispatchError::Other("Invalid destroy witness"))?;
+        Assets::destroy(Origin::root(), *asset_id, witness)
+            .map(|_| ())
+            .map_err(|err| err.error)
+    }
 }
 
 parameter_types! {
@@ -237,7 +291,12 @@ impl AssetConfig<Test> for MantaAssetConfig {
     type NativeAssetMetadata = NativeAssetMetadata;
     type StorageMetadata = AssetStorageMetadata;
     type AssetRegistry = MantaAssetRegistry;
-    type FungibleLedger = NativeAndNonNative<Test, MantaAssetConfig, Balances, Assets>;
+    type FungibleLedger = FungibleAndNonFungible<
+        NativeAndNonNative<Test, MantaAssetConfig, Balances, Assets>,
+        AssetManager,
+        Uniques,
+    >;
+    type AssetStatusMap = AssetManager;
 }
 
 impl pallet_asset_manager::Config for Test {
@@ -246,7 +305,18 @@ impl pallet_asset_manager::Config for Test {
     type Balance = Balance;
     type Location = AssetLocation;
     type AssetConfig = MantaAssetConfig;
+    type CollectionId = u32;
+    type ItemId = u32;
+    type NonFungibles = Uniques;
     type ModifierOrigin = EnsureRoot<AccountId32>;
+    type MintCapOrigin = EnsureNever<AccountId32>;
+    type MaxLocationAliases = ConstU32<4>;
+    type Currency = Balances;
+    type ProposalDeposit = ConstU128<1_000>;
+    type ProposalLifetime = ConstU32<100>;
+    type OnProposalSlash = ();
+    type SiblingOrigin = EnsureNever<u32>;
+    type MaxSiblingRequests = ConstU32<4>;
     type PalletId = AssetManagerPalletId;
     type WeightInfo = ();
 }
@@ -260,11 +330,15 @@ impl crate::Config for Test {
     type WeightInfo = crate::weights::SubstrateWeight<Self>;
     type PalletId = MantaPayPalletId;
     type AssetConfig = MantaAssetConfig;
+    type UnshieldLimitOrigin = EnsureRoot<AccountId32>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    frame_system::GenesisConfig::default()
+    let mut storage = frame_system::GenesisConfig::default()
         .build_storage::<Test>()
-        .unwrap()
-        .into()
+        .unwrap();
+    pallet_asset_manager::GenesisConfig::<Test>::default()
+        .assimilate_storage(&mut storage)
+        .unwrap();
+    storage.into()
 }
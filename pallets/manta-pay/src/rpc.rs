@@ -16,7 +16,11 @@
 
 //! MantaPay RPC Interfaces
 
-use crate::{runtime::PullLedgerDiffApi, types::DensePullResponse, Checkpoint, PullResponse};
+use crate::{
+    runtime::{ForestSnapshotApi, PullLedgerDiffApi},
+    types::{DensePullEncoding, DensePullResponse, ForestSnapshot},
+    Checkpoint, PullResponse,
+};
 use alloc::sync::Arc;
 use core::marker::PhantomData;
 use jsonrpsee::{
@@ -31,6 +35,12 @@ use sp_runtime::{generic::BlockId, traits::Block};
 /// Pull Ledger Diff Error Code
 pub const PULL_LEDGER_DIFF_ERROR: i32 = 1;
 
+/// Dense Pull Encoding Error Code
+pub const DENSE_PULL_ENCODING_ERROR: i32 = 2;
+
+/// Forest Snapshot Error Code
+pub const FOREST_SNAPSHOT_ERROR: i32 = 3;
+
 /// Pull API
 #[rpc(server)]
 pub trait PullApi {
@@ -44,12 +54,18 @@ pub trait PullApi {
         max_senders: u64,
     ) -> RpcResult<PullResponse>;
 
+    /// Returns the update required to be synchronized with the ledger starting from
+    /// `checkpoint`, with the receiver and sender chunks encoded densely.
+    ///
+    /// The chunks are base64 encoded SCALE bytes unless a compressed `encoding` is requested, in
+    /// which case the uncompressed sizes are reported alongside them.
     #[method(name = "mantaPay_dense_pull_ledger_diff", blocking)]
     fn dense_pull_ledger_diff(
         &self,
         checkpoint: Checkpoint,
         max_receivers: u64,
         max_senders: u64,
+        encoding: Option<DensePullEncoding>,
     ) -> RpcResult<DensePullResponse>;
 }
 
@@ -106,18 +122,84 @@ where
         checkpoint: Checkpoint,
         max_receivers: u64,
         max_senders: u64,
+        encoding: Option<DensePullEncoding>,
     ) -> RpcResult<DensePullResponse> {
         let api = self.client.runtime_api();
         let at = BlockId::hash(self.client.info().finalized_hash);
-        api.pull_ledger_diff(&at, checkpoint.into(), max_receivers, max_senders)
-            .map(Into::into)
+        let response = api
+            .pull_ledger_diff(&at, checkpoint.into(), max_receivers, max_senders)
             .map_err(|err| {
                 CallError::Custom(ErrorObject::owned(
                     PULL_LEDGER_DIFF_ERROR,
                     "Unable to compute dense state diff for pull",
                     Some(format!("{err:?}")),
                 ))
-                .into()
-            })
+            })?;
+        DensePullResponse::new(response, encoding.unwrap_or_default()).map_err(|err| {
+            CallError::Custom(ErrorObject::owned(
+                DENSE_PULL_ENCODING_ERROR,
+                "Unable to encode dense state diff for pull",
+                Some(format!("{err:?}")),
+            ))
+            .into()
+        })
+    }
+}
+
+/// Snapshot API
+#[rpc(server)]
+pub trait SnapshotApi<BlockHash> {
+    /// Returns a snapshot of the UTXO accumulator forest at the block `at`, or at the last
+    /// finalized block if `at` is not given, together with the checkpoint matching it.
+    #[method(name = "mantaPay_forest_snapshot", blocking)]
+    fn forest_snapshot(&self, at: Option<BlockHash>) -> RpcResult<ForestSnapshot>;
+}
+
+/// Snapshot RPC API Implementation
+pub struct Snapshot<B, C> {
+    /// Client
+    client: Arc<C>,
+
+    /// Type Parameter Marker
+    __: PhantomData<B>,
+}
+
+impl<B, C> Snapshot<B, C> {
+    /// Builds a new [`Snapshot`] RPC API implementation.
+    #[inline]
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            __: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<B, C> SnapshotApiServer<B::Hash> for Snapshot<B, C>
+where
+    B: Block,
+    C: 'static + ProvideRuntimeApi<B> + HeaderBackend<B>,
+    C::Api: ForestSnapshotApi<B>,
+{
+    #[inline]
+    fn forest_snapshot(&self, at: Option<B::Hash>) -> RpcResult<ForestSnapshot> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().finalized_hash));
+        match api.forest_snapshot(&at) {
+            Ok(Some(snapshot)) => Ok(snapshot.into()),
+            Ok(None) => Err(CallError::Custom(ErrorObject::owned(
+                FOREST_SNAPSHOT_ERROR,
+                "Unable to decode the ledger state for the forest snapshot",
+                None::<()>,
+            ))
+            .into()),
+            Err(err) => Err(CallError::Custom(ErrorObject::owned(
+                FOREST_SNAPSHOT_ERROR,
+                "Unable to compute the forest snapshot",
+                Some(format!("{err:?}")),
+            ))
+            .into()),
+        }
     }
 }
@@ -16,10 +16,14 @@
 
 //! MantaPay Runtime APIs
 
-use crate::{PullResponse, RawCheckpoint};
+use crate::{types::RawForestSnapshot, PullResponse, RawCheckpoint};
 
 sp_api::decl_runtime_apis! {
     pub trait PullLedgerDiffApi {
         fn pull_ledger_diff(checkpoint: RawCheckpoint, max_receivers: u64, max_senders: u64) -> PullResponse;
     }
+
+    pub trait ForestSnapshotApi {
+        fn forest_snapshot() -> Option<RawForestSnapshot>;
+    }
 }
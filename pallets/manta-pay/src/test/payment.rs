@@ -17,13 +17,22 @@
 use crate::{
     fp_decode,
     mock::{
-        new_test_ext, MantaAssetConfig, MantaAssetRegistry, MantaPayPallet, Origin as MockOrigin,
-        Test,
+        new_test_ext, AssetManager, MantaAssetConfig, MantaAssetRegistry, MantaPayPallet,
+        Origin as MockOrigin, System, Test, Uniques,
+    },
+    types::{
+        asset_value_decode, fp_encode, AssetId, AssetValue, TransferPost as PalletTransferPost,
+        UnshieldLimit, UnshieldWindow,
     },
-    types::{fp_encode, AssetId, AssetValue, TransferPost as PalletTransferPost},
     Error, FungibleLedger, StandardAssetId,
 };
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{
+        tokens::nonfungibles::{Create, Inspect, Mutate},
+        ExistenceRequirement,
+    },
+};
 use manta_accounting::transfer::test::value_distribution;
 use manta_crypto::{
     arkworks::constraint::fp::Fp,
@@ -40,12 +49,17 @@ use manta_pay::{
 };
 use manta_primitives::{
     assets::{
-        AssetConfig, AssetRegistry, AssetRegistryMetadata, AssetStorageMetadata,
-        FungibleLedger as _,
+        AssetConfig, AssetLocation, AssetRegistry, AssetRegistryMetadata, AssetStatus,
+        AssetStorageMetadata, FungibleLedger as _,
     },
     constants::TEST_DEFAULT_ASSET_ED,
 };
 use std::{env, path::Path};
+use xcm::{
+    prelude::{Parachain, X1},
+    v1::MultiLocation,
+    VersionedMultiLocation,
+};
 
 /// UTXO Accumulator for Building Circuits
 type UtxoAccumulator =
@@ -61,6 +75,7 @@ lazy_static::lazy_static! {
 const RANDOMIZED_TESTS_ITERATIONS: usize = 10;
 
 pub const ALICE: sp_runtime::AccountId32 = sp_runtime::AccountId32::new([0u8; 32]);
+pub const BOB: sp_runtime::AccountId32 = sp_runtime::AccountId32::new([1u8; 32]);
 pub const NATIVE_ASSET_ID: StandardAssetId =
     <MantaAssetConfig as AssetConfig<Test>>::NativeAssetId::get();
 
@@ -130,6 +145,64 @@ where
     }
 }
 
+/// Samples a [`PrivateTransfer`] of `value` for each of its two senders, posting the
+/// [`ToPrivate`] transactions of its inputs to the ledger.
+#[inline]
+fn sample_private_transfer<R>(
+    asset_id: StandardAssetId,
+    value: AssetValue,
+    utxo_accumulator: &mut UtxoAccumulator,
+    rng: &mut R,
+) -> PalletTransferPost
+where
+    R: CryptoRng + RngCore + ?Sized,
+{
+    let (inputs, private_transfer) = test::payment::private_transfer::prove_full(
+        &PROVING_CONTEXT,
+        &PARAMETERS,
+        utxo_accumulator,
+        Fp::from(asset_id),
+        [value, value],
+        rng,
+    );
+    for input in inputs {
+        assert_ok!(MantaPayPallet::to_private(
+            MockOrigin::signed(ALICE),
+            PalletTransferPost::try_from(input).unwrap()
+        ));
+    }
+    PalletTransferPost::try_from(private_transfer).unwrap()
+}
+
+/// Samples a [`ToPublic`] of `value` for each of its two senders, posting the [`ToPrivate`]
+/// transactions of its inputs to the ledger.
+#[inline]
+fn sample_posted_to_public<R>(
+    asset_id: StandardAssetId,
+    value: AssetValue,
+    utxo_accumulator: &mut UtxoAccumulator,
+    rng: &mut R,
+) -> PalletTransferPost
+where
+    R: CryptoRng + RngCore + ?Sized,
+{
+    let (inputs, to_public) = test::payment::to_public::prove_full(
+        &PROVING_CONTEXT,
+        &PARAMETERS,
+        utxo_accumulator,
+        Fp::from(asset_id),
+        [value, value],
+        rng,
+    );
+    for input in inputs {
+        assert_ok!(MantaPayPallet::to_private(
+            MockOrigin::signed(ALICE),
+            PalletTransferPost::try_from(input).unwrap()
+        ));
+    }
+    PalletTransferPost::try_from(to_public).unwrap()
+}
+
 /// Builds `count`-many [`PrivateTransfer`] tests.
 #[inline]
 fn private_transfer_test<R>(
@@ -331,6 +404,40 @@ fn initialize_test(id: StandardAssetId, value: AssetValue) {
     ));
 }
 
+/// Registers a new asset in the asset manager and mints `value` of it for [`ALICE`], returning
+/// its asset id.
+fn register_test_asset(value: AssetValue) -> StandardAssetId {
+    let metadata = AssetRegistryMetadata {
+        metadata: AssetStorageMetadata {
+            name: b"Calamari".to_vec(),
+            symbol: b"KMA".to_vec(),
+            decimals: 12,
+            is_frozen: false,
+        },
+        min_balance: TEST_DEFAULT_ASSET_ED,
+        is_sufficient: true,
+    };
+    let location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+        1,
+        X1(Parachain(2000)),
+    )));
+    let asset_id = AssetManager::next_asset_id();
+    assert_ok!(AssetManager::register_asset(
+        MockOrigin::root(),
+        location,
+        metadata
+    ));
+    assert_ok!(FungibleLedger::<Test>::deposit_minting(
+        asset_id, &ALICE, value
+    ));
+    assert_ok!(FungibleLedger::<Test>::deposit_minting(
+        asset_id,
+        &MantaPayPallet::account_id(),
+        TEST_DEFAULT_ASSET_ED
+    ));
+    asset_id
+}
+
 /// Tests multiple to_private from some total supply.
 #[test]
 fn to_private_should_work() {
@@ -349,6 +456,85 @@ fn to_private_should_work() {
     }
 }
 
+/// Tests that a registered non-fungible item can only be converted into a private asset with a
+/// value of one.
+#[test]
+fn non_fungible_to_private_should_work() {
+    let mut rng = OsRng;
+    new_test_ext().execute_with(|| {
+        let (collection, item) = (0, 0);
+        assert_ok!(Uniques::create_collection(
+            &collection,
+            &AssetManager::account_id(),
+            &AssetManager::account_id()
+        ));
+        assert_ok!(Uniques::mint_into(&collection, &item, &ALICE));
+        assert_ok!(AssetManager::register_non_fungible_asset(
+            MockOrigin::root(),
+            collection,
+            item
+        ));
+        let asset_id = AssetManager::non_fungible_asset_id(collection, item).unwrap();
+        assert_noop!(
+            MantaPayPallet::to_private(
+                MockOrigin::signed(ALICE),
+                sample_to_private(MantaPayPallet::field_from_id(asset_id), 2, &mut rng)
+            ),
+            Error::<Test>::InvalidSourceAccount
+        );
+        mint_private_tokens(asset_id, &[1], &mut rng);
+        assert_eq!(
+            <Uniques as Inspect<_>>::owner(&collection, &item),
+            Some(MantaPayPallet::account_id())
+        );
+    });
+}
+
+/// Tests that a shielded non-fungible item cannot be moved out of the MantaPay account by the
+/// owner of its collection, but only through the ledger.
+#[test]
+fn shielded_non_fungible_should_be_locked() {
+    let mut rng = OsRng;
+    new_test_ext().execute_with(|| {
+        let (collection, item) = (0, 0);
+        let collection_owner = AssetManager::account_id();
+        assert_ok!(Uniques::create_collection(
+            &collection,
+            &collection_owner,
+            &collection_owner
+        ));
+        assert_ok!(Uniques::mint_into(&collection, &item, &ALICE));
+        assert_ok!(AssetManager::register_non_fungible_asset(
+            MockOrigin::root(),
+            collection,
+            item
+        ));
+        let asset_id = AssetManager::non_fungible_asset_id(collection, item).unwrap();
+        mint_private_tokens(asset_id, &[1], &mut rng);
+        assert_noop!(
+            Uniques::transfer(MockOrigin::signed(collection_owner), collection, item, BOB),
+            pallet_uniques::Error::<Test>::Locked
+        );
+        assert_ok!(FungibleLedger::<Test>::transfer(
+            asset_id,
+            &MantaPayPallet::account_id(),
+            &ALICE,
+            1,
+            ExistenceRequirement::KeepAlive
+        ));
+        assert_ok!(Uniques::transfer(
+            MockOrigin::signed(ALICE),
+            collection,
+            item,
+            BOB
+        ));
+        assert_eq!(
+            <Uniques as Inspect<_>>::owner(&collection, &item),
+            Some(BOB)
+        );
+    });
+}
+
 /// Tests to_private with zero balance should failed.
 #[test]
 fn to_private_with_zero_should_not_work() {
@@ -616,3 +802,322 @@ fn pull_ledger_diff_should_work() {
         assert_eq!(runtime_pull_response.senders, decoded_senders);
     });
 }
+
+#[test]
+fn dense_pull_ledger_diff_encodings_should_work() {
+    use crate::types::{DensePullEncoding, DensePullResponse};
+    new_test_ext().execute_with(|| {
+        let mut rng = OsRng;
+        let asset_id = rng.gen();
+        let total_free_supply = rng.gen();
+        initialize_test(asset_id, total_free_supply + TEST_DEFAULT_ASSET_ED);
+        mint_private_tokens(
+            asset_id,
+            &value_distribution(5, total_free_supply, &mut rng),
+            &mut rng,
+        );
+        let runtime_pull_response =
+            MantaPayPallet::pull_ledger_diff(crate::Checkpoint::default(), 128, 128);
+
+        // the default encoding must match the legacy base64 response
+        let dense_pull_response =
+            DensePullResponse::new(runtime_pull_response.clone(), DensePullEncoding::Base64)
+                .unwrap();
+        assert_eq!(
+            dense_pull_response,
+            DensePullResponse::from(runtime_pull_response.clone())
+        );
+        assert_eq!(dense_pull_response.receivers_size, None);
+        assert_eq!(
+            dense_pull_response.into_pull_response().unwrap(),
+            runtime_pull_response
+        );
+
+        #[cfg(feature = "zstd")]
+        {
+            use scale_codec::Encode;
+            let compressed_pull_response =
+                DensePullResponse::new(runtime_pull_response.clone(), DensePullEncoding::Zstd)
+                    .unwrap();
+            assert_eq!(
+                compressed_pull_response.receivers_size,
+                Some(runtime_pull_response.receivers.encode().len() as u64)
+            );
+
+            // a server reporting an oversized chunk must not make the client allocate for it
+            let mut oversized_pull_response = compressed_pull_response.clone();
+            oversized_pull_response.receivers_size = Some(u64::MAX);
+            assert_eq!(
+                oversized_pull_response.decode_receivers(),
+                Err(crate::types::DensePullError::SizeLimitExceeded)
+            );
+
+            assert_eq!(
+                compressed_pull_response.into_pull_response().unwrap(),
+                runtime_pull_response
+            );
+        }
+    });
+}
+
+#[test]
+fn forest_snapshot_should_match_ledger() {
+    new_test_ext().execute_with(|| {
+        let empty_snapshot = MantaPayPallet::forest_snapshot().unwrap();
+        assert_eq!(
+            empty_snapshot.shards.len(),
+            MerkleTreeConfiguration::FOREST_WIDTH
+        );
+        assert!(empty_snapshot
+            .shards
+            .iter()
+            .all(|shard| shard.utxo_accumulator_output.is_none()));
+        assert_eq!(empty_snapshot.checkpoint, crate::RawCheckpoint::default());
+
+        let mut rng = OsRng;
+        let asset_id = rng.gen();
+        let total_free_supply = rng.gen();
+        initialize_test(asset_id, total_free_supply + TEST_DEFAULT_ASSET_ED);
+        mint_private_tokens(
+            asset_id,
+            &value_distribution(5, total_free_supply, &mut rng),
+            &mut rng,
+        );
+
+        let snapshot = MantaPayPallet::forest_snapshot().unwrap();
+        for shard in snapshot.shards.iter() {
+            if let Some(output) = shard.utxo_accumulator_output {
+                assert!(crate::pallet::UtxoAccumulatorOutputs::<Test>::contains_key(
+                    output
+                ));
+            }
+        }
+        assert_eq!(snapshot.checkpoint.receiver_index.iter().sum::<u64>(), 5);
+
+        // nothing is left to pull after the snapshot checkpoint
+        let pull_response = MantaPayPallet::pull_ledger_diff(snapshot.checkpoint.into(), 128, 128);
+        assert!(!pull_response.should_continue);
+        assert!(pull_response.receivers.is_empty());
+        assert!(pull_response.senders.is_empty());
+    });
+}
+
+#[test]
+fn set_unshield_limit_should_work() {
+    new_test_ext().execute_with(|| {
+        let limit = UnshieldLimit {
+            amount: 1_000,
+            period: 10,
+        };
+        assert_noop!(
+            MantaPayPallet::set_unshield_limit(MockOrigin::root(), 8, Some(limit)),
+            Error::<Test>::UnregisteredAsset
+        );
+        let asset_id = register_test_asset(TEST_DEFAULT_ASSET_ED);
+        assert_noop!(
+            MantaPayPallet::set_unshield_limit(MockOrigin::signed(ALICE), asset_id, Some(limit)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            MantaPayPallet::set_unshield_limit(
+                MockOrigin::root(),
+                asset_id,
+                Some(UnshieldLimit {
+                    amount: 1_000,
+                    period: 0
+                })
+            ),
+            Error::<Test>::InvalidUnshieldLimit
+        );
+        assert_ok!(MantaPayPallet::set_unshield_limit(
+            MockOrigin::root(),
+            asset_id,
+            Some(limit)
+        ));
+        assert_eq!(MantaPayPallet::unshield_limit(asset_id), Some(limit));
+        assert_ok!(MantaPayPallet::set_unshield_limit(
+            MockOrigin::root(),
+            asset_id,
+            None
+        ));
+        assert_eq!(MantaPayPallet::unshield_limit(asset_id), None);
+    });
+}
+
+/// Tests that the unshielded amount is released linearly over the period of the limit.
+#[test]
+fn unshield_window_should_roll() {
+    let limit = UnshieldLimit {
+        amount: 1_000,
+        period: 10,
+    };
+    let mut window = UnshieldWindow {
+        updated: 1,
+        unshielded: 1_000,
+    };
+    window.advance(6, &limit);
+    assert_eq!(
+        window,
+        UnshieldWindow {
+            updated: 6,
+            unshielded: 500
+        }
+    );
+    window.advance(8, &limit);
+    assert_eq!(
+        window,
+        UnshieldWindow {
+            updated: 8,
+            unshielded: 300
+        }
+    );
+    window.advance(20, &limit);
+    assert_eq!(
+        window,
+        UnshieldWindow {
+            updated: 20,
+            unshielded: 0
+        }
+    );
+}
+
+#[test]
+fn to_public_above_unshield_limit_should_not_work() {
+    let mut rng = OsRng;
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let value: AssetValue = 1_000;
+        let asset_id = register_test_asset(4 * value + TEST_DEFAULT_ASSET_ED);
+        let mut utxo_accumulator = UtxoAccumulator::new(UTXO_ACCUMULATOR_MODEL.clone());
+        let first = sample_posted_to_public(asset_id, value, &mut utxo_accumulator, &mut rng);
+        let second = sample_posted_to_public(asset_id, value, &mut utxo_accumulator, &mut rng);
+        let unshielded = asset_value_decode(first.sinks[0]);
+        let amount = unshielded.max(asset_value_decode(second.sinks[0]));
+        assert_ok!(MantaPayPallet::set_unshield_limit(
+            MockOrigin::root(),
+            asset_id,
+            Some(UnshieldLimit { amount, period: 10 })
+        ));
+        assert_ok!(MantaPayPallet::to_public(MockOrigin::signed(ALICE), first));
+        assert_eq!(
+            MantaPayPallet::unshield_window(asset_id),
+            Some(UnshieldWindow {
+                updated: 1,
+                unshielded
+            })
+        );
+        // the limit is used up until the first unshielding has left the rolling window
+        assert_noop!(
+            MantaPayPallet::to_public(MockOrigin::signed(ALICE), second.clone()),
+            Error::<Test>::UnshieldLimitExceeded
+        );
+        System::set_block_number(11);
+        assert_ok!(MantaPayPallet::to_public(MockOrigin::signed(ALICE), second));
+    });
+}
+
+/// Tests that the asset status decides whether the asset can be converted between public and
+/// private assets.
+#[test]
+fn asset_status_should_gate_shielding() {
+    let mut rng = OsRng;
+    new_test_ext().execute_with(|| {
+        let value: AssetValue = 1_000;
+        let asset_id = register_test_asset(2 * value + TEST_DEFAULT_ASSET_ED);
+        let mut utxo_accumulator = UtxoAccumulator::new(UTXO_ACCUMULATOR_MODEL.clone());
+        let ([to_private_0, to_private_1], to_public) = test::payment::to_public::prove_full(
+            &PROVING_CONTEXT,
+            &PARAMETERS,
+            &mut utxo_accumulator,
+            Fp::from(asset_id),
+            [value, value],
+            &mut rng,
+        );
+        let to_private_0 = PalletTransferPost::try_from(to_private_0).unwrap();
+        assert_ok!(AssetManager::set_asset_status(
+            MockOrigin::root(),
+            asset_id,
+            AssetStatus::ShieldingSuspended
+        ));
+        assert_noop!(
+            MantaPayPallet::to_private(MockOrigin::signed(ALICE), to_private_0.clone()),
+            Error::<Test>::AssetShieldingSuspended
+        );
+        assert_ok!(AssetManager::set_asset_status(
+            MockOrigin::root(),
+            asset_id,
+            AssetStatus::XcmSuspended
+        ));
+        for post in [
+            to_private_0,
+            PalletTransferPost::try_from(to_private_1).unwrap(),
+        ] {
+            assert_ok!(MantaPayPallet::to_private(MockOrigin::signed(ALICE), post));
+        }
+        assert_ok!(AssetManager::set_asset_status(
+            MockOrigin::root(),
+            asset_id,
+            AssetStatus::Deprecated
+        ));
+        assert_noop!(
+            MantaPayPallet::to_private(
+                MockOrigin::signed(ALICE),
+                sample_to_private(MantaPayPallet::field_from_id(asset_id), value, &mut rng)
+            ),
+            Error::<Test>::AssetShieldingSuspended
+        );
+        assert_ok!(MantaPayPallet::to_public(
+            MockOrigin::signed(ALICE),
+            PalletTransferPost::try_from(to_public).unwrap()
+        ));
+    });
+}
+
+/// Tests that both legs of a private swap are posted together or not at all.
+#[test]
+fn private_swap_should_work() {
+    let mut rng = OsRng;
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let (first_asset, second_asset) = (8, 9);
+        let value: AssetValue = 1_000;
+        for asset_id in [first_asset, second_asset] {
+            initialize_test(asset_id, 4 * value + TEST_DEFAULT_ASSET_ED);
+        }
+        let mut utxo_accumulator = UtxoAccumulator::new(UTXO_ACCUMULATOR_MODEL.clone());
+        let first = sample_private_transfer(first_asset, value, &mut utxo_accumulator, &mut rng);
+        let second = sample_private_transfer(second_asset, value, &mut utxo_accumulator, &mut rng);
+        let spent = sample_private_transfer(second_asset, value, &mut utxo_accumulator, &mut rng);
+        assert_ok!(MantaPayPallet::private_transfer(
+            MockOrigin::signed(BOB),
+            spent.clone()
+        ));
+
+        // a failing leg reverts the whole swap, so `first` can still be swapped afterwards
+        assert_noop!(
+            MantaPayPallet::private_swap(MockOrigin::signed(BOB), first.clone(), spent),
+            Error::<Test>::AssetSpent
+        );
+
+        System::reset_events();
+        assert_ok!(MantaPayPallet::private_swap(
+            MockOrigin::signed(BOB),
+            first.clone(),
+            second.clone()
+        ));
+        let transfer_event: crate::mock::Event =
+            crate::Event::<Test>::PrivateTransfer { origin: Some(BOB) }.into();
+        assert_eq!(
+            System::events()
+                .into_iter()
+                .filter(|record| record.event == transfer_event)
+                .count(),
+            2
+        );
+        System::assert_last_event(crate::Event::<Test>::PrivateSwap { origin: BOB }.into());
+        assert_noop!(
+            MantaPayPallet::private_swap(MockOrigin::signed(BOB), first, second),
+            Error::<Test>::AssetSpent
+        );
+    });
+}
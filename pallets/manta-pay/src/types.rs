@@ -31,8 +31,12 @@ use manta_pay::{
     },
 };
 use manta_util::{Array, BoxArray};
-use scale_codec::{Decode, Encode, Error, MaxEncodedLen};
+use scale_codec::{Compact, Decode, Encode, Error, MaxEncodedLen};
 use scale_info::TypeInfo;
+use sp_runtime::{
+    helpers_128bit::multiply_by_rational,
+    traits::{AtLeast32BitUnsigned, UniqueSaturatedInto},
+};
 
 #[cfg(feature = "rpc")]
 use manta_pay::manta_util::serde::{Deserialize, Serialize};
@@ -787,6 +791,65 @@ impl TryFrom<TransferPost> for config::TransferPost {
     }
 }
 
+/// Unshield Limit
+#[derive(Clone, Copy, Debug, Decode, Default, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub struct UnshieldLimit<BlockNumber> {
+    /// Maximum Amount Unshielded per Period
+    pub amount: AssetValue,
+
+    /// Period Length in Blocks
+    pub period: BlockNumber,
+}
+
+/// Unshield Window
+///
+/// Tracks the amount unshielded over the rolling window of an [`UnshieldLimit`]. The amount is
+/// released linearly over the period of the limit, so that at any block at most the limit amount
+/// is outstanding.
+#[derive(Clone, Copy, Debug, Decode, Default, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub struct UnshieldWindow<BlockNumber> {
+    /// Block at which the Window was Last Updated
+    pub updated: BlockNumber,
+
+    /// Amount Unshielded and not yet Released at the Last Update
+    pub unshielded: AssetValue,
+}
+
+impl<BlockNumber> UnshieldWindow<BlockNumber> {
+    /// Builds a new empty [`UnshieldWindow`] updated at `updated`.
+    #[inline]
+    pub fn new(updated: BlockNumber) -> Self {
+        Self {
+            updated,
+            unshielded: 0,
+        }
+    }
+}
+
+impl<BlockNumber> UnshieldWindow<BlockNumber>
+where
+    BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+    /// Releases the amount which has left the rolling window of `limit` since the last update,
+    /// moving the window to `now`.
+    #[inline]
+    pub fn advance(&mut self, now: BlockNumber, limit: &UnshieldLimit<BlockNumber>) {
+        let elapsed = now.saturating_sub(self.updated);
+        let released = if elapsed >= limit.period {
+            self.unshielded
+        } else {
+            multiply_by_rational(
+                limit.amount,
+                elapsed.unique_saturated_into(),
+                limit.period.unique_saturated_into(),
+            )
+            .unwrap_or(self.unshielded)
+        };
+        self.unshielded = self.unshielded.saturating_sub(released);
+        self.updated = now;
+    }
+}
+
 /// Leaf Digest Type
 pub type LeafDigest = [u8; 32];
 
@@ -794,6 +857,11 @@ pub type LeafDigest = [u8; 32];
 pub type InnerDigest = [u8; 32];
 
 /// Merkle Tree Current Path
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
 #[derive(Clone, Debug, Decode, Default, Encode, Eq, PartialEq, TypeInfo)]
 pub struct CurrentPath {
     /// Sibling Digest
@@ -856,6 +924,11 @@ impl TryFrom<CurrentPath> for merkle_tree::CurrentPath<MerkleTreeConfiguration>
 }
 
 /// UTXO Merkle Tree Path
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
 #[derive(Clone, Debug, Decode, Default, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
 pub struct UtxoMerkleTreePath {
     /// Current Leaf Digest
@@ -865,6 +938,61 @@ pub struct UtxoMerkleTreePath {
     pub current_path: CurrentPath,
 }
 
+/// Shard Snapshot
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Debug, Decode, Default, Encode, Eq, PartialEq, TypeInfo)]
+pub struct ShardSnapshot {
+    /// Shard Merkle Tree Path
+    pub tree: UtxoMerkleTreePath,
+
+    /// Current UTXO Accumulator Output
+    ///
+    /// This is the root of the shard tree, or `None` if nothing was ever inserted in the shard.
+    pub utxo_accumulator_output: Option<UtxoAccumulatorOutput>,
+}
+
+/// Raw Merkle Forest Snapshot for Encoding and Decoding
+#[derive(Clone, Debug, Decode, Default, Encode, Eq, PartialEq, TypeInfo)]
+pub struct RawForestSnapshot {
+    /// Shard Snapshots Ordered by Shard Index
+    pub shards: Vec<ShardSnapshot>,
+
+    /// Checkpoint Matching the Snapshot
+    pub checkpoint: RawCheckpoint,
+}
+
+/// Merkle Forest Snapshot
+///
+/// Wallets can initialize their UTXO accumulator forest from a [`ForestSnapshot`] and continue
+/// synchronizing from its `checkpoint` without downloading the historical UTXOs.
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ForestSnapshot {
+    /// Shard Snapshots Ordered by Shard Index
+    pub shards: Vec<ShardSnapshot>,
+
+    /// Checkpoint Matching the Snapshot
+    pub checkpoint: Checkpoint,
+}
+
+impl From<RawForestSnapshot> for ForestSnapshot {
+    #[inline]
+    fn from(snapshot: RawForestSnapshot) -> Self {
+        Self {
+            shards: snapshot.shards,
+            checkpoint: snapshot.checkpoint.into(),
+        }
+    }
+}
+
 /// Receiver Chunk Data Type
 pub type ReceiverChunk = Vec<(Utxo, FullIncomingNote)>;
 
@@ -895,6 +1023,11 @@ pub struct PullResponse {
     pub senders_receivers_total: [u8; 16],
 }
 
+/// Maximum Number of Entries in a Receiver or Sender Chunk of a [`DensePullResponse`]
+///
+/// This matches the largest chunk the ledger returns for a single pull.
+pub const DENSE_PULL_MAX_CHUNK_LENGTH: usize = 32768;
+
 /// Ledger Source Dense Pull Response
 #[cfg_attr(
     feature = "serde",
@@ -928,6 +1061,96 @@ pub struct DensePullResponse {
     /// and the potential risk of inconsistent computing rules between the client and server
     #[codec(skip)]
     pub next_checkpoint: Option<Checkpoint>,
+
+    /// Encoding of the Receiver and Sender Chunks
+    ///
+    /// This field is omitted from the JSON response when the chunks are plain base64 encoded, so
+    /// that the response shape is unchanged for existing clients.
+    #[codec(skip)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "DensePullEncoding::is_base64")
+    )]
+    pub encoding: DensePullEncoding,
+
+    /// Uncompressed Size of the SCALE Encoded Receiver Chunk
+    #[codec(skip)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub receivers_size: Option<u64>,
+
+    /// Uncompressed Size of the SCALE Encoded Sender Chunk
+    #[codec(skip)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub senders_size: Option<u64>,
+}
+
+impl DensePullResponse {
+    /// Builds a new [`DensePullResponse`] from `resp`, encoding its receiver and sender chunks
+    /// with `encoding`.
+    #[inline]
+    pub fn new(resp: PullResponse, encoding: DensePullEncoding) -> Result<Self, DensePullError> {
+        let receivers = resp.receivers.encode();
+        let senders = resp.senders.encode();
+        let (receivers_size, senders_size) = match encoding {
+            DensePullEncoding::Base64 => (None, None),
+            _ => (Some(receivers.len() as u64), Some(senders.len() as u64)),
+        };
+        Ok(Self {
+            should_continue: resp.should_continue,
+            receivers: encoding.encode(&receivers)?,
+            senders: encoding.encode(&senders)?,
+            senders_receivers_total: resp.senders_receivers_total,
+            next_checkpoint: None,
+            encoding,
+            receivers_size,
+            senders_size,
+        })
+    }
+
+    /// Decodes the receiver chunk of `self` into its SCALE representation.
+    #[inline]
+    pub fn decode_receivers(&self) -> Result<ReceiverChunk, DensePullError> {
+        self.decode_chunk(&self.receivers, self.receivers_size)
+    }
+
+    /// Decodes the sender chunk of `self` into its SCALE representation.
+    #[inline]
+    pub fn decode_senders(&self) -> Result<SenderChunk, DensePullError> {
+        self.decode_chunk(&self.senders, self.senders_size)
+    }
+
+    /// Decodes `chunk` according to the encoding of `self`, checking it against the reported
+    /// uncompressed `size`.
+    ///
+    /// The reported `size` is only trusted up to the largest chunk of `T` entries that the ledger
+    /// can return, see [`DENSE_PULL_MAX_CHUNK_LENGTH`].
+    #[inline]
+    fn decode_chunk<T>(&self, chunk: &str, size: Option<u64>) -> Result<Vec<T>, DensePullError>
+    where
+        T: Decode + MaxEncodedLen,
+    {
+        let max_size = Compact::<u32>::max_encoded_len()
+            .saturating_add(DENSE_PULL_MAX_CHUNK_LENGTH.saturating_mul(T::max_encoded_len()));
+        let bytes = self.encoding.decode(chunk, size, max_size)?;
+        Vec::<T>::decode(&mut bytes.as_slice()).map_err(DensePullError::Codec)
+    }
+
+    /// Converts `self` into a [`PullResponse`].
+    #[inline]
+    pub fn into_pull_response(self) -> Result<PullResponse, DensePullError> {
+        Ok(PullResponse {
+            should_continue: self.should_continue,
+            receivers: self.decode_receivers()?,
+            senders: self.decode_senders()?,
+            senders_receivers_total: self.senders_receivers_total,
+        })
+    }
 }
 
 impl From<PullResponse> for DensePullResponse {
@@ -939,12 +1162,134 @@ impl From<PullResponse> for DensePullResponse {
             senders: base64::encode(resp.senders.encode()),
             senders_receivers_total: resp.senders_receivers_total,
             next_checkpoint: None,
+            encoding: DensePullEncoding::Base64,
+            receivers_size: None,
+            senders_size: None,
+        }
+    }
+}
+
+/// Dense Pull Response Encoding
+///
+/// Encoding applied to the SCALE encoded receiver and sender chunks of a [`DensePullResponse`].
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, Decode, Encode, Eq, Hash, MaxEncodedLen, PartialEq, TypeInfo)]
+pub enum DensePullEncoding {
+    /// Base64 over the SCALE bytes
+    Base64,
+
+    /// Base64 over the zstd compressed SCALE bytes
+    Zstd,
+}
+
+impl DensePullEncoding {
+    /// Default zstd Compression Level
+    pub const ZSTD_LEVEL: i32 = 3;
+
+    /// Returns `true` if `self` is [`DensePullEncoding::Base64`].
+    #[inline]
+    pub fn is_base64(&self) -> bool {
+        matches!(self, Self::Base64)
+    }
+
+    /// Encodes the SCALE `bytes` into their string representation.
+    #[inline]
+    pub fn encode(&self, bytes: &[u8]) -> Result<alloc::string::String, DensePullError> {
+        match self {
+            Self::Base64 => Ok(base64::encode(bytes)),
+            #[cfg(feature = "zstd")]
+            Self::Zstd => zstd::bulk::compress(bytes, Self::ZSTD_LEVEL)
+                .map(base64::encode)
+                .map_err(|_| DensePullError::Compression),
+            #[cfg(not(feature = "zstd"))]
+            Self::Zstd => Err(DensePullError::UnsupportedEncoding(*self)),
         }
     }
+
+    /// Decodes `chunk` back into its SCALE bytes, using `size` as the expected uncompressed length
+    /// for compressed encodings.
+    ///
+    /// Compressed chunks reporting a `size` larger than `max_size` are rejected before anything
+    /// is decompressed.
+    #[inline]
+    pub fn decode(
+        &self,
+        chunk: &str,
+        size: Option<u64>,
+        max_size: usize,
+    ) -> Result<Vec<u8>, DensePullError> {
+        let bytes = base64::decode(chunk).map_err(DensePullError::Base64)?;
+        match self {
+            Self::Base64 => Ok(bytes),
+            #[cfg(feature = "zstd")]
+            Self::Zstd => {
+                let size = size.ok_or(DensePullError::MissingSize)?;
+                if size > max_size as u64 {
+                    return Err(DensePullError::SizeLimitExceeded);
+                }
+                let size = size as usize;
+                let decompressed = zstd::bulk::decompress(&bytes, size)
+                    .map_err(|_| DensePullError::Compression)?;
+                if decompressed.len() != size {
+                    return Err(DensePullError::SizeMismatch);
+                }
+                Ok(decompressed)
+            }
+            #[cfg(not(feature = "zstd"))]
+            Self::Zstd => {
+                let _ = (size, max_size);
+                Err(DensePullError::UnsupportedEncoding(*self))
+            }
+        }
+    }
+}
+
+impl Default for DensePullEncoding {
+    #[inline]
+    fn default() -> Self {
+        Self::Base64
+    }
+}
+
+/// Dense Pull Response Error
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DensePullError {
+    /// Base64 Decoding Error
+    Base64(base64::DecodeError),
+
+    /// SCALE Decoding Error
+    Codec(scale_codec::Error),
+
+    /// Compression or Decompression Error
+    Compression,
+
+    /// Missing Uncompressed Size Error
+    ///
+    /// A compressed chunk was received without its uncompressed size.
+    MissingSize,
+
+    /// Size Mismatch Error
+    ///
+    /// The decompressed chunk does not have the reported uncompressed size.
+    SizeMismatch,
+
+    /// Size Limit Exceeded Error
+    ///
+    /// The reported uncompressed size is larger than any chunk the ledger can return.
+    SizeLimitExceeded,
+
+    /// Unsupported Encoding Error
+    ///
+    /// The encoding was not enabled when compiling this crate.
+    UnsupportedEncoding(DensePullEncoding),
 }
 
 /// Raw Checkpoint for Encoding and Decoding
-#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Decode, Encode)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Decode, Encode, TypeInfo)]
 pub struct RawCheckpoint {
     /// Receiver Index
     pub receiver_index: [u64; MerkleTreeConfiguration::FOREST_WIDTH],
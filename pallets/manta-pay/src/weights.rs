@@ -44,6 +44,9 @@ pub trait WeightInfo {
 
     /// Returns the [`Weight`] of the [`crate::Pallet::public_transfer`] extrinsic.
     fn public_transfer() -> Weight;
+
+    /// Returns the [`Weight`] of the [`crate::Pallet::set_unshield_limit`] extrinsic.
+    fn set_unshield_limit() -> Weight;
 }
 
 /// Concrete Weight Functions
@@ -73,13 +76,15 @@ where
     /// Storage: MantaPay VoidNumberSetSize (r:1 w:1)
     /// Storage: MantaPay ShardTrees (r:1 w:1)
     /// Storage: MantaPay Balances (r:1 w:1)
+    /// Storage: MantaPay UnshieldLimits (r:1 w:0)
+    /// Storage: MantaPay UnshieldWindows (r:1 w:1)
     /// Storage: MantaPay VoidNumberSetInsertionOrder (r:0 w:2)
     /// Storage: MantaPay Shards (r:0 w:1)
     /// ```
     fn to_public() -> Weight {
         (44_100_000_000 as Weight)
-            .saturating_add(T::DbWeight::get().reads(10 as Weight))
-            .saturating_add(T::DbWeight::get().writes(12 as Weight))
+            .saturating_add(T::DbWeight::get().reads(12 as Weight))
+            .saturating_add(T::DbWeight::get().writes(13 as Weight))
     }
 
     /// ```text
@@ -102,4 +107,16 @@ where
     fn public_transfer() -> Weight {
         (46_629_000 as Weight)
     }
+
+    /// ```text
+    /// Storage: AssetManager AssetIdLocation (r:1 w:0)
+    /// Storage: AssetManager AssetIdNonFungible (r:1 w:0)
+    /// Storage: MantaPay UnshieldLimits (r:0 w:1)
+    /// Storage: MantaPay UnshieldWindows (r:0 w:1)
+    /// ```
+    fn set_unshield_limit() -> Weight {
+        (17_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
 }
@@ -0,0 +1,77 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Price Feed pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::{Call, Config, Event, Pallet};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::Get;
+use frame_system::{EventRecord, RawOrigin};
+use sp_runtime::FixedU128;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
+    let events = frame_system::Pallet::<T>::events();
+    let system_event: <T as frame_system::Config>::Event = generic_event.into();
+    let EventRecord { event, .. } = &events[events.len() - 1];
+    assert_eq!(event, &system_event);
+}
+
+benchmarks! {
+    where_clause { where T::AssetId: From<u32> }
+
+    add_feeder {
+        let feeder: T::AccountId = account("feeder", 0, 0);
+    }: _(RawOrigin::Root, feeder.clone())
+    verify {
+        assert_last_event::<T>(Event::FeederAdded { feeder }.into());
+    }
+
+    remove_feeder {
+        let p in 0 .. 100;
+        let feeder: T::AccountId = whitelisted_caller();
+        Pallet::<T>::add_feeder(RawOrigin::Root.into(), feeder.clone())?;
+        for asset_id in 0..p {
+            Pallet::<T>::submit_price(
+                RawOrigin::Signed(feeder.clone()).into(),
+                T::AssetId::from(asset_id),
+                FixedU128::from_inner(1_000),
+            )?;
+        }
+    }: _(RawOrigin::Root, feeder.clone(), p)
+    verify {
+        assert_last_event::<T>(Event::FeederRemoved { feeder }.into());
+    }
+
+    submit_price {
+        let f in 1 .. T::MaxFeeders::get();
+        let feeder: T::AccountId = whitelisted_caller();
+        Pallet::<T>::add_feeder(RawOrigin::Root.into(), feeder.clone())?;
+        let asset_id = T::AssetId::from(8);
+        let price = FixedU128::from_inner(1_000);
+        for index in 1..f {
+            let other: T::AccountId = account("feeder", index, 0);
+            Pallet::<T>::add_feeder(RawOrigin::Root.into(), other.clone())?;
+            Pallet::<T>::submit_price(RawOrigin::Signed(other).into(), asset_id, price)?;
+        }
+    }: _(RawOrigin::Signed(feeder.clone()), asset_id, price)
+    verify {
+        assert_last_event::<T>(Event::PriceSubmitted { feeder, asset_id, price }.into());
+    }
+}
+
+impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
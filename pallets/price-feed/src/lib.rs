@@ -0,0 +1,382 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Price Feed Module
+//!
+//! Oracle pricing of XCM fees paid in non-native assets.
+//!
+//! ## Overview
+//!
+//! A set of feeders managed by `FeederOrigin` submits the price of assets in units of the native
+//! asset. The price of an asset is the median of all submissions which are younger than
+//! `MaxPriceAge`, as long as there are at least `MinimumFeeds` of them. For an even number of
+//! submissions the median is the mean of the two middle prices. The median is computed when a
+//! price is submitted and cached until its oldest price becomes stale, so reading the price only
+//! touches a single storage value. The pallet implements
+//! [`UnitsPerSecond`] by converting `NativeUnitsPerSecond` with this price, never charging less
+//! than the manually set `units_per_second` of `ManualUnitsPerSecond`. Assets without a manual
+//! `units_per_second` are not priced at all, so the oracle can never enable fee payment in an
+//! asset on its own.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub use crate::weights::WeightInfo;
+pub use pallet::*;
+
+/// Price Feed Pallet
+#[frame_support::pallet]
+pub mod pallet {
+    use crate::weights::WeightInfo;
+    use frame_support::{pallet_prelude::*, transactional};
+    use frame_system::pallet_prelude::*;
+    use manta_primitives::assets::{AssetIdType, UnitsPerSecond};
+    use sp_runtime::{
+        traits::{Saturating, Zero},
+        FixedPointNumber, FixedU128,
+    };
+    use sp_std::vec::Vec;
+
+    /// Price Entry
+    #[derive(Clone, Copy, Debug, Decode, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+    pub struct PriceEntry<BlockNumber> {
+        /// Price of one unit of the asset in units of the native asset
+        pub price: FixedU128,
+
+        /// Block at which the price was submitted
+        pub submitted_at: BlockNumber,
+    }
+
+    /// Median Price
+    #[derive(Clone, Copy, Debug, Decode, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+    pub struct MedianPrice<BlockNumber> {
+        /// Median of the fresh prices of the asset
+        pub price: FixedU128,
+
+        /// Block at which the oldest price counted in the median becomes stale
+        pub expires_at: BlockNumber,
+    }
+
+    /// Pallet Configuration
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+        /// Asset Id Type
+        type AssetId: Parameter + Copy + MaxEncodedLen;
+
+        /// Manually Set Units per Second
+        ///
+        /// The oracle price never charges less than these values, and assets without a manual
+        /// value are never priced.
+        type ManualUnitsPerSecond: UnitsPerSecond<AssetId = Self::AssetId>;
+
+        /// Units of the native asset charged per second of XCM execution.
+        #[pallet::constant]
+        type NativeUnitsPerSecond: Get<u128>;
+
+        /// The origin which may add or remove feeders.
+        type FeederOrigin: EnsureOrigin<Self::Origin>;
+
+        /// Maximum number of feeders.
+        #[pallet::constant]
+        type MaxFeeders: Get<u32>;
+
+        /// Minimum number of fresh prices needed before the median is used.
+        #[pallet::constant]
+        type MinimumFeeds: Get<u32>;
+
+        /// Number of blocks after which a submitted price is stale.
+        #[pallet::constant]
+        type MaxPriceAge: Get<Self::BlockNumber>;
+
+        /// Weight information for the extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    /// Price Feed Pallet
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Feeders
+    ///
+    /// Accounts which are allowed to submit prices.
+    #[pallet::storage]
+    #[pallet::getter(fn feeders)]
+    pub type Feeders<T: Config> =
+        StorageValue<_, BoundedVec<T::AccountId, T::MaxFeeders>, ValueQuery>;
+
+    /// Submitted Prices
+    ///
+    /// The latest price submitted by each feeder for each asset.
+    #[pallet::storage]
+    #[pallet::getter(fn submitted_price)]
+    pub type Prices<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AssetId,
+        PriceEntry<T::BlockNumber>,
+    >;
+
+    /// Price Counts
+    ///
+    /// The number of assets each feeder has submitted a price for.
+    #[pallet::storage]
+    #[pallet::getter(fn price_count)]
+    pub type PriceCounts<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Median Prices
+    ///
+    /// The median of the fresh prices of each asset, as of the latest price submitted for it.
+    #[pallet::storage]
+    #[pallet::getter(fn median_price)]
+    pub type MedianPrices<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, MedianPrice<T::BlockNumber>>;
+
+    /// Price Feed Event
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A feeder was added
+        FeederAdded {
+            /// Added Feeder
+            feeder: T::AccountId,
+        },
+
+        /// A feeder was removed together with its prices
+        FeederRemoved {
+            /// Removed Feeder
+            feeder: T::AccountId,
+        },
+
+        /// A feeder submitted a price
+        PriceSubmitted {
+            /// Feeder who submitted the Price
+            feeder: T::AccountId,
+
+            /// Asset Id of the priced Asset
+            asset_id: T::AssetId,
+
+            /// Price of one unit of the Asset in units of the native Asset
+            price: FixedU128,
+        },
+    }
+
+    /// Price Feed Error
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The account is already a feeder.
+        AlreadyFeeder,
+
+        /// The account is not a feeder.
+        NotFeeder,
+
+        /// The maximum number of feeders was reached.
+        TooManyFeeders,
+
+        /// Prices must be greater than zero.
+        ZeroPrice,
+
+        /// The price count witness is lower than the number of prices of the feeder.
+        InvalidPriceCount,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Add `feeder` to the set of accounts which can submit prices.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `FeederOrigin`.
+        /// * `feeder`: Account to be added.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::add_feeder())]
+        #[transactional]
+        pub fn add_feeder(origin: OriginFor<T>, feeder: T::AccountId) -> DispatchResult {
+            T::FeederOrigin::ensure_origin(origin)?;
+            Feeders::<T>::try_mutate(|feeders| {
+                ensure!(!feeders.contains(&feeder), Error::<T>::AlreadyFeeder);
+                feeders
+                    .try_push(feeder.clone())
+                    .map_err(|_| Error::<T>::TooManyFeeders)
+            })?;
+            Self::deposit_event(Event::FeederAdded { feeder });
+            Ok(())
+        }
+
+        /// Remove `feeder` from the set of accounts which can submit prices, clearing all of its
+        /// submitted prices and the cached medians of the assets it priced.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `FeederOrigin`.
+        /// * `feeder`: Account to be removed.
+        /// * `price_count`: Upper bound on the number of prices submitted by `feeder`, see
+        ///   [`price_count`](Self::price_count).
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::remove_feeder(*price_count))]
+        #[transactional]
+        pub fn remove_feeder(
+            origin: OriginFor<T>,
+            feeder: T::AccountId,
+            price_count: u32,
+        ) -> DispatchResultWithPostInfo {
+            T::FeederOrigin::ensure_origin(origin)?;
+            Feeders::<T>::try_mutate(|feeders| {
+                let index = feeders
+                    .iter()
+                    .position(|f| f == &feeder)
+                    .ok_or(Error::<T>::NotFeeder)?;
+                feeders.remove(index);
+                Ok::<_, Error<T>>(())
+            })?;
+            let count = PriceCounts::<T>::take(&feeder);
+            ensure!(count <= price_count, Error::<T>::InvalidPriceCount);
+            Prices::<T>::drain_prefix(&feeder)
+                .for_each(|(asset_id, _)| MedianPrices::<T>::remove(asset_id));
+            Self::deposit_event(Event::FeederRemoved { feeder });
+            Ok(Some(T::WeightInfo::remove_feeder(count)).into())
+        }
+
+        /// Submit the `price` of one unit of `asset_id` in units of the native asset, updating the
+        /// cached median of `asset_id`.
+        ///
+        /// * `origin`: Feeder submitting the price.
+        /// * `asset_id`: AssetId of the priced asset.
+        /// * `price`: Price of one unit of the asset in units of the native asset.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::submit_price(T::MaxFeeders::get()))]
+        #[transactional]
+        pub fn submit_price(
+            origin: OriginFor<T>,
+            asset_id: T::AssetId,
+            price: FixedU128,
+        ) -> DispatchResultWithPostInfo {
+            let feeder = ensure_signed(origin)?;
+            let feeders = Feeders::<T>::get();
+            ensure!(feeders.contains(&feeder), Error::<T>::NotFeeder);
+            ensure!(!price.is_zero(), Error::<T>::ZeroPrice);
+            let entry = PriceEntry {
+                price,
+                submitted_at: frame_system::Pallet::<T>::block_number(),
+            };
+            if Prices::<T>::mutate(&feeder, asset_id, |e| e.replace(entry).is_none()) {
+                PriceCounts::<T>::mutate(&feeder, |count| *count = count.saturating_add(1));
+            }
+            Self::update_median(&feeders, &asset_id);
+            Self::deposit_event(Event::PriceSubmitted {
+                feeder,
+                asset_id,
+                price,
+            });
+            Ok(Some(T::WeightInfo::submit_price(feeders.len() as u32)).into())
+        }
+    }
+
+    impl<T> Pallet<T>
+    where
+        T: Config,
+    {
+        /// Returns the median of all fresh prices of `asset_id`, or `None` if there are less than
+        /// `MinimumFeeds` of them. For an even number of prices this is the mean of the two middle
+        /// prices.
+        ///
+        /// The median is the one cached by the latest submission for `asset_id`, which is dropped
+        /// as soon as any of the prices counted in it is stale until the next submission.
+        #[inline]
+        pub fn price(asset_id: &T::AssetId) -> Option<FixedU128> {
+            let median = MedianPrices::<T>::get(asset_id)?;
+            if frame_system::Pallet::<T>::block_number() < median.expires_at {
+                Some(median.price)
+            } else {
+                None
+            }
+        }
+
+        /// Computes the median of the fresh prices submitted by `feeders` for `asset_id` and
+        /// caches it in [`MedianPrices`], removing the cached median if there are less than
+        /// `MinimumFeeds` fresh prices.
+        #[inline]
+        fn update_median(feeders: &[T::AccountId], asset_id: &T::AssetId) {
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut entries = feeders
+                .iter()
+                .filter_map(|feeder| Prices::<T>::get(feeder, asset_id))
+                .filter(|entry| now.saturating_sub(entry.submitted_at) < T::MaxPriceAge::get())
+                .collect::<Vec<_>>();
+            if entries.is_empty() || (entries.len() as u32) < T::MinimumFeeds::get() {
+                MedianPrices::<T>::remove(asset_id);
+                return;
+            }
+            let expires_at = entries
+                .iter()
+                .map(|entry| entry.submitted_at)
+                .min()
+                .unwrap_or(now)
+                .saturating_add(T::MaxPriceAge::get());
+            entries.sort_unstable_by_key(|entry| entry.price);
+            let upper = entries[entries.len() / 2].price;
+            let price = if entries.len() % 2 == 1 {
+                upper
+            } else {
+                let lower = entries[entries.len() / 2 - 1].price;
+                FixedU128::from_inner(
+                    lower.into_inner() + (upper.into_inner() - lower.into_inner()) / 2,
+                )
+            };
+            MedianPrices::<T>::insert(asset_id, MedianPrice { price, expires_at });
+        }
+
+        /// Returns the `units_per_second` of `asset_id` derived from its oracle [`price`].
+        ///
+        /// [`price`]: Self::price
+        #[inline]
+        pub fn oracle_units_per_second(asset_id: &T::AssetId) -> Option<u128> {
+            Some(
+                Self::price(asset_id)?
+                    .reciprocal()?
+                    .saturating_mul_int(T::NativeUnitsPerSecond::get()),
+            )
+        }
+    }
+
+    impl<T> AssetIdType for Pallet<T>
+    where
+        T: Config,
+    {
+        type AssetId = T::AssetId;
+    }
+
+    impl<T> UnitsPerSecond for Pallet<T>
+    where
+        T: Config,
+    {
+        #[inline]
+        fn units_per_second(asset_id: &Self::AssetId) -> Option<u128> {
+            let floor = T::ManualUnitsPerSecond::units_per_second(asset_id)?;
+            Some(Self::oracle_units_per_second(asset_id).map_or(floor, |oracle| floor.max(oracle)))
+        }
+    }
+}
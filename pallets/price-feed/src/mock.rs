@@ -0,0 +1,126 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Mock runtime for price-feed
+
+use crate as pallet_price_feed;
+use frame_support::{
+    construct_runtime,
+    traits::{ConstU128, ConstU32, ConstU64},
+};
+use frame_system::EnsureRoot;
+use manta_primitives::assets::{AssetIdType, UnitsPerSecond};
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::{BlakeTwo256, IdentityLookup},
+};
+
+pub type AccountId = u64;
+pub type AssetId = u128;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+
+/// Asset with a manually set `units_per_second` of [`MANUAL_UNITS_PER_SECOND`].
+pub const MANUAL_ASSET_ID: AssetId = 8;
+
+/// Manually set `units_per_second` of [`MANUAL_ASSET_ID`].
+pub const MANUAL_UNITS_PER_SECOND: u128 = 500_000_000;
+
+/// Native units charged per second of XCM execution.
+pub const NATIVE_UNITS_PER_SECOND: u128 = 1_000_000_000;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+    pub enum Runtime where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+        PriceFeed: pallet_price_feed::{Pallet, Call, Storage, Event<T>},
+    }
+);
+
+impl frame_system::Config for Runtime {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type Origin = Origin;
+    type Call = Call;
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<AccountId>;
+    type Header = Header;
+    type Event = Event;
+    type BlockHashCount = ConstU64<250>;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+/// Manual Units per Second
+pub struct ManualUnitsPerSecond;
+
+impl AssetIdType for ManualUnitsPerSecond {
+    type AssetId = AssetId;
+}
+
+impl UnitsPerSecond for ManualUnitsPerSecond {
+    #[inline]
+    fn units_per_second(asset_id: &AssetId) -> Option<u128> {
+        if *asset_id == MANUAL_ASSET_ID {
+            Some(MANUAL_UNITS_PER_SECOND)
+        } else {
+            None
+        }
+    }
+}
+
+impl pallet_price_feed::Config for Runtime {
+    type Event = Event;
+    type AssetId = AssetId;
+    type ManualUnitsPerSecond = ManualUnitsPerSecond;
+    type NativeUnitsPerSecond = ConstU128<NATIVE_UNITS_PER_SECOND>;
+    type FeederOrigin = EnsureRoot<AccountId>;
+    type MaxFeeders = ConstU32<3>;
+    type MinimumFeeds = ConstU32<2>;
+    type MaxPriceAge = ConstU64<10>;
+    type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::default()
+        .build_storage::<Runtime>()
+        .unwrap();
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,234 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Unit tests for price-feed
+
+use crate::{mock::*, Error, Event as PriceFeedEvent, MedianPrice};
+use frame_support::{assert_noop, assert_ok};
+use manta_primitives::assets::UnitsPerSecond;
+use sp_runtime::{traits::BadOrigin, FixedPointNumber, FixedU128};
+
+fn price(n: u128) -> FixedU128 {
+    FixedU128::saturating_from_integer(n)
+}
+
+#[test]
+fn add_and_remove_feeders_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            PriceFeed::add_feeder(Origin::signed(ALICE), ALICE),
+            BadOrigin
+        );
+        assert_ok!(PriceFeed::add_feeder(Origin::root(), ALICE));
+        System::assert_last_event(PriceFeedEvent::FeederAdded { feeder: ALICE }.into());
+        assert_noop!(
+            PriceFeed::add_feeder(Origin::root(), ALICE),
+            Error::<Runtime>::AlreadyFeeder
+        );
+        assert_ok!(PriceFeed::add_feeder(Origin::root(), BOB));
+        assert_ok!(PriceFeed::add_feeder(Origin::root(), CHARLIE));
+        assert_noop!(
+            PriceFeed::add_feeder(Origin::root(), 4),
+            Error::<Runtime>::TooManyFeeders
+        );
+        assert_ok!(PriceFeed::submit_price(
+            Origin::signed(ALICE),
+            MANUAL_ASSET_ID,
+            price(2)
+        ));
+        assert_ok!(PriceFeed::submit_price(
+            Origin::signed(ALICE),
+            MANUAL_ASSET_ID,
+            price(3)
+        ));
+        assert_ok!(PriceFeed::submit_price(Origin::signed(ALICE), 9, price(1)));
+        assert_eq!(PriceFeed::price_count(ALICE), 2);
+        assert_noop!(
+            PriceFeed::remove_feeder(Origin::root(), ALICE, 1),
+            Error::<Runtime>::InvalidPriceCount
+        );
+        assert_ok!(PriceFeed::remove_feeder(Origin::root(), ALICE, 2));
+        System::assert_last_event(PriceFeedEvent::FeederRemoved { feeder: ALICE }.into());
+        assert_eq!(PriceFeed::feeders().into_inner(), vec![BOB, CHARLIE]);
+        assert_eq!(PriceFeed::submitted_price(ALICE, MANUAL_ASSET_ID), None);
+        assert_eq!(PriceFeed::submitted_price(ALICE, 9), None);
+        assert_eq!(PriceFeed::price_count(ALICE), 0);
+        assert_noop!(
+            PriceFeed::remove_feeder(Origin::root(), ALICE, 0),
+            Error::<Runtime>::NotFeeder
+        );
+    });
+}
+
+#[test]
+fn submit_price_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            PriceFeed::submit_price(Origin::signed(ALICE), MANUAL_ASSET_ID, price(2)),
+            Error::<Runtime>::NotFeeder
+        );
+        assert_ok!(PriceFeed::add_feeder(Origin::root(), ALICE));
+        assert_noop!(
+            PriceFeed::submit_price(Origin::signed(ALICE), MANUAL_ASSET_ID, price(0)),
+            Error::<Runtime>::ZeroPrice
+        );
+        assert_ok!(PriceFeed::submit_price(
+            Origin::signed(ALICE),
+            MANUAL_ASSET_ID,
+            price(2)
+        ));
+        System::assert_last_event(
+            PriceFeedEvent::PriceSubmitted {
+                feeder: ALICE,
+                asset_id: MANUAL_ASSET_ID,
+                price: price(2),
+            }
+            .into(),
+        );
+        let entry = PriceFeed::submitted_price(ALICE, MANUAL_ASSET_ID).unwrap();
+        assert_eq!(entry.price, price(2));
+        assert_eq!(entry.submitted_at, 1);
+    });
+}
+
+#[test]
+fn median_price_should_work() {
+    let asset_id = 9;
+    new_test_ext().execute_with(|| {
+        for feeder in [ALICE, BOB, CHARLIE] {
+            assert_ok!(PriceFeed::add_feeder(Origin::root(), feeder));
+        }
+        assert_ok!(PriceFeed::submit_price(
+            Origin::signed(ALICE),
+            asset_id,
+            price(7)
+        ));
+        // Not enough feeds yet
+        assert_eq!(PriceFeed::price(&asset_id), None);
+        assert_ok!(PriceFeed::submit_price(
+            Origin::signed(BOB),
+            asset_id,
+            price(1)
+        ));
+        // Mean of the two middle prices
+        assert_eq!(PriceFeed::price(&asset_id), Some(price(4)));
+        assert_ok!(PriceFeed::submit_price(
+            Origin::signed(CHARLIE),
+            asset_id,
+            price(3)
+        ));
+        assert_eq!(PriceFeed::price(&asset_id), Some(price(3)));
+        assert_eq!(
+            PriceFeed::median_price(asset_id),
+            Some(MedianPrice {
+                price: price(3),
+                expires_at: 11
+            })
+        );
+        // Removing a feeder drops the cached median until the next submission
+        assert_ok!(PriceFeed::remove_feeder(Origin::root(), CHARLIE, 1));
+        assert_eq!(PriceFeed::price(&asset_id), None);
+        assert_ok!(PriceFeed::submit_price(
+            Origin::signed(BOB),
+            asset_id,
+            price(3)
+        ));
+        assert_eq!(PriceFeed::price(&asset_id), Some(price(5)));
+    });
+}
+
+#[test]
+fn stale_prices_should_be_ignored() {
+    let asset_id = 9;
+    new_test_ext().execute_with(|| {
+        for feeder in [ALICE, BOB] {
+            assert_ok!(PriceFeed::add_feeder(Origin::root(), feeder));
+            assert_ok!(PriceFeed::submit_price(
+                Origin::signed(feeder),
+                asset_id,
+                price(2)
+            ));
+        }
+        System::set_block_number(10);
+        assert_eq!(PriceFeed::price(&asset_id), Some(price(2)));
+        System::set_block_number(11);
+        assert_eq!(PriceFeed::price(&asset_id), None);
+        assert_ok!(PriceFeed::submit_price(
+            Origin::signed(ALICE),
+            asset_id,
+            price(4)
+        ));
+        assert_eq!(PriceFeed::price(&asset_id), None);
+        assert_ok!(PriceFeed::submit_price(
+            Origin::signed(BOB),
+            asset_id,
+            price(4)
+        ));
+        assert_eq!(PriceFeed::price(&asset_id), Some(price(4)));
+    });
+}
+
+#[test]
+fn units_per_second_should_require_manual_floor() {
+    let asset_id = 9;
+    new_test_ext().execute_with(|| {
+        assert_eq!(PriceFeed::units_per_second(&asset_id), None);
+        assert_eq!(
+            PriceFeed::units_per_second(&MANUAL_ASSET_ID),
+            Some(MANUAL_UNITS_PER_SECOND)
+        );
+        for feeder in [ALICE, BOB] {
+            assert_ok!(PriceFeed::add_feeder(Origin::root(), feeder));
+            assert_ok!(PriceFeed::submit_price(
+                Origin::signed(feeder),
+                asset_id,
+                price(2)
+            ));
+            // Below the manual floor
+            assert_ok!(PriceFeed::submit_price(
+                Origin::signed(feeder),
+                MANUAL_ASSET_ID,
+                price(4)
+            ));
+        }
+        // Oracle prices alone do not enable fee payment
+        assert_eq!(
+            PriceFeed::oracle_units_per_second(&asset_id),
+            Some(NATIVE_UNITS_PER_SECOND / 2)
+        );
+        assert_eq!(PriceFeed::units_per_second(&asset_id), None);
+        assert_eq!(
+            PriceFeed::oracle_units_per_second(&MANUAL_ASSET_ID),
+            Some(NATIVE_UNITS_PER_SECOND / 4)
+        );
+        assert_eq!(
+            PriceFeed::units_per_second(&MANUAL_ASSET_ID),
+            Some(MANUAL_UNITS_PER_SECOND)
+        );
+        // Above the manual floor
+        for feeder in [ALICE, BOB] {
+            assert_ok!(PriceFeed::submit_price(
+                Origin::signed(feeder),
+                MANUAL_ASSET_ID,
+                FixedU128::saturating_from_rational(1, 4)
+            ));
+        }
+        assert_eq!(
+            PriceFeed::units_per_second(&MANUAL_ASSET_ID),
+            Some(4 * NATIVE_UNITS_PER_SECOND)
+        );
+    });
+}
@@ -0,0 +1,111 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # XCM Fees Module
+//!
+//! Governance configuration of the XCM fee revenue.
+//!
+//! ## Overview
+//!
+//! The XCM fees paid in the native asset and in the registered assets are split between the
+//! treasury, the block author, and burning, according to the [`FeeSplit`] set by `UpdateOrigin`.
+//! The split always keeps some of the revenue, so it can never burn all of the fees.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub use crate::weights::WeightInfo;
+pub use pallet::*;
+
+/// XCM Fees Pallet
+#[frame_support::pallet]
+pub mod pallet {
+    use crate::weights::WeightInfo;
+    use frame_support::{pallet_prelude::*, transactional};
+    use frame_system::pallet_prelude::*;
+    use manta_primitives::xcm::FeeSplit;
+
+    /// Pallet Configuration
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+        /// The origin which may update the fee split.
+        type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+        /// Weight information for the extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    /// XCM Fees Pallet
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Split of the XCM Fee Revenue
+    ///
+    /// Shares of the XCM fees, in the native and in the registered assets, which are deposited to
+    /// the treasury, deposited to the block author, and burned.
+    #[pallet::storage]
+    #[pallet::getter(fn xcm_fee_split)]
+    pub type XcmFeeSplit<T: Config> = StorageValue<_, FeeSplit, ValueQuery>;
+
+    /// XCM Fees Event
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Updated the split of the XCM fee revenue
+        XcmFeeSplitUpdated {
+            /// Updated Fee Split
+            split: FeeSplit,
+        },
+    }
+
+    /// XCM Fees Error
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The shares of the XCM fee split do not add up to one hundred percent, or burn all of
+        /// the revenue.
+        InvalidXcmFeeSplit,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set the split of the XCM fee revenue between the treasury, the block author, and burning.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `UpdateOrigin`.
+        /// * `split`: New fee split, which must be [valid](FeeSplit::is_valid).
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_xcm_fee_split())]
+        #[transactional]
+        pub fn set_xcm_fee_split(origin: OriginFor<T>, split: FeeSplit) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(split.is_valid(), Error::<T>::InvalidXcmFeeSplit);
+            XcmFeeSplit::<T>::put(split);
+            Self::deposit_event(Event::XcmFeeSplitUpdated { split });
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Unit tests for xcm-fees
+
+use crate::{mock::*, Error, Event as XcmFeesEvent};
+use frame_support::{assert_noop, assert_ok};
+use manta_primitives::xcm::FeeSplit;
+use sp_runtime::traits::BadOrigin;
+
+#[test]
+fn set_xcm_fee_split_should_work() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(XcmFees::xcm_fee_split(), FeeSplit::new(100, 0, 0));
+        let split = FeeSplit::new(50, 30, 20);
+        assert_noop!(
+            XcmFees::set_xcm_fee_split(Origin::signed(ALICE), split),
+            BadOrigin
+        );
+        assert_noop!(
+            XcmFees::set_xcm_fee_split(Origin::root(), FeeSplit::new(50, 30, 30)),
+            Error::<Runtime>::InvalidXcmFeeSplit
+        );
+        assert_noop!(
+            XcmFees::set_xcm_fee_split(Origin::root(), FeeSplit::new(50, 30, 10)),
+            Error::<Runtime>::InvalidXcmFeeSplit
+        );
+        assert_ok!(XcmFees::set_xcm_fee_split(Origin::root(), split));
+        assert_eq!(XcmFees::xcm_fee_split(), split);
+        System::assert_last_event(XcmFeesEvent::XcmFeeSplitUpdated { split }.into());
+    });
+}
+
+#[test]
+fn burning_all_of_the_xcm_fees_should_fail() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            XcmFees::set_xcm_fee_split(Origin::root(), FeeSplit::new(0, 0, 100)),
+            Error::<Runtime>::InvalidXcmFeeSplit
+        );
+        assert_ok!(XcmFees::set_xcm_fee_split(
+            Origin::root(),
+            FeeSplit::new(0, 1, 99)
+        ));
+    });
+}
@@ -0,0 +1,56 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! XCM Rate Limiter pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::{Call, Config, Event, Pallet, XcmRateLimit, XcmRateLimitOverride};
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_system::{EventRecord, RawOrigin};
+use xcm::latest::MultiLocation;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
+    let events = frame_system::Pallet::<T>::events();
+    let system_event: <T as frame_system::Config>::Event = generic_event.into();
+    let EventRecord { event, .. } = &events[events.len() - 1];
+    assert_eq!(event, &system_event);
+}
+
+benchmarks! {
+    set_xcm_rate_limit {
+        let limit = Some(XcmRateLimit {
+            window: 10,
+            max_messages: 100,
+            max_weight: 1_000_000_000,
+        });
+    }: _(RawOrigin::Root, limit)
+    verify {
+        assert_last_event::<T>(Event::XcmRateLimitUpdated { limit }.into());
+    }
+
+    set_xcm_rate_limit_override {
+        let location = T::Location::from(MultiLocation::parent());
+        let rate_limit_override = Some(XcmRateLimitOverride::Unlimited);
+    }: _(RawOrigin::Root, location.clone(), rate_limit_override)
+    verify {
+        assert_last_event::<T>(
+            Event::XcmRateLimitOverrideUpdated { location, rate_limit_override }.into()
+        );
+    }
+}
+
+impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
@@ -0,0 +1,349 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # XCM Rate Limiter Module
+//!
+//! Throttling of incoming XCM messages per origin.
+//!
+//! ## Overview
+//!
+//! Each origin gets a budget of messages, and of their total weight, for every window of blocks.
+//! The budget is the default rate limit set by `UpdateOrigin`, unless the origin has an override
+//! which either exempts it from rate limiting or gives it a budget of its own. The pallet
+//! implements [`XcmRateLimiter`] for the [`RateLimitedExecutor`] of the message queues:
+//!
+//! - Messages over what is left of the budget of their origin are deferred, so the message queues
+//!   keep them for a later window. The [`XcmMessageThrottled`](Event::XcmMessageThrottled) event is
+//!   deposited once per origin and window.
+//! - Messages heavier than the budget of a whole window are moved to the overweight queue of the
+//!   message queues. They can be executed from there once the rate limit of their origin allows
+//!   them.
+//!
+//! The usage of the windows which have ended is pruned when blocks have weight left.
+//!
+//! [`RateLimitedExecutor`]: manta_primitives::xcm::RateLimitedExecutor
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub use crate::weights::WeightInfo;
+pub use pallet::*;
+
+/// XCM Rate Limiter Pallet
+#[frame_support::pallet]
+pub mod pallet {
+    use crate::weights::WeightInfo;
+    use frame_support::{pallet_prelude::*, transactional};
+    use frame_system::pallet_prelude::*;
+    use manta_primitives::{
+        assets::NormalizeLocation,
+        xcm::{XcmRateLimitVerdict, XcmRateLimiter},
+    };
+    use sp_std::vec::Vec;
+    use xcm::latest::MultiLocation;
+
+    /// XCM Rate Limit
+    ///
+    /// Budget of incoming XCM messages, and of their total weight, for an origin in each window of
+    /// blocks.
+    #[derive(Clone, Copy, Debug, Decode, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+    pub struct XcmRateLimit {
+        /// Length of the window in blocks
+        pub window: u32,
+
+        /// Maximum number of messages per window
+        pub max_messages: u32,
+
+        /// Maximum total weight of the messages per window
+        pub max_weight: Weight,
+    }
+
+    impl XcmRateLimit {
+        /// Returns `true` if `self` has a non-empty window which allows some messages.
+        #[inline]
+        pub fn is_valid(&self) -> bool {
+            self.window > 0 && self.max_messages > 0
+        }
+    }
+
+    /// XCM Rate Limit Override
+    ///
+    /// Governance decision on the rate limit of a trusted origin, like the relay chain or a sibling.
+    #[derive(Clone, Copy, Debug, Decode, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+    pub enum XcmRateLimitOverride {
+        /// Messages from the origin are never throttled.
+        Unlimited,
+
+        /// Messages from the origin are throttled with their own rate limit.
+        Limited(XcmRateLimit),
+    }
+
+    /// XCM Usage
+    ///
+    /// Messages and weight an origin has used in its current rate limit window.
+    #[derive(Clone, Debug, Decode, Default, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+    pub struct XcmUsage<BlockNumber> {
+        /// First block after the window
+        pub window_end: BlockNumber,
+
+        /// Number of messages executed in the window
+        pub messages: u32,
+
+        /// Total weight of the messages executed in the window
+        pub weight: Weight,
+
+        /// Whether a message of the origin has been throttled in the window
+        pub throttled: bool,
+    }
+
+    /// Pallet Configuration
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+        /// Origin Location Type
+        type Location: Parameter + From<MultiLocation> + NormalizeLocation;
+
+        /// The origin which may update the rate limits.
+        type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+        /// Weight information for the extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    /// XCM Rate Limiter Pallet
+    #[pallet::pallet]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(_);
+
+    /// Default Rate Limit of Incoming XCM Messages
+    ///
+    /// Applies to every origin without an override in [`XcmRateLimitOverrides`]. Incoming messages
+    /// are not rate limited if this is not set.
+    #[pallet::storage]
+    #[pallet::getter(fn xcm_rate_limit)]
+    pub type XcmRateLimitDefault<T: Config> = StorageValue<_, XcmRateLimit>;
+
+    /// Rate Limit Overrides of Trusted XCM Origins
+    #[pallet::storage]
+    #[pallet::getter(fn xcm_rate_limit_override)]
+    pub type XcmRateLimitOverrides<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::Location, XcmRateLimitOverride>;
+
+    /// Usage of the Current Rate Limit Window of each XCM Origin
+    ///
+    /// The usage of ended windows is pruned in [`on_idle`](Hooks::on_idle).
+    #[pallet::storage]
+    #[pallet::getter(fn xcm_usage)]
+    pub type XcmUsages<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::Location, XcmUsage<T::BlockNumber>, ValueQuery>;
+
+    /// XCM Rate Limiter Event
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Updated the default rate limit of incoming XCM messages
+        XcmRateLimitUpdated {
+            /// Updated Rate Limit, if any
+            limit: Option<XcmRateLimit>,
+        },
+
+        /// Updated the rate limit override of an XCM origin
+        XcmRateLimitOverrideUpdated {
+            /// Origin Location
+            location: T::Location,
+
+            /// Updated Override, if any
+            rate_limit_override: Option<XcmRateLimitOverride>,
+        },
+
+        /// Incoming XCM messages are deferred because their origin is over its rate limit for the
+        /// rest of the window
+        XcmMessageThrottled {
+            /// Origin Location
+            origin: T::Location,
+
+            /// Weight of the first Throttled Message
+            weight: Weight,
+        },
+
+        /// An incoming XCM message was moved to the overweight queue because it exceeds the rate
+        /// limit of its origin for a whole window
+        XcmMessageOverweight {
+            /// Origin Location
+            origin: T::Location,
+
+            /// Weight of the Overweight Message
+            weight: Weight,
+        },
+    }
+
+    /// XCM Rate Limiter Error
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The window of the XCM rate limit is empty, or the rate limit allows no messages.
+        InvalidXcmRateLimit,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Prunes the usage of the windows which have ended, as far as `remaining_weight` allows.
+        fn on_idle(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+            let db_weight = T::DbWeight::get();
+            let mut used_weight: Weight = 0;
+            let mut ended = Vec::new();
+            for (location, usage) in XcmUsages::<T>::iter() {
+                if used_weight.saturating_add(db_weight.reads_writes(1, 1)) > remaining_weight {
+                    break;
+                }
+                used_weight = used_weight.saturating_add(db_weight.reads(1));
+                if usage.window_end <= now {
+                    used_weight = used_weight.saturating_add(db_weight.writes(1));
+                    ended.push(location);
+                }
+            }
+            for location in ended {
+                XcmUsages::<T>::remove(location);
+            }
+            used_weight
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set the default rate limit of incoming XCM messages.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `UpdateOrigin`.
+        /// * `limit`: New rate limit, or `None` to stop rate limiting origins without an override.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_xcm_rate_limit())]
+        #[transactional]
+        pub fn set_xcm_rate_limit(
+            origin: OriginFor<T>,
+            limit: Option<XcmRateLimit>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            match limit {
+                Some(limit) => {
+                    ensure!(limit.is_valid(), Error::<T>::InvalidXcmRateLimit);
+                    XcmRateLimitDefault::<T>::put(limit);
+                }
+                None => XcmRateLimitDefault::<T>::kill(),
+            }
+            Self::deposit_event(Event::XcmRateLimitUpdated { limit });
+            Ok(())
+        }
+
+        /// Set the rate limit override of a trusted XCM origin, like the relay chain or a sibling.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `UpdateOrigin`.
+        /// * `location`: Origin location of the incoming messages.
+        /// * `rate_limit_override`: New override, or `None` to fall back to the default rate limit.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::set_xcm_rate_limit_override())]
+        #[transactional]
+        pub fn set_xcm_rate_limit_override(
+            origin: OriginFor<T>,
+            location: T::Location,
+            rate_limit_override: Option<XcmRateLimitOverride>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            let location = location.normalize();
+            match rate_limit_override {
+                Some(XcmRateLimitOverride::Limited(limit)) if !limit.is_valid() => {
+                    return Err(Error::<T>::InvalidXcmRateLimit.into())
+                }
+                Some(rate_limit_override) => {
+                    XcmRateLimitOverrides::<T>::insert(&location, rate_limit_override)
+                }
+                None => XcmRateLimitOverrides::<T>::remove(&location),
+            }
+            Self::deposit_event(Event::XcmRateLimitOverrideUpdated {
+                location,
+                rate_limit_override,
+            });
+            Ok(())
+        }
+    }
+
+    impl<T> XcmRateLimiter for Pallet<T>
+    where
+        T: Config,
+    {
+        #[inline]
+        fn note_message(origin: &MultiLocation, weight: Weight) -> XcmRateLimitVerdict {
+            let location = T::Location::from(origin.clone()).normalize();
+            let limit = match XcmRateLimitOverrides::<T>::get(&location) {
+                Some(XcmRateLimitOverride::Unlimited) => return XcmRateLimitVerdict::Execute,
+                Some(XcmRateLimitOverride::Limited(limit)) => limit,
+                None => match XcmRateLimitDefault::<T>::get() {
+                    Some(limit) => limit,
+                    None => return XcmRateLimitVerdict::Execute,
+                },
+            };
+            if weight > limit.max_weight {
+                Self::deposit_event(Event::XcmMessageOverweight {
+                    origin: location,
+                    weight,
+                });
+                return XcmRateLimitVerdict::Overweight;
+            }
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut usage = XcmUsages::<T>::get(&location);
+            if usage.window_end <= now {
+                let window = T::BlockNumber::from(limit.window.max(1));
+                usage = XcmUsage {
+                    window_end: now - now % window + window,
+                    ..Default::default()
+                };
+            }
+            let messages = usage.messages.saturating_add(1);
+            let used_weight = usage.weight.saturating_add(weight);
+            if messages > limit.max_messages || used_weight > limit.max_weight {
+                if !usage.throttled {
+                    Self::deposit_event(Event::XcmMessageThrottled {
+                        origin: location.clone(),
+                        weight,
+                    });
+                    usage.throttled = true;
+                    XcmUsages::<T>::insert(&location, usage);
+                }
+                return XcmRateLimitVerdict::Defer;
+            }
+            usage.messages = messages;
+            usage.weight = used_weight;
+            XcmUsages::<T>::insert(&location, usage);
+            XcmRateLimitVerdict::Execute
+        }
+
+        #[inline]
+        fn note_message_weight() -> Weight {
+            // Reads the override, the default rate limit and the usage of the origin, writes the
+            // usage and deposits an event, weighed as one more write.
+            T::DbWeight::get().reads_writes(3, 2)
+        }
+    }
+}
@@ -0,0 +1,261 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Unit tests for xcm-rate-limiter
+
+use crate::{
+    mock::*, Error, Event as XcmRateLimiterEvent, XcmRateLimit, XcmRateLimitOverride, XcmUsages,
+};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use manta_primitives::{
+    assets::AssetLocation,
+    xcm::{XcmRateLimitVerdict, XcmRateLimiter as _},
+};
+use sp_runtime::traits::BadOrigin;
+use xcm::{
+    latest::{Junction::Parachain, Junctions::X1, MultiLocation},
+    VersionedMultiLocation,
+};
+
+const LIMIT: XcmRateLimit = XcmRateLimit {
+    window: 10,
+    max_messages: 2,
+    max_weight: 1_000,
+};
+
+fn sibling() -> MultiLocation {
+    MultiLocation::new(1, X1(Parachain(2015)))
+}
+
+fn location(location: MultiLocation) -> AssetLocation {
+    AssetLocation(VersionedMultiLocation::V1(location))
+}
+
+fn throttled_events() -> usize {
+    System::events()
+        .into_iter()
+        .filter(|record| {
+            matches!(
+                record.event,
+                Event::XcmRateLimiter(XcmRateLimiterEvent::XcmMessageThrottled { .. })
+            )
+        })
+        .count()
+}
+
+#[test]
+fn xcm_rate_limit_should_throttle_origins_over_budget() {
+    new_test_ext().execute_with(|| {
+        let relay = MultiLocation::parent();
+        let sibling = sibling();
+        let trusted = MultiLocation::new(1, X1(Parachain(2016)));
+        // Origins are not rate limited by default
+        assert_eq!(
+            XcmRateLimiter::note_message(&sibling, u64::MAX),
+            XcmRateLimitVerdict::Execute
+        );
+        assert_noop!(
+            XcmRateLimiter::set_xcm_rate_limit(Origin::signed(ALICE), Some(LIMIT)),
+            BadOrigin
+        );
+        for limit in [
+            XcmRateLimit { window: 0, ..LIMIT },
+            XcmRateLimit {
+                max_messages: 0,
+                ..LIMIT
+            },
+        ] {
+            assert_noop!(
+                XcmRateLimiter::set_xcm_rate_limit(Origin::root(), Some(limit)),
+                Error::<Runtime>::InvalidXcmRateLimit
+            );
+        }
+        assert_ok!(XcmRateLimiter::set_xcm_rate_limit(
+            Origin::root(),
+            Some(LIMIT)
+        ));
+        System::assert_last_event(
+            XcmRateLimiterEvent::XcmRateLimitUpdated { limit: Some(LIMIT) }.into(),
+        );
+        // The relay chain is trusted and the other sibling gets its own budget
+        assert_ok!(XcmRateLimiter::set_xcm_rate_limit_override(
+            Origin::root(),
+            location(relay.clone()),
+            Some(XcmRateLimitOverride::Unlimited)
+        ));
+        System::assert_last_event(
+            XcmRateLimiterEvent::XcmRateLimitOverrideUpdated {
+                location: location(relay.clone()),
+                rate_limit_override: Some(XcmRateLimitOverride::Unlimited),
+            }
+            .into(),
+        );
+        assert_ok!(XcmRateLimiter::set_xcm_rate_limit_override(
+            Origin::root(),
+            location(trusted.clone()),
+            Some(XcmRateLimitOverride::Limited(XcmRateLimit {
+                max_messages: 5,
+                max_weight: u64::MAX,
+                ..LIMIT
+            }))
+        ));
+        // Messages are deferred once the budget of the window is spent
+        assert_eq!(
+            XcmRateLimiter::note_message(&sibling, 400),
+            XcmRateLimitVerdict::Execute
+        );
+        assert_eq!(
+            XcmRateLimiter::note_message(&sibling, 700),
+            XcmRateLimitVerdict::Defer
+        );
+        System::assert_last_event(
+            XcmRateLimiterEvent::XcmMessageThrottled {
+                origin: location(sibling.clone()),
+                weight: 700,
+            }
+            .into(),
+        );
+        assert_eq!(
+            XcmRateLimiter::note_message(&sibling, 600),
+            XcmRateLimitVerdict::Execute
+        );
+        assert_eq!(
+            XcmRateLimiter::note_message(&sibling, 0),
+            XcmRateLimitVerdict::Defer
+        );
+        assert_eq!(
+            XcmRateLimiter::xcm_usage(location(sibling.clone())).messages,
+            2
+        );
+        for _ in 0..5 {
+            assert_eq!(
+                XcmRateLimiter::note_message(&relay, u64::MAX),
+                XcmRateLimitVerdict::Execute
+            );
+            assert_eq!(
+                XcmRateLimiter::note_message(&trusted, 100),
+                XcmRateLimitVerdict::Execute
+            );
+        }
+        assert_eq!(
+            XcmRateLimiter::note_message(&trusted, 100),
+            XcmRateLimitVerdict::Defer
+        );
+        // The budget is restored in the next window
+        System::set_block_number(10);
+        assert_eq!(
+            XcmRateLimiter::note_message(&sibling, 1_000),
+            XcmRateLimitVerdict::Execute
+        );
+        assert_eq!(
+            XcmRateLimiter::note_message(&trusted, 100),
+            XcmRateLimitVerdict::Execute
+        );
+        // Removing the default rate limit stops throttling
+        assert_ok!(XcmRateLimiter::set_xcm_rate_limit(Origin::root(), None));
+        assert_eq!(
+            XcmRateLimiter::note_message(&sibling, u64::MAX),
+            XcmRateLimitVerdict::Execute
+        );
+    });
+}
+
+#[test]
+fn throttled_messages_should_be_reported_once_per_window() {
+    new_test_ext().execute_with(|| {
+        let sibling = sibling();
+        assert_ok!(XcmRateLimiter::set_xcm_rate_limit(
+            Origin::root(),
+            Some(LIMIT)
+        ));
+        for _ in 0..2 {
+            assert_eq!(
+                XcmRateLimiter::note_message(&sibling, 100),
+                XcmRateLimitVerdict::Execute
+            );
+        }
+        // Retries of the deferred messages are not reported again
+        for _ in 0..3 {
+            assert_eq!(
+                XcmRateLimiter::note_message(&sibling, 100),
+                XcmRateLimitVerdict::Defer
+            );
+        }
+        assert_eq!(throttled_events(), 1);
+        // The next window reports throttled messages again
+        System::set_block_number(10);
+        for _ in 0..2 {
+            assert_eq!(
+                XcmRateLimiter::note_message(&sibling, 100),
+                XcmRateLimitVerdict::Execute
+            );
+        }
+        assert_eq!(
+            XcmRateLimiter::note_message(&sibling, 100),
+            XcmRateLimitVerdict::Defer
+        );
+        assert_eq!(throttled_events(), 2);
+    });
+}
+
+#[test]
+fn messages_over_the_budget_of_a_window_should_be_overweight() {
+    new_test_ext().execute_with(|| {
+        let sibling = sibling();
+        assert_ok!(XcmRateLimiter::set_xcm_rate_limit(
+            Origin::root(),
+            Some(LIMIT)
+        ));
+        assert_eq!(
+            XcmRateLimiter::note_message(&sibling, 1_001),
+            XcmRateLimitVerdict::Overweight
+        );
+        System::assert_last_event(
+            XcmRateLimiterEvent::XcmMessageOverweight {
+                origin: location(sibling.clone()),
+                weight: 1_001,
+            }
+            .into(),
+        );
+        // Overweight messages do not use the budget of their origin
+        assert_eq!(
+            XcmRateLimiter::note_message(&sibling, 1_000),
+            XcmRateLimitVerdict::Execute
+        );
+        assert_eq!(throttled_events(), 0);
+    });
+}
+
+#[test]
+fn usage_of_ended_windows_should_be_pruned() {
+    new_test_ext().execute_with(|| {
+        let sibling = sibling();
+        assert_ok!(XcmRateLimiter::set_xcm_rate_limit(
+            Origin::root(),
+            Some(LIMIT)
+        ));
+        assert_eq!(
+            XcmRateLimiter::note_message(&sibling, 100),
+            XcmRateLimitVerdict::Execute
+        );
+        XcmRateLimiter::on_idle(9, u64::MAX);
+        assert!(XcmUsages::<Runtime>::contains_key(location(
+            sibling.clone()
+        )));
+        XcmRateLimiter::on_idle(10, u64::MAX);
+        assert!(!XcmUsages::<Runtime>::contains_key(location(sibling)));
+    });
+}
@@ -0,0 +1,80 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+
+//! Autogenerated weights for pallet_xcm_rate_limiter
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2022-12-29, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("calamari-dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/production/manta
+// benchmark
+// pallet
+// --chain=calamari-dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_xcm_rate_limiter
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./scripts/benchmarking/frame-weights-output/pallet_xcm_rate_limiter.rs
+// --template=.github/resources/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_xcm_rate_limiter.
+pub trait WeightInfo {
+    fn set_xcm_rate_limit() -> Weight;
+    fn set_xcm_rate_limit_override() -> Weight;
+}
+
+/// Weights for pallet_xcm_rate_limiter using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    // Storage: XcmRateLimiter XcmRateLimitDefault (r:0 w:1)
+    fn set_xcm_rate_limit() -> Weight {
+        (21_044_000 as Weight)
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    // Storage: XcmRateLimiter XcmRateLimitOverrides (r:0 w:1)
+    fn set_xcm_rate_limit_override() -> Weight {
+        (23_386_000 as Weight)
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    // Storage: XcmRateLimiter XcmRateLimitDefault (r:0 w:1)
+    fn set_xcm_rate_limit() -> Weight {
+        (21_044_000 as Weight)
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    // Storage: XcmRateLimiter XcmRateLimitOverrides (r:0 w:1)
+    fn set_xcm_rate_limit_override() -> Weight {
+        (23_386_000 as Weight)
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+}
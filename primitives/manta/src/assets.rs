@@ -17,23 +17,33 @@
 //! Asset Utilities
 use crate::{constants::TEST_DEFAULT_ASSET_ED, types::Balance as MantaBalance};
 use alloc::vec::Vec;
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
 use core::{borrow::Borrow, marker::PhantomData};
 use frame_support::{
     dispatch::DispatchError,
     pallet_prelude::Get,
-    traits::tokens::{
-        currency::Currency,
-        fungible,
-        fungibles::{self, Mutate, Transfer},
-        DepositConsequence, ExistenceRequirement, WithdrawReasons,
+    storage::{with_transaction, TransactionOutcome},
+    traits::{
+        tokens::{
+            currency::{Currency, NamedReservableCurrency},
+            fungible,
+            fungibles::{self, Mutate, Transfer},
+            nonfungibles, BalanceStatus, DepositConsequence, ExistenceRequirement, WithdrawReasons,
+        },
+        Locker,
     },
-    Parameter,
+    PalletId, Parameter,
 };
 use frame_system::Config;
 use scale_info::TypeInfo;
+use sp_io::hashing::blake2_256;
+use sp_runtime::{
+    traits::{AccountIdConversion, One, Saturating, TrailingZeroInput, Zero},
+    ArithmeticError, TokenError,
+};
 use xcm::{
-    v1::{Junctions, MultiLocation},
+    v0::{Junction as V0Junction, MultiLocation as V0MultiLocation},
+    v1::{Junction, Junctions, MultiLocation},
     VersionedMultiLocation,
 };
 use xcm_executor::traits::Convert;
@@ -112,6 +122,22 @@ pub trait AssetRegistry: AssetIdType + BalanceType {
         asset_id: &Self::AssetId,
         metadata: Self::Metadata,
     ) -> Result<(), Self::Error>;
+
+    /// Returns the total supply of `asset_id`.
+    fn total_supply(asset_id: &Self::AssetId) -> Self::Balance;
+
+    /// Returns the number of accounts holding `asset_id` and the number of approvals spending
+    /// it.
+    fn accounts_and_approvals(asset_id: &Self::AssetId) -> (u32, u32);
+
+    /// Freezes `asset_id`, preventing any further transfers of it.
+    fn freeze_asset(asset_id: &Self::AssetId) -> Result<(), Self::Error>;
+
+    /// Destroys `asset_id` in the implementation layer.
+    ///
+    /// The destruction should fail if there are still accounts holding or approvals spending this
+    /// asset.
+    fn destroy_asset(asset_id: &Self::AssetId) -> Result<(), Self::Error>;
 }
 
 /// Asset Configuration
@@ -155,6 +181,11 @@ where
         AssetId = Self::AssetId,
         Balance = Self::Balance,
     >;
+
+    /// Asset Status Map
+    ///
+    /// The map we use to look up the lifecycle [`AssetStatus`] of an asset.
+    type AssetStatusMap: AssetStatusMap<AssetId = Self::AssetId>;
 }
 
 /// Asset Storage Metadata
@@ -280,6 +311,55 @@ impl From<AssetLocation> for Option<MultiLocation> {
     }
 }
 
+/// Location Normalization
+///
+/// Equivalent encodings of the same location must be turned into a single canonical form before
+/// they are used as keys of the asset registry, otherwise the same location could be registered as
+/// several distinct assets.
+pub trait NormalizeLocation: Sized {
+    /// Returns the canonical form of `self`.
+    fn normalize(self) -> Self;
+}
+
+impl NormalizeLocation for AssetLocation {
+    /// Locations are converted to XCM v1, which has a single encoding for every location. Every
+    /// `Parent` junction of an XCM v0 location either cancels out the junction before it or is
+    /// counted towards the parents of the location. Locations which cannot be expressed in XCM v1
+    /// are returned unchanged.
+    #[inline]
+    fn normalize(self) -> Self {
+        match &self.0 {
+            VersionedMultiLocation::V0(location) => {
+                normalize_v0_location(location).map_or(self, Self::from)
+            }
+            _ => self,
+        }
+    }
+}
+
+/// Converts the XCM v0 `location` into its canonical XCM v1 form, returning `None` if it cannot be
+/// expressed in XCM v1.
+#[inline]
+fn normalize_v0_location(location: &V0MultiLocation) -> Option<MultiLocation> {
+    let mut parents = 0u8;
+    let mut interior: Vec<Junction> = Vec::new();
+    for junction in location.iter() {
+        match junction {
+            V0Junction::Parent => {
+                if interior.pop().is_none() {
+                    parents = parents.checked_add(1)?;
+                }
+            }
+            junction => interior.push(junction.clone().try_into().ok()?),
+        }
+    }
+    let mut junctions = Junctions::Here;
+    for junction in interior {
+        junctions.push(junction).ok()?;
+    }
+    Some(MultiLocation::new(parents, junctions))
+}
+
 ///
 pub trait AssetIdLocationMap: AssetIdType + LocationType {
     /// Returns the [`Location`](LocationType::Location) of `asset_id`.
@@ -289,6 +369,86 @@ pub trait AssetIdLocationMap: AssetIdType + LocationType {
     fn asset_id(location: &Self::Location) -> Option<Self::AssetId>;
 }
 
+/// Asset Status
+///
+/// Lifecycle status of a registered asset which decides where it can be used.
+#[derive(Clone, Copy, Debug, Decode, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub enum AssetStatus {
+    /// The asset can be used everywhere.
+    Active,
+
+    /// The asset cannot be transferred over XCM or used to pay for XCM execution or transaction
+    /// fees.
+    XcmSuspended,
+
+    /// The asset cannot be converted into or out of private assets.
+    ShieldingSuspended,
+
+    /// The asset is being phased out: it can no longer be received over XCM, used to pay fees or
+    /// converted into private assets, but holders can still move it out.
+    Deprecated,
+}
+
+impl AssetStatus {
+    /// Returns `true` if the asset can be received over XCM or used to pay for XCM execution.
+    #[inline]
+    pub fn can_receive_over_xcm(&self) -> bool {
+        matches!(self, Self::Active | Self::ShieldingSuspended)
+    }
+
+    /// Returns `true` if transaction fees can be paid in the asset.
+    #[inline]
+    pub fn can_pay_fees(&self) -> bool {
+        matches!(self, Self::Active | Self::ShieldingSuspended)
+    }
+
+    /// Returns `true` if the asset can be sent out over XCM.
+    #[inline]
+    pub fn can_send_over_xcm(&self) -> bool {
+        !matches!(self, Self::XcmSuspended)
+    }
+
+    /// Returns `true` if the asset can be converted into private assets.
+    #[inline]
+    pub fn can_shield(&self) -> bool {
+        matches!(self, Self::Active | Self::XcmSuspended)
+    }
+
+    /// Returns `true` if private assets can be converted back into public assets.
+    #[inline]
+    pub fn can_unshield(&self) -> bool {
+        !matches!(self, Self::ShieldingSuspended)
+    }
+}
+
+impl Default for AssetStatus {
+    #[inline]
+    fn default() -> Self {
+        Self::Active
+    }
+}
+
+/// Maps asset ids to their lifecycle [`AssetStatus`].
+pub trait AssetStatusMap: AssetIdType {
+    /// Returns the [`AssetStatus`] of `asset_id`.
+    fn status(asset_id: &Self::AssetId) -> AssetStatus;
+
+    /// Returns `true` if `asset_id` is registered.
+    fn is_registered(asset_id: &Self::AssetId) -> bool;
+}
+
+/// Maps asset ids of single non-fungible items to their collection and item ids.
+pub trait NonFungibleAssetMap: AssetIdType {
+    /// Collection Id Type
+    type CollectionId;
+
+    /// Item Id Type
+    type ItemId;
+
+    /// Returns the collection and item of `asset_id` if it is a non-fungible asset.
+    fn non_fungible(asset_id: &Self::AssetId) -> Option<(Self::CollectionId, Self::ItemId)>;
+}
+
 /// Defines the units per second charged given an `AssetId`.
 pub trait UnitsPerSecond: AssetIdType {
     /// Returns the units per second for `asset_id`.
@@ -355,6 +515,25 @@ pub enum FungibleLedgerError<I, B> {
 
     /// Encode Error
     EncodeError,
+
+    /// Non-fungible assets can only be moved with an amount of exactly one.
+    InvalidNonFungibleAmount(B),
+
+    /// Unable to Reserve an Asset
+    InvalidReserve(DispatchError),
+
+    /// Cannot unreserve or repatriate more than the specified amount
+    CannotUnreserveMoreThan(B),
+
+    /// Unable to Unreserve an Asset
+    InvalidUnreserve(DispatchError),
+
+    /// A leg of a batched transfer failed, given by its index and the cause of the failure.
+    InvalidTransferLeg(u32, DispatchError),
+
+    /// Non-native assets can only be reserved or placed on hold in amounts which bring the
+    /// holding account to at least the given minimum balance of the asset.
+    HoldBelowMinimum(B),
 }
 
 impl<I, B> FungibleLedgerError<I, B> {
@@ -372,6 +551,34 @@ impl<I, B> FungibleLedgerError<I, B> {
     }
 }
 
+impl<I, B> From<FungibleLedgerError<I, B>> for DispatchError {
+    #[inline]
+    fn from(err: FungibleLedgerError<I, B>) -> Self {
+        match err {
+            FungibleLedgerError::InvalidAssetId(_) | FungibleLedgerError::UnknownAsset => {
+                TokenError::UnknownAsset.into()
+            }
+            FungibleLedgerError::BelowMinimum | FungibleLedgerError::HoldBelowMinimum(_) => {
+                TokenError::BelowMinimum.into()
+            }
+            FungibleLedgerError::CannotCreate => TokenError::CannotCreate.into(),
+            FungibleLedgerError::Overflow => ArithmeticError::Overflow.into(),
+            FungibleLedgerError::CannotWithdrawMoreThan(_)
+            | FungibleLedgerError::CannotUnreserveMoreThan(_) => TokenError::NoFunds.into(),
+            FungibleLedgerError::InvalidMint(err)
+            | FungibleLedgerError::InvalidBurn(err)
+            | FungibleLedgerError::InvalidTransfer(err)
+            | FungibleLedgerError::InvalidReserve(err)
+            | FungibleLedgerError::InvalidUnreserve(err)
+            | FungibleLedgerError::InvalidTransferLeg(_, err) => err,
+            FungibleLedgerError::EncodeError => DispatchError::Other("Encode Error"),
+            FungibleLedgerError::InvalidNonFungibleAmount(_) => {
+                DispatchError::Other("Invalid Non-Fungible Amount")
+            }
+        }
+    }
+}
+
 impl<I, B> AssetIdType for FungibleLedgerError<I, B> {
     type AssetId = I;
 }
@@ -380,6 +587,25 @@ impl<I, B> BalanceType for FungibleLedgerError<I, B> {
     type Balance = B;
 }
 
+/// Transfer Leg
+///
+/// A single transfer of `amount` of `asset_id` from `source` to `destination` within a batch of
+/// transfers.
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub struct TransferLeg<I, A, B> {
+    /// Asset Id
+    pub asset_id: I,
+
+    /// Source Account
+    pub source: A,
+
+    /// Destination Account
+    pub destination: A,
+
+    /// Amount
+    pub amount: B,
+}
+
 /// Unified Interface for Fungible Assets
 ///
 /// This trait unifies the interface for the [`fungible`] and [`fungibles`] modules.
@@ -443,8 +669,137 @@ pub trait FungibleLedger: AssetIdType + BalanceType {
         amount: Self::Balance,
         existence_requirement: ExistenceRequirement,
     ) -> Result<(), FungibleLedgerError<Self::AssetId, Self::Balance>>;
+
+    /// Returns the amount of `asset_id` that `who` has reserved through [`reserve`](Self::reserve).
+    ///
+    /// Funds placed on hold for a reason, or reserved outside of this ledger, are not included.
+    fn reserved_balance(
+        asset_id: Self::AssetId,
+        who: &Self::AccountId,
+    ) -> Result<Self::Balance, FungibleLedgerError<Self::AssetId, Self::Balance>>;
+
+    /// Moves `amount` of `asset_id` from the free balance of `who` into its reserved balance.
+    fn reserve(
+        asset_id: Self::AssetId,
+        who: &Self::AccountId,
+        amount: Self::Balance,
+    ) -> Result<(), FungibleLedgerError<Self::AssetId, Self::Balance>>;
+
+    /// Moves `amount` of `asset_id` from the reserved balance of `who` back into its free balance.
+    fn unreserve(
+        asset_id: Self::AssetId,
+        who: &Self::AccountId,
+        amount: Self::Balance,
+    ) -> Result<(), FungibleLedgerError<Self::AssetId, Self::Balance>>;
+
+    /// Moves `amount` of `asset_id` from the reserved balance of `slashed` into the balance of
+    /// `beneficiary` selected by `status`.
+    fn repatriate_reserved(
+        asset_id: Self::AssetId,
+        slashed: &Self::AccountId,
+        beneficiary: &Self::AccountId,
+        amount: Self::Balance,
+        status: BalanceStatus,
+    ) -> Result<(), FungibleLedgerError<Self::AssetId, Self::Balance>>;
+
+    /// Returns the amount of `asset_id` that `who` has placed on hold for `reason`.
+    fn balance_on_hold(
+        asset_id: Self::AssetId,
+        reason: &HoldReason,
+        who: &Self::AccountId,
+    ) -> Result<Self::Balance, FungibleLedgerError<Self::AssetId, Self::Balance>>;
+
+    /// Places `amount` of `asset_id` from the free balance of `who` on hold for `reason`.
+    fn hold(
+        asset_id: Self::AssetId,
+        reason: &HoldReason,
+        who: &Self::AccountId,
+        amount: Self::Balance,
+    ) -> Result<(), FungibleLedgerError<Self::AssetId, Self::Balance>>;
+
+    /// Releases `amount` of `asset_id` held by `who` for `reason` back into its free balance.
+    fn release(
+        asset_id: Self::AssetId,
+        reason: &HoldReason,
+        who: &Self::AccountId,
+        amount: Self::Balance,
+    ) -> Result<(), FungibleLedgerError<Self::AssetId, Self::Balance>>;
+
+    /// Moves `amount` of `asset_id` held by `source` for `reason` into the balance of
+    /// `destination` selected by `status`. Reserved funds stay on hold for the same `reason`.
+    fn transfer_on_hold(
+        asset_id: Self::AssetId,
+        reason: &HoldReason,
+        source: &Self::AccountId,
+        destination: &Self::AccountId,
+        amount: Self::Balance,
+        status: BalanceStatus,
+    ) -> Result<(), FungibleLedgerError<Self::AssetId, Self::Balance>>;
+
+    /// Performs all of the transfers in `legs` or none of them.
+    ///
+    /// Every leg is checked with [`can_withdraw`](Self::can_withdraw) and
+    /// [`can_deposit`](Self::can_deposit) against the balances from before the batch, and the legs
+    /// are then applied in order inside of a storage transaction. Any failure is reported as
+    /// [`InvalidTransferLeg`](FungibleLedgerError::InvalidTransferLeg) with the index of the
+    /// failing leg, and leaves all balances untouched.
+    #[inline]
+    fn transfer_batch(
+        legs: &[TransferLeg<Self::AssetId, Self::AccountId, Self::Balance>],
+        existence_requirement: ExistenceRequirement,
+    ) -> Result<(), FungibleLedgerError<Self::AssetId, Self::Balance>>
+    where
+        Self::AssetId: Clone,
+        Self::Balance: Clone,
+    {
+        for (index, leg) in legs.iter().enumerate() {
+            Self::can_withdraw(
+                leg.asset_id.clone(),
+                &leg.source,
+                &leg.amount,
+                existence_requirement,
+            )
+            .and_then(|asset_id| {
+                Self::can_deposit(asset_id, &leg.destination, leg.amount.clone(), false)
+            })
+            .map_err(|err| FungibleLedgerError::InvalidTransferLeg(index as u32, err.into()))?;
+        }
+        let mut failed_leg = 0;
+        with_transaction(|| {
+            for (index, leg) in legs.iter().enumerate() {
+                if let Err(err) = Self::transfer(
+                    leg.asset_id.clone(),
+                    &leg.source,
+                    &leg.destination,
+                    leg.amount.clone(),
+                    existence_requirement,
+                ) {
+                    failed_leg = index as u32;
+                    return TransactionOutcome::Rollback(Err(DispatchError::from(err)));
+                }
+            }
+            TransactionOutcome::Commit(Ok(()))
+        })
+        .map_err(|err| FungibleLedgerError::InvalidTransferLeg(failed_leg, err))
+    }
 }
 
+/// Hold Reason
+///
+/// Identifies why funds are on hold. For the native asset this is the named reserve identifier of
+/// the balances pallet.
+pub type HoldReason = [u8; 8];
+
+/// Hold Reason of Reserves
+///
+/// The named reserve identifier under which [`NativeAndNonNative`] reserves the native asset, so
+/// that its reserved balance only covers reserves of the [`FungibleLedger`], as it does for
+/// non-native assets.
+pub const RESERVE_HOLD_REASON: HoldReason = *b"manta/rs";
+
+/// Prefix of the accounts holding reserved non-native assets
+pub const HOLDING_ACCOUNT_PREFIX: &[u8; 10] = b"manta/hold";
+
 /// Fungible Ledger Implementation for Native and NonNative Assets
 ///
 /// The `Native` assets are defined by an implementation of a [`fungible`] asset and the `NonNative`
@@ -470,6 +825,168 @@ where
     type Balance = A::Balance;
 }
 
+impl<C, A, Native, NonNative> NativeAndNonNative<C, A, Native, NonNative>
+where
+    C: Config,
+    A: AssetConfig<C>,
+    A::AssetId: Clone + PartialOrd,
+    A::Balance: Clone + PartialOrd,
+    Native: fungible::Inspect<C::AccountId, Balance = A::Balance>
+        + NamedReservableCurrency<C::AccountId, Balance = A::Balance, ReserveIdentifier = HoldReason>,
+    NonNative: fungibles::Inspect<C::AccountId, AssetId = A::AssetId, Balance = A::Balance>
+        + Mutate<C::AccountId>
+        + Transfer<C::AccountId>,
+{
+    /// Returns the account holding the non-native assets that `who` has placed on hold for
+    /// `reason`, or has reserved when `reason` is `None`.
+    ///
+    /// Since [`fungibles`] has no notion of reserved balances, non-native assets are reserved by
+    /// moving them into this account, which nobody can sign for. The account is subject to the
+    /// minimum balance of the asset like any other, so amounts which would leave it with less are
+    /// rejected with [`HoldBelowMinimum`](FungibleLedgerError::HoldBelowMinimum).
+    #[inline]
+    pub fn holding_account(who: &C::AccountId, reason: Option<&HoldReason>) -> C::AccountId {
+        let entropy = (HOLDING_ACCOUNT_PREFIX, who, reason).using_encoded(blake2_256);
+        Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+            .expect("Infinite length input. No invalid inputs for type.")
+    }
+
+    /// Returns the amount of `asset_id` that `who` has placed on hold for `reason`, or has
+    /// reserved when `reason` is `None`.
+    #[inline]
+    fn on_hold(
+        asset_id: A::AssetId,
+        reason: Option<&HoldReason>,
+        who: &C::AccountId,
+    ) -> Result<A::Balance, FungibleLedgerError<A::AssetId, A::Balance>> {
+        let asset_id = Self::ensure_valid(asset_id)?;
+        Ok(if asset_id == A::NativeAssetId::get() {
+            Native::reserved_balance_named(reason.unwrap_or(&RESERVE_HOLD_REASON), who)
+        } else {
+            NonNative::balance(asset_id, &Self::holding_account(who, reason))
+        })
+    }
+
+    /// Checks that `who` has at least `amount` of `asset_id` on hold for `reason`.
+    #[inline]
+    fn ensure_on_hold(
+        asset_id: A::AssetId,
+        reason: Option<&HoldReason>,
+        who: &C::AccountId,
+        amount: &A::Balance,
+    ) -> Result<A::AssetId, FungibleLedgerError<A::AssetId, A::Balance>> {
+        let on_hold = Self::on_hold(asset_id.clone(), reason, who)?;
+        if on_hold >= *amount {
+            Ok(asset_id)
+        } else {
+            Err(FungibleLedgerError::CannotUnreserveMoreThan(on_hold))
+        }
+    }
+
+    /// Checks that moving `amount` of the non-native `asset_id` into `holding_account` leaves it
+    /// with at least the minimum balance of the asset.
+    #[inline]
+    fn ensure_holding_minimum(
+        asset_id: A::AssetId,
+        holding_account: &C::AccountId,
+        amount: &A::Balance,
+    ) -> Result<(), FungibleLedgerError<A::AssetId, A::Balance>> {
+        let minimum_balance = NonNative::minimum_balance(asset_id.clone());
+        if NonNative::balance(asset_id, holding_account).saturating_add(amount.clone())
+            < minimum_balance
+        {
+            return Err(FungibleLedgerError::HoldBelowMinimum(minimum_balance));
+        }
+        Ok(())
+    }
+
+    /// Places `amount` of `asset_id` on hold for `reason`, or reserves it when `reason` is `None`.
+    ///
+    /// Like a transfer with [`KeepAlive`](ExistenceRequirement::KeepAlive), placing non-native
+    /// assets on hold never reaps the account of `who`.
+    #[inline]
+    fn place_on_hold(
+        asset_id: A::AssetId,
+        reason: Option<&HoldReason>,
+        who: &C::AccountId,
+        amount: A::Balance,
+    ) -> Result<(), FungibleLedgerError<A::AssetId, A::Balance>> {
+        let asset_id = Self::ensure_valid(asset_id)?;
+        if asset_id == A::NativeAssetId::get() {
+            Native::reserve_named(reason.unwrap_or(&RESERVE_HOLD_REASON), who, amount)
+        } else {
+            let holding_account = Self::holding_account(who, reason);
+            Self::ensure_holding_minimum(asset_id.clone(), &holding_account, &amount)?;
+            NonNative::transfer(asset_id, who, &holding_account, amount, true).map(|_| ())
+        }
+        .map_err(FungibleLedgerError::InvalidReserve)
+    }
+
+    /// Releases `amount` of `asset_id` placed on hold for `reason`, or unreserves it when `reason`
+    /// is `None`.
+    #[inline]
+    fn release_from_hold(
+        asset_id: A::AssetId,
+        reason: Option<&HoldReason>,
+        who: &C::AccountId,
+        amount: A::Balance,
+    ) -> Result<(), FungibleLedgerError<A::AssetId, A::Balance>> {
+        let asset_id = Self::ensure_on_hold(asset_id, reason, who, &amount)?;
+        if asset_id == A::NativeAssetId::get() {
+            // NOTE: The remaining balance is always zero since the amount on hold was checked.
+            Native::unreserve_named(reason.unwrap_or(&RESERVE_HOLD_REASON), who, amount);
+            Ok(())
+        } else {
+            let holding_account = Self::holding_account(who, reason);
+            NonNative::transfer(asset_id, &holding_account, who, amount, false)
+                .map(|_| ())
+                .map_err(FungibleLedgerError::InvalidUnreserve)
+        }
+    }
+
+    /// Moves `amount` of `asset_id` placed on hold by `source` for `reason`, or reserved when
+    /// `reason` is `None`, into the balance of `destination` selected by `status`.
+    #[inline]
+    fn repatriate_from_hold(
+        asset_id: A::AssetId,
+        reason: Option<&HoldReason>,
+        source: &C::AccountId,
+        destination: &C::AccountId,
+        amount: A::Balance,
+        status: BalanceStatus,
+    ) -> Result<(), FungibleLedgerError<A::AssetId, A::Balance>> {
+        let asset_id = Self::ensure_on_hold(asset_id, reason, source, &amount)?;
+        if asset_id == A::NativeAssetId::get() {
+            Native::repatriate_reserved_named(
+                reason.unwrap_or(&RESERVE_HOLD_REASON),
+                source,
+                destination,
+                amount,
+                status,
+            )
+            .map(|_| ())
+        } else {
+            let destination = match status {
+                BalanceStatus::Free => destination.clone(),
+                BalanceStatus::Reserved => {
+                    let holding_account = Self::holding_account(destination, reason);
+                    Self::ensure_holding_minimum(asset_id.clone(), &holding_account, &amount)?;
+                    holding_account
+                }
+            };
+            NonNative::transfer(
+                asset_id,
+                &Self::holding_account(source, reason),
+                &destination,
+                amount,
+                false,
+            )
+            .map(|_| ())
+        }
+        .map_err(FungibleLedgerError::InvalidTransfer)
+    }
+}
+
 impl<C, A, Native, NonNative> FungibleLedger for NativeAndNonNative<C, A, Native, NonNative>
 where
     C: Config,
@@ -477,7 +994,7 @@ where
     A::AssetId: Clone + PartialOrd,
     A::Balance: Clone + PartialOrd,
     Native: fungible::Inspect<C::AccountId, Balance = A::Balance>
-        + Currency<C::AccountId, Balance = A::Balance>,
+        + NamedReservableCurrency<C::AccountId, Balance = A::Balance, ReserveIdentifier = HoldReason>,
     NonNative: fungibles::Inspect<C::AccountId, AssetId = A::AssetId, Balance = A::Balance>
         + Mutate<C::AccountId>
         + Transfer<C::AccountId>,
@@ -637,4 +1154,403 @@ where
         }
         Ok(())
     }
+
+    #[inline]
+    fn reserved_balance(
+        asset_id: Self::AssetId,
+        who: &C::AccountId,
+    ) -> Result<Self::Balance, FungibleLedgerError<Self::AssetId, Self::Balance>> {
+        Self::on_hold(asset_id, None, who)
+    }
+
+    #[inline]
+    fn reserve(
+        asset_id: Self::AssetId,
+        who: &C::AccountId,
+        amount: Self::Balance,
+    ) -> Result<(), FungibleLedgerError<Self::AssetId, Self::Balance>> {
+        Self::place_on_hold(asset_id, None, who, amount)
+    }
+
+    /// Non-native assets are moved out of the holding account with `allow_death`, so any dust left
+    /// behind in it is unreserved together with `amount`.
+    #[inline]
+    fn unreserve(
+        asset_id: Self::AssetId,
+        who: &C::AccountId,
+        amount: Self::Balance,
+    ) -> Result<(), FungibleLedgerError<Self::AssetId, Self::Balance>> {
+        Self::release_from_hold(asset_id, None, who, amount)
+    }
+
+    #[inline]
+    fn repatriate_reserved(
+        asset_id: Self::AssetId,
+        slashed: &C::AccountId,
+        beneficiary: &C::AccountId,
+        amount: Self::Balance,
+        status: BalanceStatus,
+    ) -> Result<(), FungibleLedgerError<Self::AssetId, Self::Balance>> {
+        Self::repatriate_from_hold(asset_id, None, slashed, beneficiary, amount, status)
+    }
+
+    #[inline]
+    fn balance_on_hold(
+        asset_id: Self::AssetId,
+        reason: &HoldReason,
+        who: &C::AccountId,
+    ) -> Result<Self::Balance, FungibleLedgerError<Self::AssetId, Self::Balance>> {
+        Self::on_hold(asset_id, Some(reason), who)
+    }
+
+    #[inline]
+    fn hold(
+        asset_id: Self::AssetId,
+        reason: &HoldReason,
+        who: &C::AccountId,
+        amount: Self::Balance,
+    ) -> Result<(), FungibleLedgerError<Self::AssetId, Self::Balance>> {
+        Self::place_on_hold(asset_id, Some(reason), who, amount)
+    }
+
+    /// Non-native assets are moved out of the holding account with `allow_death`, so any dust left
+    /// behind in it is released together with `amount`.
+    #[inline]
+    fn release(
+        asset_id: Self::AssetId,
+        reason: &HoldReason,
+        who: &C::AccountId,
+        amount: Self::Balance,
+    ) -> Result<(), FungibleLedgerError<Self::AssetId, Self::Balance>> {
+        Self::release_from_hold(asset_id, Some(reason), who, amount)
+    }
+
+    #[inline]
+    fn transfer_on_hold(
+        asset_id: Self::AssetId,
+        reason: &HoldReason,
+        source: &C::AccountId,
+        destination: &C::AccountId,
+        amount: Self::Balance,
+        status: BalanceStatus,
+    ) -> Result<(), FungibleLedgerError<Self::AssetId, Self::Balance>> {
+        Self::repatriate_from_hold(asset_id, Some(reason), source, destination, amount, status)
+    }
+}
+
+environmental::environmental!(ledger_transfer: bool);
+
+/// Runs `f` with the locks of [`PalletItemLocker`] lifted.
+///
+/// The flag lifting the locks only lives in memory for the duration of `f`, so it is never
+/// written to storage and cannot outlive the transfer made by the ledger.
+#[inline]
+fn with_ledger_transfer<R>(f: impl FnOnce() -> R) -> R {
+    ledger_transfer::using(&mut true, f)
+}
+
+/// Pallet Item Locker
+///
+/// Locks every non-fungible item of `NonFungible` owned by the account of the pallet `P`, so that
+/// neither the owner nor the admins of its collection can move it out of the pallet account. The
+/// items can still be moved by the [`FungibleAndNonFungible`] ledger.
+pub struct PalletItemLocker<P, AccountId, NonFungible> {
+    ///  Type Parameter Marker
+    __: PhantomData<(P, AccountId, NonFungible)>,
+}
+
+impl<P, AccountId, NonFungible> Locker<NonFungible::CollectionId, NonFungible::ItemId>
+    for PalletItemLocker<P, AccountId, NonFungible>
+where
+    P: Get<PalletId>,
+    AccountId: Decode + Encode + PartialEq,
+    NonFungible: nonfungibles::Inspect<AccountId>,
+{
+    #[inline]
+    fn is_locked(collection: NonFungible::CollectionId, item: NonFungible::ItemId) -> bool {
+        !ledger_transfer::with(|active| *active).unwrap_or(false)
+            && NonFungible::owner(&collection, &item) == Some(P::get().into_account_truncating())
+    }
+}
+
+/// Fungible Ledger Implementation for Fungible and Non-Fungible Assets
+///
+/// Asset ids which are mapped to an item by `M` are backed by the `NonFungible` ledger and can only
+/// be moved in amounts of exactly one. All other asset ids are forwarded to the `Fungible` ledger.
+/// Non-fungible items cannot be minted or burned through this ledger.
+pub struct FungibleAndNonFungible<Fungible, M, NonFungible> {
+    ///  Type Parameter Marker
+    __: PhantomData<(Fungible, M, NonFungible)>,
+}
+
+impl<Fungible, M, NonFungible> FungibleAndNonFungible<Fungible, M, NonFungible>
+where
+    Fungible: FungibleLedger,
+    Fungible::Balance: Clone + One + PartialEq,
+{
+    /// Checks that `amount` is the unit amount of a non-fungible asset.
+    #[inline]
+    fn ensure_unit(
+        amount: &Fungible::Balance,
+    ) -> Result<(), FungibleLedgerError<Fungible::AssetId, Fungible::Balance>> {
+        if amount.is_one() {
+            Ok(())
+        } else {
+            Err(FungibleLedgerError::InvalidNonFungibleAmount(
+                amount.clone(),
+            ))
+        }
+    }
+}
+
+impl<Fungible, M, NonFungible> AssetIdType for FungibleAndNonFungible<Fungible, M, NonFungible>
+where
+    Fungible: FungibleLedger,
+{
+    type AssetId = Fungible::AssetId;
+}
+
+impl<Fungible, M, NonFungible> BalanceType for FungibleAndNonFungible<Fungible, M, NonFungible>
+where
+    Fungible: FungibleLedger,
+{
+    type Balance = Fungible::Balance;
+}
+
+impl<Fungible, M, NonFungible> FungibleLedger for FungibleAndNonFungible<Fungible, M, NonFungible>
+where
+    Fungible: FungibleLedger,
+    Fungible::AccountId: PartialEq,
+    Fungible::Balance: Clone + One + PartialEq + Zero,
+    M: NonFungibleAssetMap<AssetId = Fungible::AssetId>,
+    NonFungible: nonfungibles::Inspect<
+            Fungible::AccountId,
+            CollectionId = M::CollectionId,
+            ItemId = M::ItemId,
+        > + nonfungibles::Transfer<Fungible::AccountId>,
+{
+    type AccountId = Fungible::AccountId;
+
+    #[inline]
+    fn ensure_valid(
+        asset_id: Self::AssetId,
+    ) -> Result<Self::AssetId, FungibleLedgerError<Self::AssetId, Self::Balance>> {
+        if M::non_fungible(&asset_id).is_some() {
+            Ok(asset_id)
+        } else {
+            Fungible::ensure_valid(asset_id)
+        }
+    }
+
+    /// Non-fungible items can only be deposited if they exist, so the `can_increase_total_supply`
+    /// flag is ignored for them.
+    #[inline]
+    fn can_deposit(
+        asset_id: Self::AssetId,
+        account: &Self::AccountId,
+        amount: Self::Balance,
+        can_increase_total_supply: bool,
+    ) -> Result<Self::AssetId, FungibleLedgerError<Self::AssetId, Self::Balance>> {
+        match M::non_fungible(&asset_id) {
+            Some((collection, item)) => {
+                Self::ensure_unit(&amount)?;
+                NonFungible::owner(&collection, &item)
+                    .map(|_| asset_id)
+                    .ok_or(FungibleLedgerError::UnknownAsset)
+            }
+            _ => Fungible::can_deposit(asset_id, account, amount, can_increase_total_supply),
+        }
+    }
+
+    #[inline]
+    fn deposit_minting(
+        asset_id: Self::AssetId,
+        account: &Self::AccountId,
+        amount: Self::Balance,
+    ) -> Result<(), FungibleLedgerError<Self::AssetId, Self::Balance>> {
+        if M::non_fungible(&asset_id).is_some() {
+            return Err(FungibleLedgerError::InvalidMint(DispatchError::Other(
+                "Non-fungible items cannot be minted through the fungible ledger",
+            )));
+        }
+        Fungible::deposit_minting(asset_id, account, amount)
+    }
+
+    #[inline]
+    fn deposit_minting_with_check(
+        asset_id: Self::AssetId,
+        account: &Self::AccountId,
+        amount: Self::Balance,
+        can_increase_total_supply: bool,
+    ) -> Result<(), FungibleLedgerError<Self::AssetId, Self::Balance>> {
+        if M::non_fungible(&asset_id).is_some() {
+            return Err(FungibleLedgerError::InvalidMint(DispatchError::Other(
+                "Non-fungible items cannot be minted through the fungible ledger",
+            )));
+        }
+        Fungible::deposit_minting_with_check(asset_id, account, amount, can_increase_total_supply)
+    }
+
+    #[inline]
+    fn transfer(
+        asset_id: Self::AssetId,
+        source: &Self::AccountId,
+        destination: &Self::AccountId,
+        amount: Self::Balance,
+        existence_requirement: ExistenceRequirement,
+    ) -> Result<(), FungibleLedgerError<Self::AssetId, Self::Balance>> {
+        match M::non_fungible(&asset_id) {
+            Some((collection, item)) => {
+                Self::can_withdraw(asset_id, source, &amount, existence_requirement)?;
+                with_ledger_transfer(|| NonFungible::transfer(&collection, &item, destination))
+                    .map_err(FungibleLedgerError::InvalidTransfer)
+            }
+            _ => Fungible::transfer(asset_id, source, destination, amount, existence_requirement),
+        }
+    }
+
+    /// Non-fungible items can only be withdrawn by their current owner, independently of the
+    /// `existence_requirement`.
+    #[inline]
+    fn can_withdraw(
+        asset_id: Self::AssetId,
+        account: &Self::AccountId,
+        amount: &Self::Balance,
+        existence_requirement: ExistenceRequirement,
+    ) -> Result<Self::AssetId, FungibleLedgerError<Self::AssetId, Self::Balance>> {
+        match M::non_fungible(&asset_id) {
+            Some((collection, item)) => {
+                Self::ensure_unit(amount)?;
+                if NonFungible::owner(&collection, &item).as_ref() == Some(account) {
+                    Ok(asset_id)
+                } else {
+                    Err(FungibleLedgerError::CannotWithdrawMoreThan(Zero::zero()))
+                }
+            }
+            _ => Fungible::can_withdraw(asset_id, account, amount, existence_requirement),
+        }
+    }
+
+    #[inline]
+    fn withdraw_burning(
+        asset_id: Self::AssetId,
+        who: &Self::AccountId,
+        amount: Self::Balance,
+        existence_requirement: ExistenceRequirement,
+    ) -> Result<(), FungibleLedgerError<Self::AssetId, Self::Balance>> {
+        if M::non_fungible(&asset_id).is_some() {
+            return Err(FungibleLedgerError::InvalidBurn(DispatchError::Other(
+                "Non-fungible items cannot be burned through the fungible ledger",
+            )));
+        }
+        Fungible::withdraw_burning(asset_id, who, amount, existence_requirement)
+    }
+
+    /// Non-fungible items can never be reserved, so their reserved balance is always zero.
+    #[inline]
+    fn reserved_balance(
+        asset_id: Self::AssetId,
+        who: &Self::AccountId,
+    ) -> Result<Self::Balance, FungibleLedgerError<Self::AssetId, Self::Balance>> {
+        if M::non_fungible(&asset_id).is_some() {
+            return Ok(Zero::zero());
+        }
+        Fungible::reserved_balance(asset_id, who)
+    }
+
+    #[inline]
+    fn reserve(
+        asset_id: Self::AssetId,
+        who: &Self::AccountId,
+        amount: Self::Balance,
+    ) -> Result<(), FungibleLedgerError<Self::AssetId, Self::Balance>> {
+        if M::non_fungible(&asset_id).is_some() {
+            return Err(FungibleLedgerError::InvalidReserve(DispatchError::Other(
+                "Non-fungible items cannot be reserved through the fungible ledger",
+            )));
+        }
+        Fungible::reserve(asset_id, who, amount)
+    }
+
+    #[inline]
+    fn unreserve(
+        asset_id: Self::AssetId,
+        who: &Self::AccountId,
+        amount: Self::Balance,
+    ) -> Result<(), FungibleLedgerError<Self::AssetId, Self::Balance>> {
+        if M::non_fungible(&asset_id).is_some() {
+            return Err(FungibleLedgerError::CannotUnreserveMoreThan(Zero::zero()));
+        }
+        Fungible::unreserve(asset_id, who, amount)
+    }
+
+    #[inline]
+    fn repatriate_reserved(
+        asset_id: Self::AssetId,
+        slashed: &Self::AccountId,
+        beneficiary: &Self::AccountId,
+        amount: Self::Balance,
+        status: BalanceStatus,
+    ) -> Result<(), FungibleLedgerError<Self::AssetId, Self::Balance>> {
+        if M::non_fungible(&asset_id).is_some() {
+            return Err(FungibleLedgerError::CannotUnreserveMoreThan(Zero::zero()));
+        }
+        Fungible::repatriate_reserved(asset_id, slashed, beneficiary, amount, status)
+    }
+
+    /// Non-fungible items can never be placed on hold, so their balance on hold is always zero.
+    #[inline]
+    fn balance_on_hold(
+        asset_id: Self::AssetId,
+        reason: &HoldReason,
+        who: &Self::AccountId,
+    ) -> Result<Self::Balance, FungibleLedgerError<Self::AssetId, Self::Balance>> {
+        if M::non_fungible(&asset_id).is_some() {
+            return Ok(Zero::zero());
+        }
+        Fungible::balance_on_hold(asset_id, reason, who)
+    }
+
+    #[inline]
+    fn hold(
+        asset_id: Self::AssetId,
+        reason: &HoldReason,
+        who: &Self::AccountId,
+        amount: Self::Balance,
+    ) -> Result<(), FungibleLedgerError<Self::AssetId, Self::Balance>> {
+        if M::non_fungible(&asset_id).is_some() {
+            return Err(FungibleLedgerError::InvalidReserve(DispatchError::Other(
+                "Non-fungible items cannot be held through the fungible ledger",
+            )));
+        }
+        Fungible::hold(asset_id, reason, who, amount)
+    }
+
+    #[inline]
+    fn release(
+        asset_id: Self::AssetId,
+        reason: &HoldReason,
+        who: &Self::AccountId,
+        amount: Self::Balance,
+    ) -> Result<(), FungibleLedgerError<Self::AssetId, Self::Balance>> {
+        if M::non_fungible(&asset_id).is_some() {
+            return Err(FungibleLedgerError::CannotUnreserveMoreThan(Zero::zero()));
+        }
+        Fungible::release(asset_id, reason, who, amount)
+    }
+
+    #[inline]
+    fn transfer_on_hold(
+        asset_id: Self::AssetId,
+        reason: &HoldReason,
+        source: &Self::AccountId,
+        destination: &Self::AccountId,
+        amount: Self::Balance,
+        status: BalanceStatus,
+    ) -> Result<(), FungibleLedgerError<Self::AssetId, Self::Balance>> {
+        if M::non_fungible(&asset_id).is_some() {
+            return Err(FungibleLedgerError::CannotUnreserveMoreThan(Zero::zero()));
+        }
+        Fungible::transfer_on_hold(asset_id, reason, source, destination, amount, status)
+    }
 }
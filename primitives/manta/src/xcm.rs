@@ -21,18 +21,23 @@ use super::{
     constants::WEIGHT_PER_SECOND,
 };
 
-use sp_runtime::traits::{CheckedConversion, Convert, Zero};
-use sp_std::marker::PhantomData;
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{AtLeast32BitUnsigned, CheckedConversion, Convert, Zero},
+    Percent,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
 
-use crate::assets::{AssetIdLocationMap, UnitsPerSecond};
+use crate::assets::{AssetIdLocationMap, AssetStatusMap, UnitsPerSecond};
 use frame_support::{
     pallet_prelude::Get,
-    traits::{fungibles::Mutate, tokens::ExistenceRequirement},
+    traits::{fungibles::Mutate, tokens::ExistenceRequirement, Contains},
     weights::Weight,
 };
 use frame_system::Config;
 use xcm::{
-    latest::{prelude::Concrete, Error as XcmError},
+    latest::{prelude::Concrete, Error as XcmError, ExecuteXcm, Instruction, Outcome, Xcm},
     v1::{
         AssetId as XcmAssetId, Fungibility,
         Junction::{AccountId32, Parachain},
@@ -44,7 +49,7 @@ use xcm_builder::TakeRevenue;
 use xcm_executor::{
     traits::{
         Convert as XcmConvert, FilterAssetLocation, MatchesFungible, MatchesFungibles,
-        TransactAsset, WeightTrader,
+        TransactAsset, WeightBounds, WeightTrader,
     },
     Assets,
 };
@@ -107,35 +112,154 @@ where
     }
 }
 
+/// Weight Purchase
+///
+/// Weight bought by the [`FirstAssetTrader`] with a single asset.
+struct WeightPurchase {
+    /// Location of the Asset
+    location: MultiLocation,
+
+    /// Weight bought with the Asset
+    weight: Weight,
+
+    /// Amount of the Asset paid for the Weight
+    amount: u128,
+
+    /// Price of the Weight in Units of the Asset per Second
+    units_per_second: u128,
+}
+
 ///
 /// This trader defines how to charge a XCM call.
-/// This takes the first fungible asset, and takes UnitPerSecondGetter that implements
-/// UnitToWeightRatio trait.
-pub struct FirstAssetTrader<M, R>
+/// This tries every fungible asset of the payment, starting with the locations given by `P` in
+/// their order and then the remaining assets in the order of the holding. The first asset which
+/// resolves with `M` and whose [`UnitsPerSecond`] of `U` can cover the weight pays for it.
+/// Purchases are tracked for every asset that was paid with, so that unused weight is refunded in
+/// those assets and `R` takes the revenue of each of them.
+///
+/// The native asset is held at `here()` while it is registered in `M` at the self location `S`, so
+/// `here()` is resolved through `S` as in [`IsNativeConcrete`]. Preferences for the native asset
+/// in `P` are given as `here()`.
+pub struct FirstAssetTrader<M, U, R, P = (), S = ()>
 where
     R: TakeRevenue,
 {
     /// Weight
     weight: Weight,
 
-    /// Refund Cache
-    refund_cache: Option<(MultiLocation, u128, u128)>,
+    /// Weight Purchases in the Order of their Assets' first Purchase
+    purchases: Vec<WeightPurchase>,
 
     /// Type Parameter Marker
-    __: PhantomData<(M, R)>,
+    __: PhantomData<(M, U, R, P, S)>,
 }
 
-impl<M, R> WeightTrader for FirstAssetTrader<M, R>
+impl<M, U, R, P, S> FirstAssetTrader<M, U, R, P, S>
 where
-    M: AssetIdLocationMap + UnitsPerSecond,
+    M: AssetIdLocationMap + AssetStatusMap,
     M::Location: From<MultiLocation>,
+    U: UnitsPerSecond<AssetId = M::AssetId>,
     R: TakeRevenue,
+    S: Get<MultiLocation>,
+{
+    /// Buys `weight` with the asset at `id` out of `payment`, returning the unused part of
+    /// `payment`.
+    #[inline]
+    fn buy_weight_with(
+        &mut self,
+        weight: Weight,
+        payment: &Assets,
+        id: MultiLocation,
+    ) -> Result<Assets> {
+        let location = if id.is_here() { S::get() } else { id.clone() };
+        let asset_id = M::asset_id(&location.into()).ok_or({
+            log::debug!(
+                target: "FirstAssetTrader::buy_weight",
+                "asset_id missing for asset location with id: {:?}",
+                id,
+            );
+            XcmError::TooExpensive
+        })?;
+        if !M::status(&asset_id).can_receive_over_xcm() {
+            log::debug!(
+                target: "FirstAssetTrader::buy_weight",
+                "asset with id: {:?} cannot be used to pay for XCM execution",
+                id,
+            );
+            return Err(XcmError::TooExpensive);
+        }
+        let units_per_second = U::units_per_second(&asset_id).ok_or({
+            log::debug!(
+                target: "FirstAssetTrader::buy_weight",
+                "units_per_second missing for asset with id: {:?}",
+                id,
+            );
+            XcmError::TooExpensive
+        })?;
+
+        let amount = units_per_second * (weight as u128) / (WEIGHT_PER_SECOND as u128);
+        // we don't need to proceed if amount is zero.
+        // This is very useful in tests.
+        if amount.is_zero() {
+            return Ok(payment.clone());
+        }
+        let required = MultiAsset {
+            fun: Fungibility::Fungible(amount),
+            id: XcmAssetId::Concrete(id.clone()),
+        };
+
+        log::debug!(
+            target: "FirstAssetTrader::buy_weight",
+            "payment: {:?}, required: {:?}",
+            payment,
+            required,
+        );
+        let unused = payment.clone().checked_sub(required).map_err(|_| {
+            log::debug!(
+                target: "FirstAssetTrader::buy_weight",
+                "not enough of asset with id: {:?} in payment",
+                id,
+            );
+            XcmError::TooExpensive
+        })?;
+        self.weight = self.weight.saturating_add(weight);
+
+        // Purchases with an asset that was already paid with are merged into its purchase, which
+        // keeps the price of the first purchase for later refunds.
+        match self
+            .purchases
+            .iter_mut()
+            .find(|purchase| purchase.location == id)
+        {
+            Some(purchase) => {
+                purchase.weight = purchase.weight.saturating_add(weight);
+                purchase.amount = purchase.amount.saturating_add(amount);
+            }
+            _ => self.purchases.push(WeightPurchase {
+                location: id,
+                weight,
+                amount,
+                units_per_second,
+            }),
+        }
+        Ok(unused)
+    }
+}
+
+impl<M, U, R, P, S> WeightTrader for FirstAssetTrader<M, U, R, P, S>
+where
+    M: AssetIdLocationMap + AssetStatusMap,
+    M::Location: From<MultiLocation>,
+    U: UnitsPerSecond<AssetId = M::AssetId>,
+    R: TakeRevenue,
+    P: Get<Vec<MultiLocation>>,
+    S: Get<MultiLocation>,
 {
     #[inline]
     fn new() -> Self {
         Self {
             weight: Zero::zero(),
-            refund_cache: None,
+            purchases: Vec::new(),
             __: PhantomData,
         }
     }
@@ -151,125 +275,92 @@ where
             payment
         );
 
-        let first_asset = payment.fungible_assets_iter().next().ok_or({
+        let mut candidates = payment
+            .fungible_assets_iter()
+            .filter_map(|asset| match asset {
+                MultiAsset {
+                    id: XcmAssetId::Concrete(id),
+                    fun: Fungibility::Fungible(_),
+                } => Some(id),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if candidates.is_empty() {
             log::debug!(
                 target: "FirstAssetTrader::buy_weight",
-                "no assets in payment: {:?}",
+                "no concrete fungible assets in payment: {:?}",
                 payment,
             );
-            XcmError::TooExpensive
-        })?;
-
-        // Check the first asset
-        match (first_asset.id, first_asset.fun) {
-            (XcmAssetId::Concrete(id), Fungibility::Fungible(_)) => {
-                let asset_id = M::asset_id(&id.clone().into()).ok_or({
-                    log::debug!(
-                        target: "FirstAssetTrader::buy_weight",
-                        "asset_id missing for asset location with id: {:?}",
-                        id,
-                    );
-                    XcmError::TooExpensive
-                })?;
-                let units_per_second = M::units_per_second(&asset_id).ok_or({
-                    log::debug!(
-                        target: "FirstAssetTrader::buy_weight",
-                        "units_per_second missing for asset with id: {:?}",
-                        id,
-                    );
-                    XcmError::TooExpensive
-                })?;
-
-                let amount = units_per_second * (weight as u128) / (WEIGHT_PER_SECOND as u128);
-                // we don't need to proceed if amount is zero.
-                // This is very useful in tests.
-                if amount.is_zero() {
-                    return Ok(payment);
-                }
-                let required = MultiAsset {
-                    fun: Fungibility::Fungible(amount),
-                    id: XcmAssetId::Concrete(id.clone()),
-                };
-
-                log::debug!(
-                    target: "FirstAssetTrader::buy_weight",
-                    "payment: {:?}, required: {:?}",
-                    payment,
-                    required,
-                );
-                let unused = payment.checked_sub(required).map_err(|_| {
-                    log::debug!(
-                        target: "FirstAssetTrader::buy_weight",
-                        "not enough required assets in payment",
-                    );
-                    XcmError::TooExpensive
-                })?;
-                self.weight = self.weight.saturating_add(weight);
-
-                // In case the asset matches the one the trader already stored before, add
-                // to later refund
-
-                // Else we are always going to subtract the weight if we can, but we latter do
-                // not refund it
-
-                // In short, we only refund on the asset the trader first successfully was able
-                // to pay for an execution
-                let new_asset = match &self.refund_cache {
-                    Some((prev_id, prev_amount, units_per_second)) => {
-                        if prev_id == &id {
-                            Some((id, prev_amount.saturating_add(amount), *units_per_second))
-                        } else {
-                            None
-                        }
-                    }
-                    None => Some((id, amount, units_per_second)),
-                };
-
-                // Due to the trait bound, we can only refund one asset.
-                if let Some(new_asset) = new_asset {
-                    self.weight = self.weight.saturating_add(weight);
-                    self.refund_cache = Some(new_asset);
-                };
-                Ok(unused)
-            }
-            _ => {
+            return Err(XcmError::TooExpensive);
+        }
+        // The sort is stable, so assets without a preference stay in the order of the holding.
+        let preferred = P::get();
+        candidates.sort_by_key(|id| {
+            preferred
+                .iter()
+                .position(|location| location == id)
+                .unwrap_or(preferred.len())
+        });
+
+        for id in candidates {
+            if let Ok(unused) = self.buy_weight_with(weight, &payment, id.clone()) {
                 log::debug!(
                     target: "FirstAssetTrader::buy_weight",
-                    "no matching XcmAssetId for first_asset in payment: {:?}",
-                    payment,
+                    "bought weight: {:?} with asset with id: {:?}",
+                    weight,
+                    id,
                 );
-                Err(XcmError::TooExpensive)
+                return Ok(unused);
             }
         }
+        log::debug!(
+            target: "FirstAssetTrader::buy_weight",
+            "no asset in payment can cover weight: {:?}",
+            weight,
+        );
+        Err(XcmError::TooExpensive)
     }
 
+    /// Refunds unused weight in the asset of the latest purchase which still has weight left.
     ///
+    /// # Limitation
+    ///
+    /// [`WeightTrader::refund_weight`] returns a single asset, so at most the weight bought with
+    /// the latest purchase is refunded per call, and the weight is not refunded proportionally
+    /// across the purchases. The executor counts the whole surplus as refunded by a
+    /// `RefundSurplus` instruction, so surplus weight beyond that purchase is kept as fee
+    /// revenue.
     #[inline]
     fn refund_weight(&mut self, weight: Weight) -> Option<MultiAsset> {
-        if let Some((id, prev_amount, units_per_second)) = &mut self.refund_cache {
-            let weight = weight.min(self.weight);
-            self.weight -= weight;
-            let amount = *units_per_second * (weight as u128) / (WEIGHT_PER_SECOND as u128);
-            *prev_amount = prev_amount.saturating_sub(amount);
-            Some(MultiAsset {
-                fun: Fungibility::Fungible(amount),
-                id: XcmAssetId::Concrete(id.clone()),
-            })
-        } else {
-            None
-        }
+        let purchase = self
+            .purchases
+            .iter_mut()
+            .rev()
+            .find(|purchase| !purchase.weight.is_zero())?;
+        let weight = weight.min(purchase.weight);
+        purchase.weight -= weight;
+        self.weight = self.weight.saturating_sub(weight);
+        let amount = (purchase.units_per_second * (weight as u128) / (WEIGHT_PER_SECOND as u128))
+            .min(purchase.amount);
+        purchase.amount -= amount;
+        Some(MultiAsset {
+            fun: Fungibility::Fungible(amount),
+            id: XcmAssetId::Concrete(purchase.location.clone()),
+        })
     }
 }
 
-impl<M, R> Drop for FirstAssetTrader<M, R>
+impl<M, U, R, P, S> Drop for FirstAssetTrader<M, U, R, P, S>
 where
     R: TakeRevenue,
 {
     /// Handles spent fees, depositing them as defined by `R`.
     #[inline]
     fn drop(&mut self) {
-        if let Some((id, amount, _)) = &self.refund_cache {
-            R::take_revenue((id.clone(), *amount).into());
+        for purchase in &self.purchases {
+            if !purchase.amount.is_zero() {
+                R::take_revenue((purchase.location.clone(), purchase.amount).into());
+            }
         }
     }
 }
@@ -301,6 +392,299 @@ where
     }
 }
 
+/// XCM Fee Split
+///
+/// Shares of the XCM fee revenue which are deposited to the treasury, deposited to the block
+/// author, and burned. A valid split has shares which add up to exactly one hundred percent and
+/// does not burn all of the revenue.
+#[derive(Clone, Copy, Debug, Decode, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub struct FeeSplit {
+    /// Treasury Share
+    pub treasury: Percent,
+
+    /// Block Author Share
+    pub author: Percent,
+
+    /// Burned Share
+    pub burn: Percent,
+}
+
+impl FeeSplit {
+    /// Builds a new [`FeeSplit`] from the `treasury`, `author`, and `burn` percentages.
+    #[inline]
+    pub fn new(treasury: u8, author: u8, burn: u8) -> Self {
+        Self {
+            treasury: Percent::from_percent(treasury),
+            author: Percent::from_percent(author),
+            burn: Percent::from_percent(burn),
+        }
+    }
+
+    /// Returns `true` if the shares of `self` add up to exactly one hundred percent and some of
+    /// the revenue is kept by the treasury or the block author.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        let total = u16::from(self.treasury.deconstruct())
+            + u16::from(self.author.deconstruct())
+            + u16::from(self.burn.deconstruct());
+        total == 100 && self.burn.deconstruct() < 100
+    }
+}
+
+impl Default for FeeSplit {
+    /// Sends all of the fee revenue to the treasury.
+    #[inline]
+    fn default() -> Self {
+        Self::new(100, 0, 0)
+    }
+}
+
+/// XCM Fee Splitter
+///
+/// Splits the fee revenue of the native asset, matched by `N`, and of the non-native assets,
+/// matched by `M`, according to the [`FeeSplit`] returned by `S`. The treasury share is minted to
+/// the account returned by `T` and the author share to the block author returned by `B`. The
+/// burned share is never minted. If there is no block author, its share is burned as well.
+pub struct XcmFeesSplit<C, A, N, M, S, T, B>(PhantomData<(C, A, N, M, S, T, B)>);
+
+impl<C, A, N, M, S, T, B> XcmFeesSplit<C, A, N, M, S, T, B>
+where
+    C: Config,
+    A: AssetConfig<C>,
+{
+    /// Mints `amount` of `asset_id` to `account`, logging any failure.
+    #[inline]
+    fn deposit(asset_id: A::AssetId, account: &C::AccountId, amount: A::Balance) {
+        if amount.is_zero() {
+            return;
+        }
+        if let Err(err) = A::FungibleLedger::deposit_minting(asset_id, account, amount) {
+            log::debug!(target: "manta-xcm", "deposit_minting failed with {:?}", err);
+        }
+    }
+}
+
+impl<C, A, N, M, S, T, B> TakeRevenue for XcmFeesSplit<C, A, N, M, S, T, B>
+where
+    C: Config,
+    A: AssetConfig<C>,
+    A::AssetId: Clone,
+    A::Balance: AtLeast32BitUnsigned + Copy,
+    N: MatchesFungible<A::Balance>,
+    M: MatchesFungibles<A::AssetId, A::Balance>,
+    S: Get<FeeSplit>,
+    T: Get<C::AccountId>,
+    B: Get<Option<C::AccountId>>,
+{
+    #[inline]
+    fn take_revenue(revenue: MultiAsset) {
+        let (asset_id, amount) = match (
+            N::matches_fungible(&revenue),
+            M::matches_fungibles(&revenue),
+        ) {
+            (Some(amount), _) => (A::NativeAssetId::get(), amount),
+            (_, Ok((asset_id, amount))) => (asset_id, amount),
+            _ => {
+                log::debug!(target: "manta-xcm", "take revenue failed matching fungible");
+                return;
+            }
+        };
+        if amount.is_zero() {
+            return;
+        }
+        let mut split = S::get();
+        if !split.is_valid() {
+            log::warn!(target: "manta-xcm", "invalid fee split {:?}, using default", split);
+            split = FeeSplit::default();
+        }
+        Self::deposit(
+            asset_id.clone(),
+            &T::get(),
+            split.treasury.mul_floor(amount),
+        );
+        if let Some(author) = B::get() {
+            Self::deposit(asset_id, &author, split.author.mul_floor(amount));
+        }
+    }
+}
+
+/// XCM Rate Limit Verdict
+///
+/// Decision of an [`XcmRateLimiter`] on an incoming message.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum XcmRateLimitVerdict {
+    /// The message is within the budget of its origin and is executed.
+    Execute,
+
+    /// The message exceeds what is left of the budget of its origin and is kept for a later
+    /// window.
+    Defer,
+
+    /// The message exceeds the budget of its origin for a whole window and is never executed
+    /// under the current rate limit.
+    Overweight,
+}
+
+/// XCM Rate Limiter
+pub trait XcmRateLimiter {
+    /// Notes an incoming message from `origin` of the given `weight`, returning whether it can be
+    /// executed within the budget of `origin`.
+    fn note_message(origin: &MultiLocation, weight: Weight) -> XcmRateLimitVerdict;
+
+    /// Returns the worst-case weight of [`note_message`](Self::note_message).
+    fn note_message_weight() -> Weight;
+}
+
+/// Rate Limited Executor
+///
+/// Wraps the XCM executor `E` so that messages, weighed with `W`, are only executed as long as
+/// their origin is still within its budget in the rate limiter `L`. Messages over the budget fail
+/// with [`WeightLimitReached`](XcmError::WeightLimitReached) before any of their instructions is
+/// executed, which makes the message queues keep them for a later block instead of dropping them.
+/// Messages which do not fit in the budget of a whole window report the maximum weight, so the
+/// message queues move them to their overweight queue right away. Noting the message costs the
+/// weight given by `L` on top of the weight of the message itself.
+pub struct RateLimitedExecutor<L, E, W>(PhantomData<(L, E, W)>);
+
+impl<L, E, W, Call> ExecuteXcm<Call> for RateLimitedExecutor<L, E, W>
+where
+    L: XcmRateLimiter,
+    E: ExecuteXcm<Call>,
+    W: WeightBounds<Call>,
+{
+    #[inline]
+    fn execute_xcm_in_credit(
+        origin: impl Into<MultiLocation>,
+        mut message: Xcm<Call>,
+        weight_limit: Weight,
+        weight_credit: Weight,
+    ) -> Outcome {
+        let origin = origin.into();
+        let note_weight = L::note_message_weight();
+        let message_weight = match W::weight(&mut message) {
+            Ok(weight) => weight,
+            _ => return Outcome::Error(XcmError::WeightNotComputable),
+        };
+        let weight = message_weight.saturating_add(note_weight);
+        if weight > weight_limit {
+            return Outcome::Error(XcmError::WeightLimitReached(weight));
+        }
+        match L::note_message(&origin, message_weight) {
+            XcmRateLimitVerdict::Execute => {}
+            XcmRateLimitVerdict::Defer => {
+                log::debug!(
+                    target: "manta-xcm",
+                    "deferred message from {:?} with weight {:?}",
+                    origin, message_weight,
+                );
+                return Outcome::Error(XcmError::WeightLimitReached(weight));
+            }
+            XcmRateLimitVerdict::Overweight => {
+                log::debug!(
+                    target: "manta-xcm",
+                    "overweight message from {:?} with weight {:?}",
+                    origin, message_weight,
+                );
+                return Outcome::Error(XcmError::WeightLimitReached(Weight::MAX));
+            }
+        }
+        match E::execute_xcm_in_credit(origin, message, weight_limit - note_weight, weight_credit) {
+            Outcome::Complete(weight) => Outcome::Complete(weight.saturating_add(note_weight)),
+            Outcome::Incomplete(weight, error) => {
+                Outcome::Incomplete(weight.saturating_add(note_weight), error)
+            }
+            Outcome::Error(XcmError::WeightLimitReached(weight)) => Outcome::Error(
+                XcmError::WeightLimitReached(weight.saturating_add(note_weight)),
+            ),
+            // The message was noted against the budget of its origin, so noting it is charged.
+            Outcome::Error(error) => Outcome::Incomplete(note_weight, error),
+        }
+    }
+}
+
+/// Filtered Transact Trap Code
+///
+/// Code of the [`XcmError::Trap`] reported for the `Transact` instructions rejected by
+/// [`FilteredTransactExecutor`]. It spells `filtered` in ASCII.
+pub const FILTERED_TRANSACT_TRAP_CODE: u64 = u64::from_be_bytes(*b"filtered");
+
+/// Filtered Transact Executor
+///
+/// Wraps the XCM executor `E` so that every `Transact` instruction, including the ones in error
+/// handlers and appendices, whose call is not allowed by `F` is replaced by a
+/// [`Trap`](Instruction::Trap) with [`FILTERED_TRANSACT_TRAP_CODE`]. The message then fails with an
+/// error the origin can read back with `ReportError`. Checking each `Transact` instruction costs
+/// the weight given by `W` on top of the weight of the message itself.
+pub struct FilteredTransactExecutor<E, F, W>(PhantomData<(E, F, W)>);
+
+impl<E, F, W> FilteredTransactExecutor<E, F, W> {
+    /// Replaces the `Transact` instructions of `message` whose call is not allowed by `F`,
+    /// returning the number of `Transact` instructions that were checked.
+    #[inline]
+    fn filter<Call>(message: &mut Xcm<Call>) -> u64
+    where
+        Call: Decode,
+        F: Contains<Call>,
+    {
+        let mut checked = 0u64;
+        for instruction in message.0.iter_mut() {
+            match instruction {
+                Instruction::Transact { call, .. } => {
+                    checked = checked.saturating_add(1);
+                    // NOTE: Calls which cannot be decoded are left to fail in the executor.
+                    let allowed = match call.ensure_decoded() {
+                        Ok(call) => F::contains(call),
+                        _ => true,
+                    };
+                    if !allowed {
+                        *instruction = Instruction::Trap(FILTERED_TRANSACT_TRAP_CODE);
+                    }
+                }
+                Instruction::SetErrorHandler(xcm) | Instruction::SetAppendix(xcm) => {
+                    checked = checked.saturating_add(Self::filter(xcm));
+                }
+                _ => {}
+            }
+        }
+        checked
+    }
+}
+
+impl<E, F, W, Call> ExecuteXcm<Call> for FilteredTransactExecutor<E, F, W>
+where
+    E: ExecuteXcm<Call>,
+    F: Contains<Call>,
+    W: Get<Weight>,
+    Call: Decode,
+{
+    #[inline]
+    fn execute_xcm_in_credit(
+        origin: impl Into<MultiLocation>,
+        mut message: Xcm<Call>,
+        weight_limit: Weight,
+        weight_credit: Weight,
+    ) -> Outcome {
+        let filter_weight = W::get().saturating_mul(Self::filter(&mut message));
+        let weight_limit = match weight_limit.checked_sub(filter_weight) {
+            Some(weight_limit) => weight_limit,
+            None => return Outcome::Error(XcmError::WeightLimitReached(filter_weight)),
+        };
+        match E::execute_xcm_in_credit(origin, message, weight_limit, weight_credit) {
+            Outcome::Complete(weight) => Outcome::Complete(weight.saturating_add(filter_weight)),
+            Outcome::Incomplete(weight, error) => {
+                Outcome::Incomplete(weight.saturating_add(filter_weight), error)
+            }
+            Outcome::Error(XcmError::WeightLimitReached(weight)) => Outcome::Error(
+                XcmError::WeightLimitReached(weight.saturating_add(filter_weight)),
+            ),
+            // The `Transact` instructions were checked even though the message failed, so checking
+            // them is charged.
+            Outcome::Error(error) if filter_weight.is_zero() => Outcome::Error(error),
+            Outcome::Error(error) => Outcome::Incomplete(filter_weight, error),
+        }
+    }
+}
+
 /// Manta's `MatchFungible` implementation.
 /// It resolves the reanchoring logic as well, i.e. it recognize `here()` as
 /// `../parachain(id)`.
@@ -324,13 +708,103 @@ where
     }
 }
 
+/// Native Teleport Filter
+///
+/// Accepts teleports of the native asset, matched as in [`IsNativeConcrete`] with the self location
+/// `S`, from the trusted origins in `C`. Teleports of any other asset are rejected.
+pub struct NativeTeleportFrom<S, C>(PhantomData<(S, C)>);
+
+impl<S, C> FilterAssetLocation for NativeTeleportFrom<S, C>
+where
+    S: Get<MultiLocation>,
+    C: Contains<MultiLocation>,
+{
+    #[inline]
+    fn filter_asset_location(asset: &MultiAsset, origin: &MultiLocation) -> bool {
+        <IsNativeConcrete<S> as MatchesFungible<u128>>::matches_fungible(asset).is_some()
+            && C::contains(origin)
+    }
+}
+
+/// Native Teleport Asset Filter
 ///
-pub struct MultiAssetAdapter<T, A, AccountIdConverter, Native, NonNative>(
-    PhantomData<(T, A, AccountIdConverter, Native, NonNative)>,
+/// Filters the assets that local accounts may teleport out of this chain to the native asset,
+/// matched as in [`IsNativeConcrete`] with the self location `S`. The destination of the teleport
+/// is checked by [`TrustedTeleportExecutor`].
+pub struct NativeTeleportAssets<S>(PhantomData<S>);
+
+impl<S> Contains<(MultiLocation, Vec<MultiAsset>)> for NativeTeleportAssets<S>
+where
+    S: Get<MultiLocation>,
+{
+    #[inline]
+    fn contains((_, assets): &(MultiLocation, Vec<MultiAsset>)) -> bool {
+        !assets.is_empty()
+            && assets.iter().all(|asset| {
+                <IsNativeConcrete<S> as MatchesFungible<u128>>::matches_fungible(asset).is_some()
+            })
+    }
+}
+
+/// Trusted Teleport Executor
+///
+/// Wraps the XCM executor `E` so that messages which teleport assets with
+/// [`InitiateTeleport`](Instruction::InitiateTeleport) to a destination outside of the trusted
+/// locations in `C` fail with [`UntrustedTeleportLocation`](XcmError::UntrustedTeleportLocation)
+/// before any of their instructions is executed.
+pub struct TrustedTeleportExecutor<E, C>(PhantomData<(E, C)>);
+
+impl<E, C> TrustedTeleportExecutor<E, C>
+where
+    C: Contains<MultiLocation>,
+{
+    /// Returns `true` if every `InitiateTeleport` instruction of `message`, including the ones in
+    /// error handlers and appendices, teleports to a trusted destination.
+    #[inline]
+    fn is_trusted<Call>(message: &Xcm<Call>) -> bool {
+        message.0.iter().all(|instruction| match instruction {
+            Instruction::InitiateTeleport { dest, .. } => C::contains(dest),
+            Instruction::SetErrorHandler(xcm) | Instruction::SetAppendix(xcm) => {
+                Self::is_trusted(xcm)
+            }
+            _ => true,
+        })
+    }
+}
+
+impl<E, C, Call> ExecuteXcm<Call> for TrustedTeleportExecutor<E, C>
+where
+    E: ExecuteXcm<Call>,
+    C: Contains<MultiLocation>,
+{
+    #[inline]
+    fn execute_xcm_in_credit(
+        origin: impl Into<MultiLocation>,
+        message: Xcm<Call>,
+        weight_limit: Weight,
+        weight_credit: Weight,
+    ) -> Outcome {
+        if !Self::is_trusted(&message) {
+            return Outcome::Error(XcmError::UntrustedTeleportLocation);
+        }
+        E::execute_xcm_in_credit(origin, message, weight_limit, weight_credit)
+    }
+}
+
+/// Multi Asset Adapter
+///
+/// Transacts the native asset, matched by `Native`, and the non-native assets, matched by
+/// `NonNative`, through the [`FungibleLedger`] of `A`.
+///
+/// If `CheckingAccount` returns an account, the native asset teleported out of this chain is
+/// deposited to it, and teleports back in are withdrawn from it, so that no more of the native
+/// asset can be teleported in than was teleported out.
+pub struct MultiAssetAdapter<T, A, AccountIdConverter, Native, NonNative, CheckingAccount = ()>(
+    PhantomData<(T, A, AccountIdConverter, Native, NonNative, CheckingAccount)>,
 );
 
-impl<T, A, AccountIdConverter, Native, NonNative>
-    MultiAssetAdapter<T, A, AccountIdConverter, Native, NonNative>
+impl<T, A, AccountIdConverter, Native, NonNative, CheckingAccount>
+    MultiAssetAdapter<T, A, AccountIdConverter, Native, NonNative, CheckingAccount>
 where
     T: Config,
     A: AssetConfig<T>,
@@ -365,8 +839,8 @@ where
     }
 }
 
-impl<T, A, AccountIdConverter, Native, NonNative> TransactAsset
-    for MultiAssetAdapter<T, A, AccountIdConverter, Native, NonNative>
+impl<T, A, AccountIdConverter, Native, NonNative, CheckingAccount> TransactAsset
+    for MultiAssetAdapter<T, A, AccountIdConverter, Native, NonNative, CheckingAccount>
 where
     T: Config,
     A: AssetConfig<T>,
@@ -375,7 +849,55 @@ where
     AccountIdConverter: XcmConvert<MultiLocation, T::AccountId>,
     Native: MatchesFungible<A::Balance>,
     NonNative: MatchesFungibles<A::AssetId, A::Balance>,
+    CheckingAccount: Get<Option<T::AccountId>>,
 {
+    #[inline]
+    fn can_check_in(_origin: &MultiLocation, what: &MultiAsset) -> Result {
+        let amount = Native::matches_fungible(what).ok_or(XcmError::AssetNotFound)?;
+        if let Some(checking_account) = CheckingAccount::get() {
+            // NOTE: The teleported supply cannot exceed what was teleported out before.
+            A::FungibleLedger::can_withdraw(
+                A::NativeAssetId::get(),
+                &checking_account,
+                &amount,
+                ExistenceRequirement::AllowDeath,
+            )
+            .map_err(|_| XcmError::NotWithdrawable)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn check_in(_origin: &MultiLocation, what: &MultiAsset) {
+        if let (Some(amount), Some(checking_account)) =
+            (Native::matches_fungible(what), CheckingAccount::get())
+        {
+            if let Err(err) = A::FungibleLedger::withdraw_burning(
+                A::NativeAssetId::get(),
+                &checking_account,
+                amount,
+                ExistenceRequirement::AllowDeath,
+            ) {
+                log::error!(target: "xcm::multi_asset_adapter", "check_in failed with {:?}", err);
+            }
+        }
+    }
+
+    #[inline]
+    fn check_out(_dest: &MultiLocation, what: &MultiAsset) {
+        if let (Some(amount), Some(checking_account)) =
+            (Native::matches_fungible(what), CheckingAccount::get())
+        {
+            if let Err(err) = A::FungibleLedger::deposit_minting(
+                A::NativeAssetId::get(),
+                &checking_account,
+                amount,
+            ) {
+                log::error!(target: "xcm::multi_asset_adapter", "check_out failed with {:?}", err);
+            }
+        }
+    }
+
     #[inline]
     fn deposit_asset(asset: &MultiAsset, location: &MultiLocation) -> Result {
         log::debug!(
@@ -384,6 +906,9 @@ where
             asset, location,
         );
         let (asset_id, who, amount) = Self::match_asset_and_location(asset, location)?;
+        if !A::AssetStatusMap::status(&asset_id).can_receive_over_xcm() {
+            return Err(XcmError::FailedToTransactAsset("Asset Deposits Suspended"));
+        }
         // NOTE: If it's non-native asset we want to check with increase in total supply. Otherwise
         //       it will just use false, as it is assumed the native asset supply cannot be changed.
         A::FungibleLedger::deposit_minting_with_check(asset_id, &who, amount, true)
@@ -398,6 +923,11 @@ where
             asset, location,
         );
         let (asset_id, who, amount) = Self::match_asset_and_location(asset, location)?;
+        if !A::AssetStatusMap::status(&asset_id).can_send_over_xcm() {
+            return Err(XcmError::FailedToTransactAsset(
+                "Asset Withdrawals Suspended",
+            ));
+        }
         A::FungibleLedger::withdraw_burning(
             asset_id,
             &who,
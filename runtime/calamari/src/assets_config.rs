@@ -15,22 +15,35 @@
 // along with Manta.  If not, see <http://www.gnu.org/licenses/>.
 
 use super::{
-    weights, xcm_config::SelfReserve, AssetManager, Assets, Balances, Event,
-    NativeTokenExistentialDeposit, Origin, Runtime,
+    currency::{deposit, KMA},
+    weights,
+    xcm_config::{EnsureSiblingParachain, ParaTokenPerSecond, SelfReserve},
+    AssetManager, Assets, Balances, CouncilCollective, EnsureRootOrMoreThanHalfCouncil, Event,
+    NativeTokenExistentialDeposit, Origin, Runtime, Treasury, Uniques,
 };
 
+use codec::{Compact, Decode, Encode};
 use manta_primitives::{
     assets::{
         AssetConfig, AssetIdType, AssetLocation, AssetRegistry, AssetRegistryMetadata,
-        AssetStorageMetadata, BalanceType, LocationType, NativeAndNonNative,
+        AssetStorageMetadata, BalanceType, FungibleAndNonFungible, LocationType,
+        NativeAndNonNative, PalletItemLocker,
     },
-    constants::{ASSET_MANAGER_PALLET_ID, CALAMARI_DECIMAL, MANTA_PAY_PALLET_ID},
-    types::{AccountId, Balance, CalamariAssetId},
+    constants::{
+        time::{DAYS, HOURS},
+        ASSET_MANAGER_PALLET_ID, CALAMARI_DECIMAL, MANTA_PAY_PALLET_ID,
+    },
+    types::{AccountId, Balance, BlockNumber, CalamariAssetId},
 };
 
-use frame_support::{pallet_prelude::DispatchResult, parameter_types, traits::ConstU32, PalletId};
+use frame_support::{
+    pallet_prelude::DispatchResult,
+    parameter_types,
+    traits::{fungibles, AsEnsureOriginWithArg, ConstU32},
+    PalletId,
+};
 
-use frame_system::EnsureRoot;
+use frame_system::{EnsureRoot, EnsureSigned};
 
 use xcm::VersionedMultiLocation;
 
@@ -60,6 +73,34 @@ impl pallet_assets::Config for Runtime {
     type WeightInfo = weights::pallet_assets::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+    pub const CollectionDeposit: Balance = 10 * KMA;
+    pub const ItemDeposit: Balance = deposit(1, 0);
+    pub const UniquesMetadataDepositBase: Balance = deposit(1, 129);
+    pub const AttributeDepositBase: Balance = deposit(1, 0);
+    pub const DepositPerByte: Balance = deposit(0, 1);
+    pub const UniquesStringLimit: u32 = 128;
+}
+
+impl pallet_uniques::Config for Runtime {
+    type Event = Event;
+    type CollectionId = u32;
+    type ItemId = u32;
+    type Currency = Balances;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+    type Locker = PalletItemLocker<MantaPayPalletId, AccountId, Uniques>;
+    type CollectionDeposit = CollectionDeposit;
+    type ItemDeposit = ItemDeposit;
+    type MetadataDepositBase = UniquesMetadataDepositBase;
+    type AttributeDepositBase = AttributeDepositBase;
+    type DepositPerByte = DepositPerByte;
+    type StringLimit = UniquesStringLimit;
+    type KeyLimit = ConstU32<32>;
+    type ValueLimit = ConstU32<64>;
+    type WeightInfo = pallet_uniques::weights::SubstrateWeight<Runtime>;
+}
+
 pub struct CalamariAssetRegistry;
 impl BalanceType for CalamariAssetRegistry {
     type Balance = Balance;
@@ -108,6 +149,38 @@ impl AssetRegistry for CalamariAssetRegistry {
             metadata.is_frozen,
         )
     }
+
+    fn total_supply(asset_id: &CalamariAssetId) -> Balance {
+        <Assets as fungibles::Inspect<AccountId>>::total_issuance(*asset_id)
+    }
+
+    fn accounts_and_approvals(asset_id: &CalamariAssetId) -> (u32, u32) {
+        // NOTE: The fields of `DestroyWitness` are private, but it is encoded as the compact
+        //       numbers of accounts, sufficient accounts and approvals of the asset.
+        <Assets as fungibles::Destroy<AccountId>>::get_destroy_witness(asset_id)
+            .and_then(|witness| {
+                <(Compact<u32>, Compact<u32>, Compact<u32>)>::decode(
+                    &mut witness.encode().as_slice(),
+                )
+                .ok()
+            })
+            .map(|(accounts, _, approvals)| (accounts.0, approvals.0))
+            .unwrap_or_default()
+    }
+
+    fn freeze_asset(asset_id: &CalamariAssetId) -> DispatchResult {
+        Assets::freeze_asset(Origin::signed(AssetManager::account_id()), *asset_id)
+    }
+
+    fn destroy_asset(asset_id: &CalamariAssetId) -> DispatchResult {
+        // NOTE: `DestroyWitness` can only be built by decoding it. An empty witness makes
+        //       `pallet_assets` refuse the destruction while any accounts or approvals remain.
+        let witness = pallet_assets::DestroyWitness::decode(&mut [0u8; 3].as_slice())
+            .map_err(|_| sp_runtime::DispatchError::Other("Invalid destroy witness"))?;
+        Assets::destroy(Origin::root(), *asset_id, witness)
+            .map(|_| ())
+            .map_err(|err| err.error)
+    }
 }
 
 parameter_types! {
@@ -128,8 +201,11 @@ parameter_types! {
     pub const AssetManagerPalletId: PalletId = ASSET_MANAGER_PALLET_ID;
 }
 
-pub type CalamariConcreteFungibleLedger =
-    NativeAndNonNative<Runtime, CalamariAssetConfig, Balances, Assets>;
+pub type CalamariConcreteFungibleLedger = FungibleAndNonFungible<
+    NativeAndNonNative<Runtime, CalamariAssetConfig, Balances, Assets>,
+    AssetManager,
+    Uniques,
+>;
 
 /// AssetConfig implementations for this runtime
 #[derive(Clone, Eq, PartialEq)]
@@ -152,15 +228,36 @@ impl AssetConfig<Runtime> for CalamariAssetConfig {
     type StorageMetadata = AssetStorageMetadata;
     type AssetRegistry = CalamariAssetRegistry;
     type FungibleLedger = CalamariConcreteFungibleLedger;
+    type AssetStatusMap = AssetManager;
+}
+
+parameter_types! {
+    pub const AssetProposalDeposit: Balance = 1_000 * KMA;
+    pub const AssetProposalLifetime: BlockNumber = 7 * DAYS;
 }
 
+/// Mint caps limit what root may mint, so only a council supermajority can change them.
+pub type MintCapOrigin =
+    pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 3, 4>;
+
 impl pallet_asset_manager::Config for Runtime {
     type Event = Event;
     type AssetId = CalamariAssetId;
     type Balance = Balance;
     type Location = AssetLocation;
     type AssetConfig = CalamariAssetConfig;
+    type CollectionId = u32;
+    type ItemId = u32;
+    type NonFungibles = Uniques;
     type ModifierOrigin = EnsureRoot<AccountId>;
+    type MintCapOrigin = MintCapOrigin;
+    type MaxLocationAliases = ConstU32<4>;
+    type Currency = Balances;
+    type ProposalDeposit = AssetProposalDeposit;
+    type ProposalLifetime = AssetProposalLifetime;
+    type OnProposalSlash = Treasury;
+    type SiblingOrigin = EnsureSiblingParachain;
+    type MaxSiblingRequests = ConstU32<4>;
     type PalletId = AssetManagerPalletId;
     type WeightInfo = weights::pallet_asset_manager::SubstrateWeight<Runtime>;
 }
@@ -174,4 +271,22 @@ impl pallet_manta_pay::Config for Runtime {
     type WeightInfo = weights::pallet_manta_pay::SubstrateWeight<Runtime>;
     type AssetConfig = CalamariAssetConfig;
     type PalletId = MantaPayPalletId;
+    type UnshieldLimitOrigin = EnsureRootOrMoreThanHalfCouncil;
+}
+
+parameter_types! {
+    pub NativeUnitsPerSecond: u128 = ParaTokenPerSecond::get().1;
+    pub const MaxPriceAge: BlockNumber = HOURS;
+}
+
+impl pallet_price_feed::Config for Runtime {
+    type Event = Event;
+    type AssetId = CalamariAssetId;
+    type ManualUnitsPerSecond = AssetManager;
+    type NativeUnitsPerSecond = NativeUnitsPerSecond;
+    type FeederOrigin = EnsureRootOrMoreThanHalfCouncil;
+    type MaxFeeders = ConstU32<16>;
+    type MinimumFeeds = ConstU32<3>;
+    type MaxPriceAge = MaxPriceAge;
+    type WeightInfo = weights::pallet_price_feed::SubstrateWeight<Runtime>;
 }
@@ -15,13 +15,19 @@
 // along with Manta.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::{
+    assets_config::CalamariAssetConfig,
     fee::{
         FEES_PERCENTAGE_TO_AUTHOR, FEES_PERCENTAGE_TO_BURN, FEES_PERCENTAGE_TO_TREASURY,
         TIPS_PERCENTAGE_TO_AUTHOR, TIPS_PERCENTAGE_TO_TREASURY,
     },
-    Authorship, Balances, NegativeImbalance, Treasury,
+    Authorship, Balances, NegativeImbalance, Runtime, Treasury,
 };
 use frame_support::traits::{Currency, Imbalance, OnUnbalanced};
+use manta_primitives::{
+    assets::{AssetConfig, FungibleLedger},
+    types::{AccountId, Balance, CalamariAssetId},
+};
+use runtime_common::OnChargeAssetFees;
 use sp_arithmetic::Percent;
 
 pub struct Author;
@@ -70,3 +76,40 @@ impl OnUnbalanced<NegativeImbalance> for DealWithFees {
         }
     }
 }
+
+/// Splits the fees paid in non-native assets like [`DealWithFees`] splits the native ones.
+pub struct DealWithAssetFees;
+impl OnChargeAssetFees<CalamariAssetId, Balance> for DealWithAssetFees {
+    fn on_asset_fees(asset_id: CalamariAssetId, fees: Balance, tips: Balance) {
+        let to_author = Percent::from_percent(FEES_PERCENTAGE_TO_AUTHOR) * fees
+            + Percent::from_percent(TIPS_PERCENTAGE_TO_AUTHOR) * tips;
+        let to_treasury = Percent::from_percent(FEES_PERCENTAGE_TO_TREASURY) * fees
+            + Percent::from_percent(TIPS_PERCENTAGE_TO_TREASURY) * tips;
+        // NOTE: The fees were burned from the payer, so the remaining share stays burned, and so do
+        //       shares which cannot be deposited because they are below the minimum balance.
+        if let Some(author) = Authorship::author() {
+            deposit_asset_fees(asset_id, &author, to_author);
+        }
+        deposit_asset_fees(asset_id, &Treasury::account_id(), to_treasury);
+    }
+}
+
+/// Mints the `amount` of `asset_id` paid in fees to `account`, logging dropped shares.
+#[inline]
+fn deposit_asset_fees(asset_id: CalamariAssetId, account: &AccountId, amount: Balance) {
+    if amount == 0 {
+        return;
+    }
+    if let Err(err) = <CalamariAssetConfig as AssetConfig<Runtime>>::FungibleLedger::deposit_minting(
+        asset_id, account, amount,
+    ) {
+        log::debug!(
+            target: "asset-tx-payment",
+            "Dropped fee share of {} of asset {} for {:?}: {:?}",
+            amount,
+            asset_id,
+            account,
+            err
+        );
+    }
+}
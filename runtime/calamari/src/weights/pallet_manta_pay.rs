@@ -49,6 +49,7 @@ pub trait WeightInfo {
     fn to_public() -> Weight;
     fn private_transfer() -> Weight;
     fn public_transfer() -> Weight;
+    fn set_unshield_limit() -> Weight;
 }
 
 /// Weights for pallet_manta_pay using the Substrate node and recommended hardware.
@@ -73,12 +74,14 @@ impl<T: frame_system::Config> pallet_manta_pay::WeightInfo for SubstrateWeight<T
     // Storage: MantaPay UtxoSet (r:1 w:1)
     // Storage: MantaPay NullifierSetSize (r:1 w:1)
     // Storage: MantaPay ShardTrees (r:1 w:1)
+    // Storage: MantaPay UnshieldLimits (r:1 w:0)
+    // Storage: MantaPay UnshieldWindows (r:1 w:1)
     // Storage: MantaPay NullifierSetInsertionOrder (r:0 w:2)
     // Storage: MantaPay Shards (r:0 w:1)
     fn to_public() -> Weight {
         (52_859_436_000 as Weight)
-            .saturating_add(T::DbWeight::get().reads(10 as Weight))
-            .saturating_add(T::DbWeight::get().writes(12 as Weight))
+            .saturating_add(T::DbWeight::get().reads(12 as Weight))
+            .saturating_add(T::DbWeight::get().writes(13 as Weight))
     }
     // Storage: MantaPay UtxoAccumulatorOutputs (r:2 w:2)
     // Storage: MantaPay NullifierCommitmentSet (r:2 w:2)
@@ -99,6 +102,15 @@ impl<T: frame_system::Config> pallet_manta_pay::WeightInfo for SubstrateWeight<T
             .saturating_add(T::DbWeight::get().reads(3 as Weight))
             .saturating_add(T::DbWeight::get().writes(3 as Weight))
     }
+    // Storage: AssetManager AssetIdLocation (r:1 w:0)
+    // Storage: AssetManager AssetIdNonFungible (r:1 w:0)
+    // Storage: MantaPay UnshieldLimits (r:0 w:1)
+    // Storage: MantaPay UnshieldWindows (r:0 w:1)
+    fn set_unshield_limit() -> Weight {
+        (17_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
 }
 
 // For backwards compatibility and tests
@@ -122,12 +134,14 @@ impl WeightInfo for () {
     // Storage: MantaPay UtxoSet (r:1 w:1)
     // Storage: MantaPay NullifierSetSize (r:1 w:1)
     // Storage: MantaPay ShardTrees (r:1 w:1)
+    // Storage: MantaPay UnshieldLimits (r:1 w:0)
+    // Storage: MantaPay UnshieldWindows (r:1 w:1)
     // Storage: MantaPay NullifierSetInsertionOrder (r:0 w:2)
     // Storage: MantaPay Shards (r:0 w:1)
     fn to_public() -> Weight {
         (52_859_436_000 as Weight)
-            .saturating_add(RocksDbWeight::get().reads(10 as Weight))
-            .saturating_add(RocksDbWeight::get().writes(12 as Weight))
+            .saturating_add(RocksDbWeight::get().reads(12 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(13 as Weight))
     }
     // Storage: MantaPay UtxoAccumulatorOutputs (r:2 w:2)
     // Storage: MantaPay NullifierCommitmentSet (r:2 w:2)
@@ -148,4 +162,13 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(3 as Weight))
             .saturating_add(RocksDbWeight::get().writes(3 as Weight))
     }
+    // Storage: AssetManager AssetIdLocation (r:1 w:0)
+    // Storage: AssetManager AssetIdNonFungible (r:1 w:0)
+    // Storage: MantaPay UnshieldLimits (r:0 w:1)
+    // Storage: MantaPay UnshieldWindows (r:0 w:1)
+    fn set_unshield_limit() -> Weight {
+        (17_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
+    }
 }
@@ -56,8 +56,9 @@ impl<Call> XcmWeightInfo<Call> for CalamariXcmWeight<Call> {
     fn reserve_asset_deposited(assets: &MultiAssets) -> Weight {
         assets.weigh_multi_assets(XcmFungibleWeight::<Runtime>::reserve_asset_deposited())
     }
-    fn receive_teleported_asset(_assets: &MultiAssets) -> Weight {
-        u64::MAX // disable teleport
+    fn receive_teleported_asset(assets: &MultiAssets) -> Weight {
+        // Only the native asset is accepted, from the locations trusted in `AssetManager`.
+        assets.weigh_multi_assets(XcmFungibleWeight::<Runtime>::receive_teleported_asset())
     }
     fn query_response(_query_id: &u64, _response: &Response, _max_weight: &u64) -> Weight {
         XcmGeneric::<Runtime>::query_response()
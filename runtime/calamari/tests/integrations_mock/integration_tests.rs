@@ -25,11 +25,12 @@ pub use calamari_runtime::{
     assets_config::{CalamariAssetConfig, CalamariConcreteFungibleLedger},
     currency::KMA,
     fee::{FEES_PERCENTAGE_TO_AUTHOR, FEES_PERCENTAGE_TO_TREASURY},
+    impls::DealWithAssetFees,
     xcm_config::{XcmExecutorConfig, XcmFeesAccount},
     AssetManager, Assets, Authorship, Balances, CalamariVesting, Council, DefaultBlocksPerRound,
     Democracy, EnactmentPeriod, Event, Get, LaunchPeriod, LeaveDelayRounds,
-    NativeTokenExistentialDeposit, Origin, ParachainStaking, Period, PolkadotXcm, Runtime,
-    TechnicalCommittee, Timestamp, TransactionPause, Treasury, Utility, VotingPeriod,
+    NativeTokenExistentialDeposit, Origin, ParachainStaking, Period, PolkadotXcm, PriceFeed,
+    Runtime, TechnicalCommittee, Timestamp, TransactionPause, Treasury, Utility, VotingPeriod,
 };
 
 use calamari_runtime::opaque::SessionKeys;
@@ -38,14 +39,18 @@ use frame_support::{
     codec::Encode,
     dispatch::Dispatchable,
     traits::{tokens::ExistenceRequirement, PalletInfo, StorageInfo, StorageInfoTrait},
+    weights::{Pays, PostDispatchInfo, WeightToFee},
     StorageHasher, Twox128,
 };
 use manta_primitives::{
     assets::{
-        AssetConfig, AssetLocation, AssetRegistryMetadata, AssetStorageMetadata, FungibleLedger,
-        FungibleLedgerError,
+        AssetConfig, AssetLocation, AssetRegistryMetadata, AssetStatus, AssetStorageMetadata,
+        FungibleLedger, FungibleLedgerError,
+    },
+    constants::{
+        time::{DAYS, HOURS},
+        WEIGHT_PER_SECOND,
     },
-    constants::time::{DAYS, HOURS},
     types::{AccountId, Balance, CalamariAssetId, Header},
 };
 use session_key_primitives::util::unchecked_account_id;
@@ -61,10 +66,12 @@ use xcm_executor::traits::WeightBounds;
 
 use nimbus_primitives::NIMBUS_ENGINE_ID;
 use pallet_transaction_payment::ChargeTransactionPayment;
+use runtime_common::ChargeAssetTransactionPayment;
 use sp_core::{sr25519, H256};
 use sp_runtime::{
     generic::DigestItem,
     traits::{BadOrigin, BlakeTwo256, Hash, Header as HeaderT, SignedExtension},
+    transaction_validity::InvalidTransaction,
     DispatchError, ModuleError, Percent,
 };
 
@@ -530,6 +537,236 @@ fn reward_fees_to_block_author_and_treasury() {
         });
 }
 
+#[test]
+fn fees_can_be_paid_in_registered_assets() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            (ALICE.clone(), INITIAL_BALANCE),
+            (BOB.clone(), INITIAL_BALANCE),
+            (CHARLIE.clone(), INITIAL_BALANCE),
+        ])
+        .with_authorities(vec![(
+            ALICE.clone(),
+            SessionKeys::from_seed_unchecked("Alice"),
+        )])
+        .build()
+        .execute_with(|| {
+            let author = ALICE.clone();
+            let mut header = seal_header(
+                Header::new(
+                    0,
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                ),
+                author.clone(),
+            );
+
+            header.digest_mut().pop(); // pop the seal off.
+            calamari_runtime::System::initialize(&1, &Default::default(), header.digest());
+            assert_eq!(Authorship::author().unwrap(), author);
+
+            let asset_id =
+                <CalamariAssetConfig as AssetConfig<Runtime>>::StartNonNativeAssetId::get();
+            let asset_metadata = AssetRegistryMetadata {
+                metadata: AssetStorageMetadata {
+                    name: b"Kusama".to_vec(),
+                    symbol: b"KSM".to_vec(),
+                    decimals: 12,
+                    is_frozen: false,
+                },
+                min_balance: 10u128,
+                is_sufficient: true,
+            };
+            assert_ok!(AssetManager::register_asset(
+                root_origin(),
+                AssetLocation(VersionedMultiLocation::V1(MultiLocation::parent())),
+                asset_metadata
+            ));
+            // Price the asset at twice the native fee of one second of weight.
+            let native_per_second =
+                <Runtime as pallet_transaction_payment::Config>::WeightToFee::weight_to_fee(
+                    &WEIGHT_PER_SECOND,
+                );
+            assert_ok!(AssetManager::set_units_per_second(
+                root_origin(),
+                asset_id,
+                2 * native_per_second
+            ));
+            let minted = 1_000 * KMA;
+            assert_ok!(CalamariConcreteFungibleLedger::deposit_minting(
+                asset_id, &BOB, minted,
+            ));
+
+            let call = Call::Balances(pallet_balances::Call::transfer {
+                dest: sp_runtime::MultiAddress::Id(CHARLIE.clone()),
+                value: 10 * KMA,
+            });
+
+            let len = 10;
+            let info = info_from_weight(100);
+            let maybe_pre = ChargeAssetTransactionPayment::<
+                Runtime,
+                CalamariAssetConfig,
+                PriceFeed,
+                DealWithAssetFees,
+            >::new(0, Some(asset_id))
+            .pre_dispatch(&BOB, &call, &info, len)
+            .unwrap();
+
+            let res = call.dispatch(Origin::signed(BOB.clone()));
+
+            let post_info = match res {
+                Ok(info) => info,
+                Err(err) => err.post_info,
+            };
+
+            assert_ok!(ChargeAssetTransactionPayment::<
+                Runtime,
+                CalamariAssetConfig,
+                PriceFeed,
+                DealWithAssetFees,
+            >::post_dispatch(
+                Some(maybe_pre),
+                &info,
+                &post_info,
+                len,
+                &res.map(|_| ()).map_err(|e| e.error),
+            ));
+
+            // The native balance only pays for the transfer itself.
+            assert_eq!(
+                Balances::free_balance(BOB.clone()),
+                INITIAL_BALANCE - 10 * KMA
+            );
+
+            // Fees split: 45% burned, 45% to treasury, 10% to author.
+            let native_fee =
+                TransactionPayment::compute_actual_fee(len as u32, &info, &post_info, 0);
+            System::assert_has_event(Event::TransactionPayment(
+                pallet_transaction_payment::Event::TransactionFeePaid {
+                    who: BOB.clone(),
+                    actual_fee: native_fee,
+                    tip: 0,
+                },
+            ));
+            let expected_fee = 2 * native_fee;
+            assert_eq!(
+                Assets::balance(asset_id, BOB.clone()),
+                minted - expected_fee
+            );
+            assert_eq!(
+                Assets::balance(asset_id, ALICE.clone()),
+                Percent::from_percent(FEES_PERCENTAGE_TO_AUTHOR) * expected_fee
+            );
+            assert_eq!(
+                Assets::balance(asset_id, Treasury::account_id()),
+                Percent::from_percent(FEES_PERCENTAGE_TO_TREASURY) * expected_fee
+            );
+        });
+}
+
+#[test]
+fn asset_fees_should_be_refunded_at_the_withdrawal_rate() {
+    type AssetPayment =
+        ChargeAssetTransactionPayment<Runtime, CalamariAssetConfig, PriceFeed, DealWithAssetFees>;
+    ExtBuilder::default()
+        .with_balances(vec![
+            (ALICE.clone(), INITIAL_BALANCE),
+            (BOB.clone(), INITIAL_BALANCE),
+        ])
+        .build()
+        .execute_with(|| {
+            let asset_id =
+                <CalamariAssetConfig as AssetConfig<Runtime>>::StartNonNativeAssetId::get();
+            let asset_metadata = AssetRegistryMetadata {
+                metadata: AssetStorageMetadata {
+                    name: b"Kusama".to_vec(),
+                    symbol: b"KSM".to_vec(),
+                    decimals: 12,
+                    is_frozen: false,
+                },
+                min_balance: 10u128,
+                is_sufficient: true,
+            };
+            assert_ok!(AssetManager::register_asset(
+                root_origin(),
+                AssetLocation(VersionedMultiLocation::V1(MultiLocation::parent())),
+                asset_metadata
+            ));
+            let native_per_second =
+                <Runtime as pallet_transaction_payment::Config>::WeightToFee::weight_to_fee(
+                    &WEIGHT_PER_SECOND,
+                );
+            assert_ok!(AssetManager::set_units_per_second(
+                root_origin(),
+                asset_id,
+                2 * native_per_second
+            ));
+            let minted = 1_000 * KMA;
+            assert_ok!(CalamariConcreteFungibleLedger::deposit_minting(
+                asset_id, &BOB, minted,
+            ));
+
+            let call = Call::Balances(pallet_balances::Call::transfer {
+                dest: sp_runtime::MultiAddress::Id(CHARLIE.clone()),
+                value: 10 * KMA,
+            });
+            let len = 10;
+            let info = info_from_weight(1_000_000);
+            let pre = AssetPayment::new(0, Some(asset_id))
+                .pre_dispatch(&BOB, &call, &info, len)
+                .unwrap();
+            let withdrawn = 2 * TransactionPayment::compute_fee(len as u32, &info, 0);
+            assert_eq!(Assets::balance(asset_id, BOB.clone()), minted - withdrawn);
+
+            // Rate changes after the withdrawal do not change the refund
+            assert_ok!(AssetManager::set_units_per_second(
+                root_origin(),
+                asset_id,
+                4 * native_per_second
+            ));
+            let post_info = PostDispatchInfo {
+                actual_weight: Some(100),
+                pays_fee: Pays::Yes,
+            };
+            assert_ok!(AssetPayment::post_dispatch(
+                Some(pre),
+                &info,
+                &post_info,
+                len,
+                &Ok(())
+            ));
+            let actual_fee =
+                2 * TransactionPayment::compute_actual_fee(len as u32, &info, &post_info, 0);
+            assert!(actual_fee < withdrawn);
+            assert_eq!(Assets::balance(asset_id, BOB.clone()), minted - actual_fee);
+
+            // Fees cannot be paid in assets which are suspended or being phased out
+            for status in [AssetStatus::XcmSuspended, AssetStatus::Deprecated] {
+                assert_ok!(AssetManager::set_asset_status(
+                    root_origin(),
+                    asset_id,
+                    status
+                ));
+                assert_eq!(
+                    AssetPayment::new(0, Some(asset_id)).validate(&BOB, &call, &info, len),
+                    Err(InvalidTransaction::Payment.into())
+                );
+                assert!(AssetPayment::new(0, Some(asset_id))
+                    .pre_dispatch(&BOB, &call, &info, len)
+                    .is_err());
+            }
+            assert_ok!(AssetManager::set_asset_status(
+                root_origin(),
+                asset_id,
+                AssetStatus::ShieldingSuspended
+            ));
+            assert_ok!(AssetPayment::new(0, Some(asset_id)).validate(&BOB, &call, &info, len));
+        });
+}
+
 #[test]
 fn root_can_change_default_xcm_vers() {
     ExtBuilder::default().build().execute_with(|| {
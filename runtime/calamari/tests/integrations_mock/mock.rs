@@ -161,6 +161,7 @@ impl ExtBuilder {
 
         pallet_asset_manager::GenesisConfig::<Runtime> {
             start_id: <CalamariAssetConfig as AssetConfig<Runtime>>::StartNonNativeAssetId::get(),
+            assets: Vec::new(),
         }
         .assimilate_storage(&mut t)
         .unwrap();
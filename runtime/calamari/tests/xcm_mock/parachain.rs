@@ -18,17 +18,20 @@
 
 #![cfg(test)]
 
-use codec::{Decode, Encode};
+use codec::{Compact, Decode, Encode};
 use cumulus_pallet_parachain_system::RelayNumberStrictlyIncreases;
 use frame_support::{
     assert_ok, construct_runtime, match_types,
     pallet_prelude::DispatchResult,
     parameter_types,
-    traits::{ConstU32, Currency, Everything, Nothing},
+    traits::{
+        fungibles, AsEnsureOriginWithArg, ConstU128, ConstU32, Contains, Currency, EnsureOrigin,
+        Everything, Nothing,
+    },
     weights::Weight,
     PalletId,
 };
-use frame_system::EnsureRoot;
+use frame_system::{EnsureNever, EnsureRoot, EnsureSigned};
 use scale_info::TypeInfo;
 use sp_core::H256;
 use sp_runtime::{
@@ -44,8 +47,13 @@ use manta_primitives::{
     },
     constants::{ASSET_MANAGER_PALLET_ID, CALAMARI_DECIMAL, WEIGHT_PER_SECOND},
     types::{BlockNumber, CalamariAssetId, Header},
-    xcm::{FirstAssetTrader, IsNativeConcrete, MultiAssetAdapter, MultiNativeAsset},
+    xcm::{
+        FilteredTransactExecutor, FirstAssetTrader, IsNativeConcrete, MultiAssetAdapter,
+        MultiNativeAsset, NativeTeleportAssets, NativeTeleportFrom, RateLimitedExecutor,
+        TrustedTeleportExecutor,
+    },
 };
+use pallet_asset_manager::IsTrustedTeleporter;
 use pallet_xcm::XcmPassthrough;
 use polkadot_core_primitives::BlockNumber as RelayBlockNumber;
 use polkadot_parachain::primitives::{
@@ -57,7 +65,8 @@ use xcm_builder::{
     AllowTopLevelPaidExecutionFrom, AllowUnpaidExecutionFrom, ConvertedConcreteAssetId,
     EnsureXcmOrigin, FixedRateOfFungible, LocationInverter, ParentIsPreset,
     SiblingParachainAsNative, SiblingParachainConvertsVia, SignedAccountId32AsNative,
-    SovereignSignedViaLocation, TakeRevenue, TakeWeightCredit, WeightInfoBounds,
+    SignedToAccountId32, SovereignSignedViaLocation, TakeRevenue, TakeWeightCredit,
+    WeightInfoBounds,
 };
 use xcm_executor::{traits::JustTry, Config, XcmExecutor};
 use xcm_simulator::{DmpMessageHandlerT, Get, TestExt, XcmpMessageHandlerT};
@@ -125,6 +134,7 @@ parameter_types! {
     pub Ancestry: MultiLocation = Parachain(ParachainInfo::parachain_id().into()).into();
     pub SelfReserve: MultiLocation = MultiLocation::new(1, X1(Parachain(ParachainInfo::parachain_id().into())));
     pub CheckingAccount: AccountId = PolkadotXcm::check_account();
+    pub TeleportCheckingAccount: Option<AccountId> = Some(CheckingAccount::get());
 }
 
 parameter_types! {
@@ -153,6 +163,25 @@ impl pallet_assets::Config for Runtime {
     type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 }
 
+impl pallet_uniques::Config for Runtime {
+    type Event = Event;
+    type CollectionId = u32;
+    type ItemId = u32;
+    type Currency = Balances;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+    type Locker = ();
+    type CollectionDeposit = ConstU128<0>;
+    type ItemDeposit = ConstU128<0>;
+    type MetadataDepositBase = ConstU128<0>;
+    type AttributeDepositBase = ConstU128<0>;
+    type DepositPerByte = ConstU128<0>;
+    type StringLimit = ConstU32<50>;
+    type KeyLimit = ConstU32<32>;
+    type ValueLimit = ConstU32<64>;
+    type WeightInfo = ();
+}
+
 /// Type for specifying how a `MultiLocation` can be converted into an `AccountId`. This is used
 /// when determining ownership of accounts for asset transacting and when attempting to use XCM
 /// `Transact` in order to determine the dispatch Origin.
@@ -183,6 +212,28 @@ pub type XcmOriginToCallOrigin = (
     XcmPassthrough<Origin>,
 );
 
+/// Ensures that the origin is a sibling parachain, as converted from an XCM `Transact` with
+/// `OriginKind::Native` by `SiblingParachainAsNative`, returning its para id.
+pub struct EnsureSiblingParachain;
+
+impl EnsureOrigin<Origin> for EnsureSiblingParachain {
+    type Success = u32;
+
+    #[inline]
+    fn try_origin(o: Origin) -> Result<u32, Origin> {
+        Into::<Result<cumulus_pallet_xcm::Origin, Origin>>::into(o).and_then(|o| match o {
+            cumulus_pallet_xcm::Origin::SiblingParachain(para_id) => Ok(para_id.into()),
+            o => Err(o.into()),
+        })
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    #[inline]
+    fn successful_origin() -> Origin {
+        cumulus_pallet_xcm::Origin::SiblingParachain(2015.into()).into()
+    }
+}
+
 parameter_types! {
     pub const UnitWeightCost: Weight = 1_000_000_000;
     // Used in native traders
@@ -209,6 +260,8 @@ pub type MultiAssetTransactor = MultiAssetAdapter<
         AssetIdLocationConvert<AssetManager>,
         JustTry,
     >,
+    // Used to track the native asset teleported out of this chain.
+    TeleportCheckingAccount,
 >;
 
 pub type XcmRouter = super::ParachainXcmRouter<MsgQueue>;
@@ -241,6 +294,13 @@ pub type Barrier = (
     AllowSubscriptionsFrom<ParentOrSiblings>,
 );
 
+/// Weigher of XCM messages on this chain
+pub type XcmWeigher = WeightInfoBounds<
+    calamari_runtime::weights::xcm::CalamariXcmWeight<Call>,
+    Call,
+    MaxInstructions,
+>;
+
 parameter_types! {
     /// Xcm fees will go to the asset manager (we don't implement treasury yet for mock parachain)
     pub XcmFeesAccount: AccountId = AssetManager::account_id();
@@ -286,14 +346,11 @@ impl Config for XcmExecutorConfig {
     type OriginConverter = XcmOriginToCallOrigin;
     // Combinations of (Location, Asset) pairs which we trust as reserves.
     type IsReserve = MultiNativeAsset;
-    type IsTeleporter = ();
+    // Teleports of the native asset are accepted from the locations trusted in `AssetManager`.
+    type IsTeleporter = NativeTeleportFrom<SelfReserve, IsTrustedTeleporter<Runtime>>;
     type LocationInverter = LocationInverter<Ancestry>;
     type Barrier = Barrier;
-    type Weigher = WeightInfoBounds<
-        calamari_runtime::weights::xcm::CalamariXcmWeight<Call>,
-        Call,
-        MaxInstructions,
-    >;
+    type Weigher = XcmWeigher;
     // Trader is the means to purchasing weight credit for XCM execution.
     // We define two traders:
     // The first one will charge parachain's native currency, who's `MultiLocation`
@@ -302,7 +359,7 @@ impl Config for XcmExecutorConfig {
     // i.e. units_per_second in `AssetManager`
     type Trader = (
         FixedRateOfFungible<ParaTokenPerSecond, XcmNativeFeeToTreasury>,
-        FirstAssetTrader<AssetManager, XcmFeesToAccount>,
+        FirstAssetTrader<AssetManager, AssetManager, XcmFeesToAccount>,
     );
     type ResponseHandler = PolkadotXcm;
     type AssetTrap = PolkadotXcm;
@@ -311,9 +368,36 @@ impl Config for XcmExecutorConfig {
     type SubscriptionService = PolkadotXcm;
 }
 
+parameter_types! {
+    pub const XcmTransactFilterWeight: Weight = 1_000;
+}
+
+/// Calls which sibling chains may dispatch through `Transact`
+pub struct XcmTransactFilter;
+
+impl Contains<Call> for XcmTransactFilter {
+    fn contains(call: &Call) -> bool {
+        matches!(
+            call,
+            Call::Balances(pallet_balances::Call::transfer { .. })
+                | Call::AssetManager(
+                    pallet_asset_manager::Call::request_sibling_registration { .. }
+                )
+        )
+    }
+}
+
 impl cumulus_pallet_xcmp_queue::Config for Runtime {
     type Event = Event;
-    type XcmExecutor = XcmExecutor<XcmExecutorConfig>;
+    type XcmExecutor = RateLimitedExecutor<
+        XcmRateLimiter,
+        FilteredTransactExecutor<
+            XcmExecutor<XcmExecutorConfig>,
+            XcmTransactFilter,
+            XcmTransactFilterWeight,
+        >,
+        XcmWeigher,
+    >;
     type ChannelInfo = ParachainSystem;
     type VersionWrapper = PolkadotXcm;
     type ExecuteOverweightOrigin = EnsureRoot<AccountId>;
@@ -467,7 +551,10 @@ pub mod mock_msg_queue {
 
 impl mock_msg_queue::Config for Runtime {
     type Event = Event;
-    type XcmExecutor = XcmExecutor<XcmExecutorConfig>;
+    // Messages over the rate limit of their origin in `XcmRateLimiter` are kept for a later
+    // block, and messages over the rate limit of a whole window are moved to the overweight queue.
+    type XcmExecutor =
+        RateLimitedExecutor<XcmRateLimiter, XcmExecutor<XcmExecutorConfig>, XcmWeigher>;
 }
 
 impl cumulus_pallet_parachain_system::Config for Runtime {
@@ -484,21 +571,22 @@ impl cumulus_pallet_parachain_system::Config for Runtime {
 
 pub type LocalOriginToLocation = ();
 
+/// Only signed accounts are converted, for the teleports of `limited_teleport_assets`.
+pub type LocalAccountToLocation = SignedToAccountId32<Origin, AccountId, RelayNetwork>;
+
 impl pallet_xcm::Config for Runtime {
     type Event = Event;
     type SendXcmOrigin = EnsureXcmOrigin<Origin, LocalOriginToLocation>;
     type XcmRouter = XcmRouter;
-    type ExecuteXcmOrigin = EnsureXcmOrigin<Origin, LocalOriginToLocation>;
+    type ExecuteXcmOrigin = EnsureXcmOrigin<Origin, LocalAccountToLocation>;
     type XcmExecuteFilter = Nothing;
-    type XcmExecutor = XcmExecutor<XcmExecutorConfig>;
-    // Do not allow teleports
-    type XcmTeleportFilter = Nothing;
+    // Teleports are only sent to the locations trusted in `AssetManager`.
+    type XcmExecutor =
+        TrustedTeleportExecutor<XcmExecutor<XcmExecutorConfig>, IsTrustedTeleporter<Runtime>>;
+    // Only the native asset can be teleported.
+    type XcmTeleportFilter = NativeTeleportAssets<SelfReserve>;
     type XcmReserveTransferFilter = Nothing;
-    type Weigher = WeightInfoBounds<
-        calamari_runtime::weights::xcm::CalamariXcmWeight<Call>,
-        Call,
-        MaxInstructions,
-    >;
+    type Weigher = XcmWeigher;
     type LocationInverter = LocationInverter<Ancestry>;
     type Origin = Origin;
     type Call = Call;
@@ -564,6 +652,38 @@ impl AssetRegistry for CalamariAssetRegistry {
             metadata.is_frozen,
         )
     }
+
+    fn total_supply(asset_id: &CalamariAssetId) -> Balance {
+        <Assets as fungibles::Inspect<AccountId>>::total_issuance(*asset_id)
+    }
+
+    fn accounts_and_approvals(asset_id: &CalamariAssetId) -> (u32, u32) {
+        // NOTE: The fields of `DestroyWitness` are private, but it is encoded as the compact
+        //       numbers of accounts, sufficient accounts and approvals of the asset.
+        <Assets as fungibles::Destroy<AccountId>>::get_destroy_witness(asset_id)
+            .and_then(|witness| {
+                <(Compact<u32>, Compact<u32>, Compact<u32>)>::decode(
+                    &mut witness.encode().as_slice(),
+                )
+                .ok()
+            })
+            .map(|(accounts, _, approvals)| (accounts.0, approvals.0))
+            .unwrap_or_default()
+    }
+
+    fn freeze_asset(asset_id: &CalamariAssetId) -> DispatchResult {
+        Assets::freeze_asset(Origin::signed(AssetManager::account_id()), *asset_id)
+    }
+
+    fn destroy_asset(asset_id: &CalamariAssetId) -> DispatchResult {
+        // NOTE: `DestroyWitness` can only be built by decoding it. An empty witness makes
+        //       `pallet_assets` refuse the destruction while any accounts or approvals remain.
+        let witness = pallet_assets::DestroyWitness::decode(&mut [0u8; 3].as_slice())
+            .map_err(|_| sp_runtime::DispatchError::Other("Invalid destroy witness"))?;
+        Assets::destroy(Origin::root(), *asset_id, witness)
+            .map(|_| ())
+            .map_err(|err| err.error)
+    }
 }
 
 parameter_types! {
@@ -605,6 +725,7 @@ impl AssetConfig<Runtime> for ParachainAssetConfig {
     type StorageMetadata = AssetStorageMetadata;
     type AssetRegistry = CalamariAssetRegistry;
     type FungibleLedger = NativeAndNonNative<Runtime, ParachainAssetConfig, Balances, Assets>;
+    type AssetStatusMap = AssetManager;
 }
 
 impl pallet_asset_manager::Config for Runtime {
@@ -613,11 +734,35 @@ impl pallet_asset_manager::Config for Runtime {
     type Balance = Balance;
     type Location = AssetLocation;
     type AssetConfig = ParachainAssetConfig;
+    type CollectionId = u32;
+    type ItemId = u32;
+    type NonFungibles = Uniques;
     type ModifierOrigin = EnsureRoot<AccountId>;
+    type MintCapOrigin = EnsureNever<AccountId>;
+    type MaxLocationAliases = ConstU32<4>;
+    type Currency = Balances;
+    type ProposalDeposit = ConstU128<1_000>;
+    type ProposalLifetime = ConstU32<100>;
+    type OnProposalSlash = ();
+    type SiblingOrigin = EnsureSiblingParachain;
+    type MaxSiblingRequests = ConstU32<4>;
     type PalletId = AssetManagerPalletId;
     type WeightInfo = ();
 }
 
+impl pallet_xcm_rate_limiter::Config for Runtime {
+    type Event = Event;
+    type Location = AssetLocation;
+    type UpdateOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = ();
+}
+
+impl pallet_xcm_fees::Config for Runtime {
+    type Event = Event;
+    type UpdateOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = ();
+}
+
 impl cumulus_pallet_xcm::Config for Runtime {
     type Event = Event;
     type XcmExecutor = XcmExecutor<XcmExecutorConfig>;
@@ -660,11 +805,7 @@ impl orml_xtokens::Config for Runtime {
     type CurrencyIdConvert = CurrencyIdtoMultiLocation<AssetIdLocationConvert<AssetManager>>;
     type XcmExecutor = XcmExecutor<XcmExecutorConfig>;
     type SelfLocation = SelfReserve;
-    type Weigher = WeightInfoBounds<
-        calamari_runtime::weights::xcm::CalamariXcmWeight<Call>,
-        Call,
-        MaxInstructions,
-    >;
+    type Weigher = XcmWeigher;
     type BaseXcmWeight = BaseXcmWeight;
     type LocationInverter = LocationInverter<Ancestry>;
     type MaxAssetsForTransfer = MaxAssetsForTransfer;
@@ -697,6 +838,9 @@ construct_runtime!(
         ParachainInfo: parachain_info::{Pallet, Storage, Config} = 8,
         XcmpQueue: cumulus_pallet_xcmp_queue::{Pallet, Call, Storage, Event<T>} = 9,
         ParachainSystem: cumulus_pallet_parachain_system::{Pallet, Call, Config, Storage, Inherent, Event<T>, ValidateUnsigned} = 10,
+        Uniques: pallet_uniques::{Pallet, Call, Storage, Event<T>} = 11,
+        XcmFees: pallet_xcm_fees::{Pallet, Call, Storage, Event<T>} = 12,
+        XcmRateLimiter: pallet_xcm_rate_limiter::{Pallet, Call, Storage, Event<T>} = 13,
     }
 );
 
@@ -21,11 +21,23 @@
 use crate::xcm_mock::parachain::XcmFeesAccount;
 use codec::Encode;
 use frame_support::{
-    assert_err, assert_noop, assert_ok, traits::tokens::fungibles::Mutate, WeakBoundedVec,
+    assert_err, assert_noop, assert_ok, parameter_types,
+    traits::{tokens::fungibles::Mutate, Currency},
+    WeakBoundedVec,
 };
-use manta_primitives::{assets::AssetLocation, constants::WEIGHT_PER_SECOND};
-use xcm::{latest::prelude::*, v2::Response, VersionedMultiLocation, WrapVersion};
-use xcm_executor::traits::{Convert, WeightBounds};
+use manta_primitives::{
+    assets::{AssetIdLocationConvert, AssetLocation, AssetStatus},
+    constants::WEIGHT_PER_SECOND,
+    types::{AccountId, Balance, CalamariAssetId},
+    xcm::{
+        FeeSplit, FirstAssetTrader, IsNativeConcrete, XcmFeesSplit, FILTERED_TRANSACT_TRAP_CODE,
+    },
+};
+use xcm::{
+    latest::prelude::*, v2::Response, VersionedMultiAssets, VersionedMultiLocation, WrapVersion,
+};
+use xcm_builder::{ConvertedConcreteAssetId, TakeRevenue};
+use xcm_executor::traits::{Convert, JustTry, WeightBounds, WeightTrader};
 use xcm_simulator::TestExt;
 
 use super::{
@@ -182,6 +194,15 @@ fn xcmp_transact_from_sibling_parachain_blocked_by_barrier() {
         assert!(!System::events()
             .iter()
             .any(|r| matches!(r.event, Event::System(frame_system::Event::Remarked { .. }))));
+        // Checking the `Transact` instruction is still charged
+        assert!(System::events().iter().any(|r| matches!(
+            r.event,
+            Event::XcmpQueue(cumulus_pallet_xcmp_queue::Event::Fail {
+                message_hash: Some(_),
+                error: XcmError::Barrier,
+                weight,
+            }) if weight == parachain::XcmTransactFilterWeight::get()
+        )));
     });
 }
 
@@ -1178,6 +1199,399 @@ fn receive_relay_asset_with_trader_on_parachain() {
     });
 }
 
+#[test]
+fn receive_relay_asset_with_suspended_status_should_fail() {
+    MockNet::reset();
+
+    let relay_source_location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::parent()));
+    let relay_asset_metadata = create_asset_metadata("Kusama", "KSM", 12, 1, false, true);
+    let amount = 666u128;
+    let relay_asset_id_on_a = register_assets_on_parachain::<ParaA>(
+        &relay_source_location,
+        &relay_asset_metadata,
+        Some(1_000_000_000u128),
+        None,
+    );
+
+    let dest: MultiLocation = AccountId32 {
+        network: Any,
+        id: ALICE.into(),
+    }
+    .into();
+
+    for status in [AssetStatus::XcmSuspended, AssetStatus::Deprecated] {
+        ParaA::execute_with(|| {
+            assert_ok!(AssetManager::set_asset_status(
+                parachain::Origin::root(),
+                relay_asset_id_on_a,
+                status
+            ));
+        });
+
+        Relay::execute_with(|| {
+            assert_ok!(RelayChainPalletXcm::reserve_transfer_assets(
+                relay_chain::Origin::signed(ALICE),
+                Box::new(X1(Parachain(1)).into().into()),
+                Box::new(VersionedMultiLocation::V1(dest.clone())),
+                Box::new((Here, amount).into()),
+                0,
+            ));
+        });
+
+        // Neither fees nor deposits are accepted in the suspended asset
+        ParaA::execute_with(|| {
+            assert_eq!(parachain::Assets::balance(relay_asset_id_on_a, &ALICE), 0);
+            assert_eq!(
+                parachain::Assets::balance(relay_asset_id_on_a, &XcmFeesAccount::get()),
+                0
+            );
+        });
+    }
+}
+
+#[test]
+fn sibling_registration_request_via_transact_should_work() {
+    MockNet::reset();
+
+    let para_a_source_location = create_asset_location(1, PARA_A_ID);
+    let para_a_asset_metadata = create_asset_metadata("ParaA", "ParaA", 12, 1, false, true);
+    let request = parachain::Call::AssetManager(
+        pallet_asset_manager::Call::<parachain::Runtime>::request_sibling_registration {
+            location: para_a_source_location.clone(),
+            metadata: para_a_asset_metadata.clone(),
+        },
+    );
+    let fee = 1_000_000_000_000u128;
+
+    // The sovereign account of ParaA on ParaB pays for the execution of the request
+    ParaB::execute_with(|| {
+        let para_a_sovereign_account = parachain::LocationToAccountId::convert(MultiLocation::new(
+            1,
+            X1(Parachain(PARA_A_ID)),
+        ))
+        .unwrap();
+        parachain::Balances::make_free_balance_be(&para_a_sovereign_account, INITIAL_BALANCE);
+    });
+
+    ParaA::execute_with(|| {
+        assert_ok!(ParachainPalletXcm::send_xcm(
+            Here,
+            (Parent, Parachain(PARA_B_ID)),
+            Xcm(vec![
+                WithdrawAsset((Here, fee).into()),
+                buy_execution((Here, fee)),
+                Transact {
+                    origin_type: OriginKind::Native,
+                    require_weight_at_most: 1_000_000_000,
+                    call: request.encode().into(),
+                },
+            ]),
+        ));
+    });
+
+    // The request is pending on ParaB until governance ratifies it
+    ParaB::execute_with(|| {
+        assert_eq!(
+            AssetManager::sibling_registration_request(PARA_A_ID, &para_a_source_location),
+            Some(para_a_asset_metadata.clone())
+        );
+        assert_eq!(
+            AssetManager::location_asset_id(&para_a_source_location),
+            None
+        );
+        assert_ok!(AssetManager::approve_sibling_registration(
+            parachain::Origin::root(),
+            para_a_source_location.clone()
+        ));
+        assert!(AssetManager::location_asset_id(&para_a_source_location).is_some());
+    });
+}
+
+#[test]
+fn xcmp_transact_from_sibling_parachain_should_be_filtered() {
+    MockNet::reset();
+
+    let receiver = sp_runtime::AccountId32::new([7u8; 32]);
+    let remark = parachain::Call::System(
+        frame_system::Call::<parachain::Runtime>::remark_with_event {
+            remark: vec![1, 2, 3],
+        },
+    );
+    let transfer =
+        parachain::Call::Balances(pallet_balances::Call::<parachain::Runtime>::transfer {
+            dest: receiver.clone(),
+            value: 100,
+        });
+    let fee = 1_000_000_000_000u128;
+    let transact = |call: &parachain::Call| {
+        Xcm(vec![
+            WithdrawAsset((Here, fee).into()),
+            buy_execution((Here, fee)),
+            Transact {
+                origin_type: OriginKind::SovereignAccount,
+                require_weight_at_most: 1_000_000_000,
+                call: call.encode().into(),
+            },
+        ])
+    };
+
+    // The sovereign account of ParaA on ParaB pays for the execution of the calls
+    ParaB::execute_with(|| {
+        let para_a_sovereign_account = parachain::LocationToAccountId::convert(MultiLocation::new(
+            1,
+            X1(Parachain(PARA_A_ID)),
+        ))
+        .unwrap();
+        parachain::Balances::make_free_balance_be(&para_a_sovereign_account, INITIAL_BALANCE);
+    });
+
+    ParaA::execute_with(|| {
+        assert_ok!(ParachainPalletXcm::send_xcm(
+            Here,
+            (Parent, Parachain(PARA_B_ID)),
+            transact(&remark),
+        ));
+    });
+
+    // The remark is not allowed by the filter, so the message is trapped
+    ParaB::execute_with(|| {
+        use parachain::{Event, System};
+        assert!(!System::events()
+            .iter()
+            .any(|r| matches!(r.event, Event::System(frame_system::Event::Remarked { .. }))));
+        assert!(System::events().iter().any(|r| matches!(
+            r.event,
+            Event::XcmpQueue(cumulus_pallet_xcmp_queue::Event::Fail {
+                message_hash: Some(_),
+                error: XcmError::Trap(FILTERED_TRANSACT_TRAP_CODE),
+                weight: _
+            })
+        )));
+    });
+
+    ParaA::execute_with(|| {
+        assert_ok!(ParachainPalletXcm::send_xcm(
+            Here,
+            (Parent, Parachain(PARA_B_ID)),
+            transact(&transfer),
+        ));
+    });
+
+    // Transfers are allowed by the filter
+    ParaB::execute_with(|| {
+        assert_eq!(parachain::Balances::free_balance(&receiver), 100);
+    });
+}
+
+#[test]
+fn native_teleports_should_be_limited_to_trusted_locations_and_teleported_supply() {
+    MockNet::reset();
+
+    let receiver = sp_runtime::AccountId32::new([8u8; 32]);
+    let amount = 10_000_000_000_000u128;
+    let fee = 1_000_000_000_000u128;
+    let teleport = || {
+        ParaA::execute_with(|| {
+            assert_ok!(ParachainPalletXcm::send_xcm(
+                Here,
+                (Parent, Parachain(PARA_B_ID)),
+                Xcm(vec![
+                    ReceiveTeleportedAsset((Here, amount).into()),
+                    ClearOrigin,
+                    buy_execution((Here, fee)),
+                    DepositAsset {
+                        assets: Wild(All),
+                        max_assets: 1,
+                        beneficiary: AccountId32 {
+                            network: NetworkId::Any,
+                            id: receiver.clone().into(),
+                        }
+                        .into(),
+                    },
+                ]),
+            ));
+        })
+    };
+    let has_failed_with = |error: XcmError| {
+        use parachain::{Event, System};
+        System::events().iter().any(|r| {
+            matches!(
+                r.event,
+                Event::XcmpQueue(cumulus_pallet_xcmp_queue::Event::Fail { error: e, .. })
+                    if e == error
+            )
+        })
+    };
+
+    // ParaA is not trusted to teleport the native asset of ParaB
+    teleport();
+    ParaB::execute_with(|| {
+        assert!(has_failed_with(XcmError::UntrustedTeleportLocation));
+        assert_eq!(parachain::Balances::free_balance(&receiver), 0);
+        assert_ok!(AssetManager::set_teleport_trust(
+            parachain::Origin::root(),
+            create_asset_location(1, PARA_A_ID),
+            true
+        ));
+    });
+
+    // Nothing was teleported out to ParaA yet
+    teleport();
+    ParaB::execute_with(|| {
+        assert!(has_failed_with(XcmError::NotWithdrawable));
+        assert_eq!(parachain::Balances::free_balance(&receiver), 0);
+    });
+
+    // Teleporting out of ParaB moves the supply to the checking account
+    ParaB::execute_with(|| {
+        let total_issuance = parachain::Balances::total_issuance();
+        let weight_limit = 10_000_000_000;
+        let outcome = xcm_executor::XcmExecutor::<ParaXcmExecutorConfig>::execute_xcm_in_credit(
+            AccountId32 {
+                network: NetworkId::Any,
+                id: ALICE.into(),
+            },
+            Xcm(vec![
+                WithdrawAsset((Here, amount).into()),
+                InitiateTeleport {
+                    assets: Wild(All),
+                    dest: (Parent, Parachain(PARA_A_ID)).into(),
+                    xcm: Xcm(vec![]),
+                },
+            ]),
+            weight_limit,
+            weight_limit,
+        );
+        assert!(matches!(outcome, Outcome::Complete(_)));
+        assert_eq!(
+            parachain::Balances::free_balance(&parachain::CheckingAccount::get()),
+            amount
+        );
+        assert_eq!(parachain::Balances::total_issuance(), total_issuance);
+    });
+
+    // The teleported supply can come back from the trusted location, but only once
+    teleport();
+    let received = ParaB::execute_with(|| {
+        let received = parachain::Balances::free_balance(&receiver);
+        assert!(received > amount - fee);
+        assert_eq!(
+            parachain::Balances::free_balance(&parachain::CheckingAccount::get()),
+            0
+        );
+        received
+    });
+    teleport();
+    ParaB::execute_with(|| {
+        assert_eq!(parachain::Balances::free_balance(&receiver), received);
+    });
+}
+
+#[test]
+fn native_teleports_should_round_trip_through_trusted_locations() {
+    MockNet::reset();
+
+    let amount = 10_000_000_000_000u128;
+    let fee = 1_000_000_000_000u128;
+    let teleport = |asset: MultiLocation| {
+        ParachainPalletXcm::limited_teleport_assets(
+            parachain::Origin::signed(ALICE),
+            Box::new(VersionedMultiLocation::V1(
+                (Parent, Parachain(PARA_A_ID)).into(),
+            )),
+            Box::new(VersionedMultiLocation::V1(
+                AccountId32 {
+                    network: NetworkId::Any,
+                    id: ALICE.into(),
+                }
+                .into(),
+            )),
+            Box::new(VersionedMultiAssets::V1((asset, amount).into())),
+            0,
+            WeightLimit::Unlimited,
+        )
+    };
+
+    // ParaA is not trusted by ParaB yet, so nothing is teleported
+    ParaB::execute_with(|| {
+        use parachain::{Event, System};
+        assert_ok!(teleport(Here.into()));
+        assert!(System::events().iter().any(|r| matches!(
+            r.event,
+            Event::PolkadotXcm(pallet_xcm::Event::Attempted(Outcome::Error(
+                XcmError::UntrustedTeleportLocation
+            )))
+        )));
+        assert_eq!(parachain::Balances::free_balance(&ALICE), INITIAL_BALANCE);
+        // Only the native asset can be teleported
+        assert_noop!(
+            teleport(Parent.into()),
+            pallet_xcm::Error::<parachain::Runtime>::Filtered
+        );
+        assert_ok!(AssetManager::set_teleport_trust(
+            parachain::Origin::root(),
+            create_asset_location(1, PARA_A_ID),
+            true
+        ));
+    });
+
+    // The native asset of ParaB is teleported to ParaA and tracked in the checking account
+    ParaB::execute_with(|| {
+        assert_ok!(teleport(Here.into()));
+        assert_eq!(
+            parachain::Balances::free_balance(&ALICE),
+            INITIAL_BALANCE - amount
+        );
+        assert_eq!(
+            parachain::Balances::free_balance(&parachain::CheckingAccount::get()),
+            amount
+        );
+    });
+
+    // ParaA only accepts teleports of its own native asset
+    ParaA::execute_with(|| {
+        use parachain::{Event, System};
+        assert!(System::events().iter().any(|r| matches!(
+            r.event,
+            Event::XcmpQueue(cumulus_pallet_xcmp_queue::Event::Fail {
+                error: XcmError::UntrustedTeleportLocation,
+                ..
+            })
+        )));
+    });
+
+    // Half of the teleported supply comes back from the trusted location
+    ParaA::execute_with(|| {
+        assert_ok!(ParachainPalletXcm::send_xcm(
+            Here,
+            (Parent, Parachain(PARA_B_ID)),
+            Xcm(vec![
+                ReceiveTeleportedAsset((Here, amount / 2).into()),
+                ClearOrigin,
+                buy_execution((Here, fee)),
+                DepositAsset {
+                    assets: Wild(All),
+                    max_assets: 1,
+                    beneficiary: AccountId32 {
+                        network: NetworkId::Any,
+                        id: ALICE.into(),
+                    }
+                    .into(),
+                },
+            ]),
+        ));
+    });
+    ParaB::execute_with(|| {
+        let balance = parachain::Balances::free_balance(&ALICE);
+        assert!(balance > INITIAL_BALANCE - amount);
+        assert!(balance < INITIAL_BALANCE - amount / 2);
+        assert_eq!(
+            parachain::Balances::free_balance(&parachain::CheckingAccount::get()),
+            amount - amount / 2
+        );
+    });
+}
+
 #[test]
 fn send_para_a_asset_to_para_b_with_trader_and_fee() {
     MockNet::reset();
@@ -1598,6 +2012,428 @@ fn send_para_a_asset_from_para_b_to_para_c_with_trader() {
     });
 }
 
+#[test]
+fn trader_should_refund_and_take_revenue_in_each_paid_asset() {
+    MockNet::reset();
+
+    let relay_source_location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::parent()));
+    let para_b_source_location = create_asset_location(1, PARA_B_ID);
+    let relay_asset_metadata = create_asset_metadata("Kusama", "KSM", 12, 1, false, true);
+    let para_b_asset_metadata = create_asset_metadata("ParaB", "ParaB", 12, 1, false, true);
+    let units_per_second = WEIGHT_PER_SECOND as u128;
+
+    let relay_asset_id = register_assets_on_parachain::<ParaA>(
+        &relay_source_location,
+        &relay_asset_metadata,
+        Some(units_per_second),
+        None,
+    );
+    let para_b_asset_id = register_assets_on_parachain::<ParaA>(
+        &para_b_source_location,
+        &para_b_asset_metadata,
+        Some(2 * units_per_second),
+        None,
+    );
+
+    ParaA::execute_with(|| {
+        let relay = MultiLocation::parent();
+        let para_b = MultiLocation::new(1, X1(Parachain(PARA_B_ID)));
+        let mut trader =
+            FirstAssetTrader::<AssetManager, AssetManager, parachain::XcmFeesToAccount>::new();
+        assert_eq!(
+            trader.buy_weight(1_000, (relay.clone(), 1_500u128).into()),
+            Ok((relay.clone(), 500u128).into())
+        );
+        assert_eq!(
+            trader.buy_weight(1_000, (para_b.clone(), 2_500u128).into()),
+            Ok((para_b.clone(), 500u128).into())
+        );
+        // Unused weight is only refunded in the latest asset paid with, the surplus of 500 beyond
+        // its purchase is left to a further call which the executor does not make
+        assert_eq!(
+            trader.refund_weight(1_500),
+            Some((para_b, 2_000u128).into())
+        );
+        assert_eq!(trader.refund_weight(400), Some((relay, 400u128).into()));
+        drop(trader);
+        assert_eq!(
+            parachain::Assets::balance(relay_asset_id, &XcmFeesAccount::get()),
+            600
+        );
+        assert_eq!(
+            parachain::Assets::balance(para_b_asset_id, &XcmFeesAccount::get()),
+            0
+        );
+    });
+}
+
+#[test]
+fn trader_should_fall_back_to_other_offered_assets() {
+    MockNet::reset();
+
+    let relay_source_location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::parent()));
+    let relay_asset_metadata = create_asset_metadata("Kusama", "KSM", 12, 1, false, true);
+    let units_per_second = WEIGHT_PER_SECOND as u128;
+    let _ = register_assets_on_parachain::<ParaA>(
+        &relay_source_location,
+        &relay_asset_metadata,
+        None,
+        None,
+    );
+    for para_id in [PARA_B_ID, PARA_C_ID] {
+        let _ = register_assets_on_parachain::<ParaA>(
+            &create_asset_location(1, para_id),
+            &create_asset_metadata("Para", "PARA", 12, 1, false, true),
+            Some(units_per_second),
+            None,
+        );
+    }
+
+    parameter_types! {
+        pub ParaCFirst: Vec<MultiLocation> = vec![MultiLocation::new(1, X1(Parachain(PARA_C_ID)))];
+    }
+
+    ParaA::execute_with(|| {
+        let relay = MultiLocation::parent();
+        let para_b = MultiLocation::new(1, X1(Parachain(PARA_B_ID)));
+        let para_c = MultiLocation::new(1, X1(Parachain(PARA_C_ID)));
+        let payment = |relay_amount: u128, para_b_amount: u128, para_c_amount: u128| {
+            xcm_executor::Assets::from(vec![
+                (relay.clone(), relay_amount).into(),
+                (para_b.clone(), para_b_amount).into(),
+                (para_c.clone(), para_c_amount).into(),
+            ])
+        };
+        // The relay asset comes first but has no units per second
+        let mut trader =
+            FirstAssetTrader::<AssetManager, AssetManager, parachain::XcmFeesToAccount>::new();
+        assert_eq!(
+            trader.buy_weight(100, payment(1_000, 1_000, 1_000)),
+            Ok(payment(1_000, 900, 1_000))
+        );
+        // Preferred assets are tried first
+        let mut trader = FirstAssetTrader::<
+            AssetManager,
+            AssetManager,
+            parachain::XcmFeesToAccount,
+            ParaCFirst,
+        >::new();
+        assert_eq!(
+            trader.buy_weight(100, payment(1_000, 1_000, 1_000)),
+            Ok(payment(1_000, 1_000, 900))
+        );
+        // None of the offered assets can cover the weight
+        assert_eq!(
+            trader.buy_weight(1_001, payment(1_000, 1_000, 1_000)),
+            Err(XcmError::TooExpensive)
+        );
+    });
+}
+
+#[test]
+fn trader_should_try_native_asset_first_at_here() {
+    MockNet::reset();
+
+    let relay_source_location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::parent()));
+    let relay_asset_metadata = create_asset_metadata("Kusama", "KSM", 12, 1, false, true);
+    let native_asset_metadata = create_asset_metadata("ParaA", "ParaA", 12, 1, false, true);
+    let units_per_second = WEIGHT_PER_SECOND as u128;
+    let _ = register_assets_on_parachain::<ParaA>(
+        &relay_source_location,
+        &relay_asset_metadata,
+        Some(units_per_second),
+        None,
+    );
+    let _ = register_assets_on_parachain::<ParaA>(
+        &create_asset_location(1, PARA_A_ID),
+        &native_asset_metadata,
+        Some(2 * units_per_second),
+        None,
+    );
+
+    parameter_types! {
+        pub NativeFirst: Vec<MultiLocation> = vec![MultiLocation::here(), MultiLocation::parent()];
+    }
+
+    ParaA::execute_with(|| {
+        let relay = MultiLocation::parent();
+        let native = MultiLocation::here();
+        let payment = |relay_amount: u128, native_amount: u128| {
+            xcm_executor::Assets::from(vec![
+                (relay.clone(), relay_amount).into(),
+                (native.clone(), native_amount).into(),
+            ])
+        };
+        // The native asset is held at `here()` and resolved through the self location
+        let mut trader = FirstAssetTrader::<
+            AssetManager,
+            AssetManager,
+            parachain::XcmFeesToAccount,
+            NativeFirst,
+            parachain::SelfReserve,
+        >::new();
+        assert_eq!(
+            trader.buy_weight(100, payment(1_000, 1_000)),
+            Ok(payment(1_000, 800))
+        );
+        assert_eq!(
+            trader.refund_weight(50),
+            Some((native.clone(), 100u128).into())
+        );
+        // Without the self location `here()` does not resolve and the relay asset pays instead
+        let mut trader = FirstAssetTrader::<
+            AssetManager,
+            AssetManager,
+            parachain::XcmFeesToAccount,
+            NativeFirst,
+        >::new();
+        assert_eq!(
+            trader.buy_weight(100, payment(1_000, 1_000)),
+            Ok(payment(900, 1_000))
+        );
+    });
+}
+
+#[test]
+fn fee_revenue_should_be_split_between_treasury_author_and_burn() {
+    MockNet::reset();
+
+    let para_b_asset_id = register_assets_on_parachain::<ParaA>(
+        &create_asset_location(1, PARA_B_ID),
+        &create_asset_metadata("ParaB", "ParaB", 12, 1, false, true),
+        Some(WEIGHT_PER_SECOND as u128),
+        None,
+    );
+
+    parameter_types! {
+        pub FeeSplitAuthor: Option<AccountId> = Some(sp_runtime::AccountId32::new([9u8; 32]));
+        pub CurrentFeeSplit: FeeSplit = parachain::XcmFees::xcm_fee_split();
+    }
+
+    type FeesSplit = XcmFeesSplit<
+        parachain::Runtime,
+        parachain::ParachainAssetConfig,
+        IsNativeConcrete<parachain::SelfReserve>,
+        ConvertedConcreteAssetId<
+            CalamariAssetId,
+            Balance,
+            AssetIdLocationConvert<parachain::AssetManager>,
+            JustTry,
+        >,
+        CurrentFeeSplit,
+        XcmFeesAccount,
+        FeeSplitAuthor,
+    >;
+
+    ParaA::execute_with(|| {
+        let author = FeeSplitAuthor::get().unwrap();
+        let treasury = XcmFeesAccount::get();
+        let para_b = MultiLocation::new(1, X1(Parachain(PARA_B_ID)));
+        let native_before = parachain::Balances::free_balance(&treasury);
+        let issuance_before = parachain::Balances::total_issuance();
+        // By default all of the revenue goes to the treasury
+        FeesSplit::take_revenue((para_b.clone(), 1_000).into());
+        assert_eq!(
+            parachain::Assets::balance(para_b_asset_id, &treasury),
+            1_000
+        );
+        assert_ok!(parachain::XcmFees::set_xcm_fee_split(
+            parachain::Origin::root(),
+            FeeSplit::new(50, 30, 20)
+        ));
+        FeesSplit::take_revenue((para_b, 1_000).into());
+        assert_eq!(
+            parachain::Assets::balance(para_b_asset_id, &treasury),
+            1_500
+        );
+        assert_eq!(parachain::Assets::balance(para_b_asset_id, &author), 300);
+        assert_eq!(parachain::Assets::total_supply(para_b_asset_id), 1_800);
+        // The native asset is split the same way
+        FeesSplit::take_revenue((MultiLocation::here(), 1_000).into());
+        assert_eq!(
+            parachain::Balances::free_balance(&treasury),
+            native_before + 500
+        );
+        assert_eq!(parachain::Balances::free_balance(&author), 300);
+        assert_eq!(parachain::Balances::total_issuance(), issuance_before + 800);
+    });
+}
+
+#[test]
+fn sibling_messages_over_the_rate_limit_should_be_deferred() {
+    MockNet::reset();
+
+    let para_a_source_location = create_asset_location(1, PARA_A_ID);
+    let para_a_asset_metadata = create_asset_metadata("ParaAToken", "ParaA", 18, 1, false, false);
+    let a_asset_id_on_a = register_assets_on_parachain::<ParaA>(
+        &para_a_source_location,
+        &para_a_asset_metadata,
+        Some(0u128),
+        None,
+    );
+    let a_asset_id_on_b = register_assets_on_parachain::<ParaB>(
+        &para_a_source_location,
+        &para_a_asset_metadata,
+        Some(0u128),
+        None,
+    );
+
+    let dest = MultiLocation {
+        parents: 1,
+        interior: X2(
+            Parachain(PARA_B_ID),
+            AccountId32 {
+                network: NetworkId::Any,
+                id: ALICE.into(),
+            },
+        ),
+    };
+    let transfer = || {
+        ParaA::execute_with(|| {
+            assert_ok!(parachain::XTokens::transfer(
+                parachain::Origin::signed(ALICE),
+                parachain::CurrencyId::MantaCurrency(a_asset_id_on_a),
+                100,
+                Box::new(VersionedMultiLocation::V1(dest.clone())),
+                ADVERTISED_DEST_WEIGHT
+            ));
+        })
+    };
+
+    ParaB::execute_with(|| {
+        assert_ok!(parachain::XcmRateLimiter::set_xcm_rate_limit(
+            parachain::Origin::root(),
+            Some(pallet_xcm_rate_limiter::XcmRateLimit {
+                window: 10,
+                max_messages: 1,
+                max_weight: u64::MAX,
+            })
+        ));
+    });
+
+    transfer();
+    ParaB::execute_with(|| {
+        assert_eq!(parachain::Assets::balance(a_asset_id_on_b, &ALICE), 100);
+    });
+
+    // The second message in the window is over the budget of ParaA and is kept in the queue
+    transfer();
+    ParaB::execute_with(|| {
+        use parachain::{Event, System};
+        assert_eq!(parachain::Assets::balance(a_asset_id_on_b, &ALICE), 100);
+        assert!(System::events().iter().any(|r| matches!(
+            &r.event,
+            Event::XcmRateLimiter(pallet_xcm_rate_limiter::Event::XcmMessageThrottled { origin, .. })
+                if origin == &para_a_source_location
+        )));
+        assert!(System::events().iter().any(|r| matches!(
+            r.event,
+            Event::XcmpQueue(cumulus_pallet_xcmp_queue::Event::Fail {
+                message_hash: Some(_),
+                error: xcm_simulator::XcmError::WeightLimitReached(_),
+                weight: _
+            })
+        )));
+        // The budget is restored in the next window
+        parachain::para_roll_to(10);
+    });
+    // The deferred message is executed first and the new one waits for the next window
+    transfer();
+    ParaB::execute_with(|| {
+        assert_eq!(parachain::Assets::balance(a_asset_id_on_b, &ALICE), 200);
+        // Trusted siblings are not rate limited
+        assert_ok!(parachain::XcmRateLimiter::set_xcm_rate_limit_override(
+            parachain::Origin::root(),
+            para_a_source_location.clone(),
+            Some(pallet_xcm_rate_limiter::XcmRateLimitOverride::Unlimited)
+        ));
+    });
+    transfer();
+    transfer();
+    ParaB::execute_with(|| {
+        assert_eq!(parachain::Assets::balance(a_asset_id_on_b, &ALICE), 500);
+    });
+}
+
+#[test]
+fn sibling_messages_over_the_rate_limit_of_a_window_should_be_overweight() {
+    MockNet::reset();
+
+    let para_a_source_location = create_asset_location(1, PARA_A_ID);
+    let para_a_asset_metadata = create_asset_metadata("ParaAToken", "ParaA", 18, 1, false, false);
+    let a_asset_id_on_a = register_assets_on_parachain::<ParaA>(
+        &para_a_source_location,
+        &para_a_asset_metadata,
+        Some(0u128),
+        None,
+    );
+    let a_asset_id_on_b = register_assets_on_parachain::<ParaB>(
+        &para_a_source_location,
+        &para_a_asset_metadata,
+        Some(0u128),
+        None,
+    );
+
+    let dest = MultiLocation {
+        parents: 1,
+        interior: X2(
+            Parachain(PARA_B_ID),
+            AccountId32 {
+                network: NetworkId::Any,
+                id: ALICE.into(),
+            },
+        ),
+    };
+
+    ParaB::execute_with(|| {
+        assert_ok!(parachain::XcmRateLimiter::set_xcm_rate_limit(
+            parachain::Origin::root(),
+            Some(pallet_xcm_rate_limiter::XcmRateLimit {
+                window: 10,
+                max_messages: 10,
+                max_weight: 1,
+            })
+        ));
+    });
+
+    ParaA::execute_with(|| {
+        assert_ok!(parachain::XTokens::transfer(
+            parachain::Origin::signed(ALICE),
+            parachain::CurrencyId::MantaCurrency(a_asset_id_on_a),
+            100,
+            Box::new(VersionedMultiLocation::V1(dest)),
+            ADVERTISED_DEST_WEIGHT
+        ));
+    });
+
+    // The message can never fit in the budget of ParaA and is moved to the overweight queue
+    ParaB::execute_with(|| {
+        use parachain::{Event, System};
+        assert_eq!(parachain::Assets::balance(a_asset_id_on_b, &ALICE), 0);
+        assert!(System::events().iter().any(|r| matches!(
+            &r.event,
+            Event::XcmRateLimiter(pallet_xcm_rate_limiter::Event::XcmMessageOverweight { origin, .. })
+                if origin == &para_a_source_location
+        )));
+        assert!(System::events().iter().any(|r| matches!(
+            r.event,
+            Event::XcmpQueue(cumulus_pallet_xcmp_queue::Event::OverweightEnqueued { .. })
+        )));
+        // Governance can execute it once the rate limit of ParaA allows it
+        assert_ok!(parachain::XcmRateLimiter::set_xcm_rate_limit_override(
+            parachain::Origin::root(),
+            para_a_source_location.clone(),
+            Some(pallet_xcm_rate_limiter::XcmRateLimitOverride::Unlimited)
+        ));
+        assert_ok!(parachain::XcmpQueue::service_overweight(
+            parachain::Origin::root(),
+            0,
+            ADVERTISED_DEST_WEIGHT
+        ));
+        assert_eq!(parachain::Assets::balance(a_asset_id_on_b, &ALICE), 100);
+    });
+}
+
 #[test]
 fn receive_relay_asset_on_parachain_with_insufficient_fee_payment_should_fail() {
     MockNet::reset();
@@ -2583,6 +3419,81 @@ fn filtered_multilocation_should_not_work() {
     });
 }
 
+#[test]
+fn denied_destination_should_not_work() {
+    MockNet::reset();
+
+    let para_a_source_location = create_asset_location(1, PARA_A_ID);
+    let para_b_source_location = create_asset_location(1, PARA_B_ID);
+    let para_a_asset_metadata = create_asset_metadata("ParaAToken", "ParaA", 18, 1, false, true);
+    let para_b_asset_metadata = create_asset_metadata("ParaBToken", "ParaB", 18, 1, false, true);
+
+    let a_asset_id_on_a = register_assets_on_parachain::<ParaA>(
+        &para_a_source_location,
+        &para_a_asset_metadata,
+        Some(0u128),
+        None,
+    );
+    let _ = register_assets_on_parachain::<ParaA>(
+        &para_b_source_location,
+        &para_b_asset_metadata,
+        Some(0u128),
+        None,
+    );
+    let _ = register_assets_on_parachain::<ParaB>(
+        &para_a_source_location,
+        &para_a_asset_metadata,
+        Some(0u128),
+        None,
+    );
+
+    let dest = MultiLocation {
+        parents: 1,
+        interior: X2(
+            Parachain(PARA_B_ID),
+            AccountId32 {
+                network: NetworkId::Any,
+                id: ALICE.into(),
+            },
+        ),
+    };
+
+    // Governance denies transfers to ParaB even though it has registered assets
+    ParaA::execute_with(|| {
+        assert_ok!(parachain::AssetManager::set_destination_policy(
+            parachain::Origin::root(),
+            para_b_source_location.clone(),
+            Some(pallet_asset_manager::DestinationPolicy::Deny),
+        ));
+        assert_noop!(
+            parachain::XTokens::transfer(
+                parachain::Origin::signed(ALICE),
+                parachain::CurrencyId::MantaCurrency(a_asset_id_on_a),
+                100,
+                Box::new(VersionedMultiLocation::V1(dest.clone())),
+                80
+            ),
+            orml_xtokens::Error::<parachain::Runtime>::NotSupportedMultiLocation,
+        );
+    });
+
+    // Lifting the policy allows the transfer again
+    ParaA::execute_with(|| {
+        assert_ok!(parachain::AssetManager::set_destination_policy(
+            parachain::Origin::root(),
+            para_b_source_location.clone(),
+            None,
+        ));
+        assert_ok!(parachain::XTokens::transfer(
+            parachain::Origin::signed(ALICE),
+            parachain::CurrencyId::MantaCurrency(a_asset_id_on_a),
+            100,
+            Box::new(VersionedMultiLocation::V1(dest)),
+            80
+        ));
+    });
+}
+
 #[test]
 fn less_than_min_xcm_fee_should_not_work() {
     MockNet::reset();
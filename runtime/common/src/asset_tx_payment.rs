@@ -0,0 +1,389 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Transaction Fee Payment in Non-Native Assets
+
+use codec::{Decode, Encode};
+use core::fmt;
+use frame_support::{
+    dispatch::DispatchResult,
+    log,
+    traits::{tokens::ExistenceRequirement, Get},
+    weights::{DispatchInfo, PostDispatchInfo, WeightToFee},
+};
+use manta_primitives::{
+    assets::{AssetConfig, AssetStatusMap, FungibleLedger, UnitsPerSecond},
+    constants::WEIGHT_PER_SECOND,
+};
+use pallet_transaction_payment::{BalanceOf, ChargeTransactionPayment};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    helpers_128bit::multiply_by_rational,
+    traits::{
+        DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SignedExtension, UniqueSaturatedInto,
+    },
+    transaction_validity::{
+        InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+    },
+    FixedPointOperand,
+};
+use sp_std::marker::PhantomData;
+
+/// Asset Fee Handler
+///
+/// Routes the fees and tips paid in non-native assets, the way an [`OnUnbalanced`] handler routes
+/// the native ones. Both amounts were already burned from the payer, so anything which is not
+/// minted to some account again stays burned.
+///
+/// [`OnUnbalanced`]: frame_support::traits::OnUnbalanced
+pub trait OnChargeAssetFees<AssetId, Balance> {
+    /// Handles the `fees` and `tips` paid in `asset_id`.
+    fn on_asset_fees(asset_id: AssetId, fees: Balance, tips: Balance);
+}
+
+impl<AssetId, Balance> OnChargeAssetFees<AssetId, Balance> for () {
+    #[inline]
+    fn on_asset_fees(asset_id: AssetId, fees: Balance, tips: Balance) {
+        let _ = (asset_id, fees, tips);
+    }
+}
+
+/// Charge Asset Transaction Payment
+///
+/// Drop-in replacement of [`ChargeTransactionPayment`] which lets the signer pay the fee in any
+/// asset with a units-per-second rate in `U` whose [`AssetStatus`] allows paying fees. The native
+/// fee is converted into the asset at the ratio of that rate to the native fee of one second of
+/// weight, withdrawn through the [`FungibleLedger`] of `C` and handed to `R` once the actual fee
+/// is known. The refund of an overestimated fee uses the rate of the withdrawal. Without an asset
+/// id, or with the native asset id, the fee is charged by [`ChargeTransactionPayment`].
+///
+/// The extension is identified and encoded like the `ChargeAssetTxPayment` extension of
+/// `pallet-asset-tx-payment`, as a compact native tip followed by an optional asset id, so that
+/// wallets can build it the same way. Both paths deposit the
+/// [`TransactionFeePaid`](pallet_transaction_payment::Event::TransactionFeePaid) event with the
+/// fee and tip in the native token.
+///
+/// [`AssetStatus`]: manta_primitives::assets::AssetStatus
+#[derive(Decode, Encode, TypeInfo)]
+#[scale_info(skip_type_params(T, C, U, R))]
+pub struct ChargeAssetTransactionPayment<T, C, U, R>
+where
+    T: pallet_transaction_payment::Config,
+    C: AssetConfig<T>,
+{
+    /// Tip in the native token
+    #[codec(compact)]
+    tip: BalanceOf<T>,
+
+    /// Asset to pay the fee in, or `None` for the native token
+    asset_id: Option<C::AssetId>,
+
+    /// Type Parameter Marker
+    __: PhantomData<(C, U, R)>,
+}
+
+impl<T, C, U, R> ChargeAssetTransactionPayment<T, C, U, R>
+where
+    T: pallet_transaction_payment::Config,
+    C: AssetConfig<T>,
+{
+    /// Builds a new [`ChargeAssetTransactionPayment`] paying `tip` on top of the fee, in
+    /// `asset_id` if given.
+    #[inline]
+    pub fn new(tip: BalanceOf<T>, asset_id: Option<C::AssetId>) -> Self {
+        Self {
+            tip,
+            asset_id,
+            __: PhantomData,
+        }
+    }
+}
+
+impl<T, C, U, R> ChargeAssetTransactionPayment<T, C, U, R>
+where
+    T: pallet_transaction_payment::Config,
+    C: AssetConfig<T>,
+    C::AssetId: Clone + PartialEq,
+    U: UnitsPerSecond<AssetId = C::AssetId>,
+{
+    /// Returns the non-native asset the fee is paid in, if any.
+    #[inline]
+    fn fee_asset(&self) -> Option<C::AssetId> {
+        self.asset_id
+            .clone()
+            .filter(|asset_id| *asset_id != C::NativeAssetId::get())
+    }
+
+    /// Returns the units-per-second rate of `asset_id` if fees can be paid in it.
+    #[inline]
+    fn units_per_second(asset_id: &C::AssetId) -> Result<u128, TransactionValidityError> {
+        if !C::AssetStatusMap::status(asset_id).can_pay_fees() {
+            return Err(InvalidTransaction::Payment.into());
+        }
+        U::units_per_second(asset_id).ok_or_else(|| InvalidTransaction::Payment.into())
+    }
+
+    /// Converts the native `fee` into an amount of an asset with `units_per_second`.
+    #[inline]
+    fn asset_fee(
+        units_per_second: u128,
+        fee: BalanceOf<T>,
+    ) -> Result<u128, TransactionValidityError> {
+        let native_per_second = T::WeightToFee::weight_to_fee(&WEIGHT_PER_SECOND);
+        multiply_by_rational(
+            fee.unique_saturated_into(),
+            units_per_second,
+            native_per_second.unique_saturated_into(),
+        )
+        .map_err(|_| InvalidTransaction::Payment.into())
+    }
+}
+
+impl<T, C, U, R> Clone for ChargeAssetTransactionPayment<T, C, U, R>
+where
+    T: pallet_transaction_payment::Config,
+    C: AssetConfig<T>,
+    C::AssetId: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.tip, self.asset_id.clone())
+    }
+}
+
+impl<T, C, U, R> PartialEq for ChargeAssetTransactionPayment<T, C, U, R>
+where
+    T: pallet_transaction_payment::Config,
+    C: AssetConfig<T>,
+    C::AssetId: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.tip == other.tip && self.asset_id == other.asset_id
+    }
+}
+
+impl<T, C, U, R> Eq for ChargeAssetTransactionPayment<T, C, U, R>
+where
+    T: pallet_transaction_payment::Config,
+    C: AssetConfig<T>,
+    C::AssetId: Eq,
+{
+}
+
+impl<T, C, U, R> fmt::Debug for ChargeAssetTransactionPayment<T, C, U, R>
+where
+    T: pallet_transaction_payment::Config,
+    C: AssetConfig<T>,
+    C::AssetId: fmt::Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "ChargeAssetTransactionPayment<{:?}, {:?}>",
+            self.tip, self.asset_id
+        )
+    }
+}
+
+/// Pre-Dispatch Data of [`ChargeAssetTransactionPayment`]
+pub enum AssetPaymentPre<T, C>
+where
+    T: pallet_transaction_payment::Config,
+    T::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+    BalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
+    C: AssetConfig<T>,
+{
+    /// The fee was charged in the native token.
+    Native(<ChargeTransactionPayment<T> as SignedExtension>::Pre),
+
+    /// The fee was charged in a non-native asset.
+    Asset {
+        /// Payer of the Fee
+        who: T::AccountId,
+
+        /// Asset the Fee was paid in
+        asset_id: C::AssetId,
+
+        /// Units per Second of the Asset at which the Fee was withdrawn
+        units_per_second: u128,
+
+        /// Amount of the Asset withdrawn from the Payer
+        withdrawn: u128,
+
+        /// Tip in the native token
+        tip: BalanceOf<T>,
+    },
+}
+
+impl<T, C, U, R> SignedExtension for ChargeAssetTransactionPayment<T, C, U, R>
+where
+    T: pallet_transaction_payment::Config,
+    T::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+    BalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
+    C: AssetConfig<T> + Send + Sync + 'static,
+    C::AssetId: Clone + fmt::Debug + Decode + Encode + Eq + Send + Sync + TypeInfo + 'static,
+    C::Balance: From<u128>,
+    U: UnitsPerSecond<AssetId = C::AssetId> + Send + Sync + 'static,
+    R: OnChargeAssetFees<C::AssetId, C::Balance> + Send + Sync + 'static,
+{
+    const IDENTIFIER: &'static str = "ChargeAssetTxPayment";
+    type AccountId = T::AccountId;
+    type Call = T::Call;
+    type AdditionalSigned = ();
+    type Pre = AssetPaymentPre<T, C>;
+
+    #[inline]
+    fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+        Ok(())
+    }
+
+    #[inline]
+    fn validate(
+        &self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        info: &DispatchInfoOf<Self::Call>,
+        len: usize,
+    ) -> TransactionValidity {
+        let asset_id = match self.fee_asset() {
+            Some(asset_id) => asset_id,
+            None => {
+                return ChargeTransactionPayment::<T>::from(self.tip).validate(who, call, info, len)
+            }
+        };
+        let fee = pallet_transaction_payment::Pallet::<T>::compute_fee(len as u32, info, self.tip);
+        let asset_fee = Self::asset_fee(Self::units_per_second(&asset_id)?, fee)?;
+        C::FungibleLedger::can_withdraw(
+            asset_id,
+            who,
+            &asset_fee.into(),
+            ExistenceRequirement::KeepAlive,
+        )
+        .map_err(|_| InvalidTransaction::Payment)?;
+        Ok(ValidTransaction {
+            priority: ChargeTransactionPayment::<T>::get_priority(info, len, self.tip, fee),
+            ..Default::default()
+        })
+    }
+
+    #[inline]
+    fn pre_dispatch(
+        self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        info: &DispatchInfoOf<Self::Call>,
+        len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        let asset_id = match self.fee_asset() {
+            Some(asset_id) => asset_id,
+            None => {
+                return ChargeTransactionPayment::<T>::from(self.tip)
+                    .pre_dispatch(who, call, info, len)
+                    .map(AssetPaymentPre::Native)
+            }
+        };
+        let fee = pallet_transaction_payment::Pallet::<T>::compute_fee(len as u32, info, self.tip);
+        let units_per_second = Self::units_per_second(&asset_id)?;
+        let withdrawn = Self::asset_fee(units_per_second, fee)?;
+        if withdrawn != 0 {
+            C::FungibleLedger::withdraw_burning(
+                asset_id.clone(),
+                who,
+                withdrawn.into(),
+                ExistenceRequirement::KeepAlive,
+            )
+            .map_err(|_| InvalidTransaction::Payment)?;
+        }
+        Ok(AssetPaymentPre::Asset {
+            who: who.clone(),
+            asset_id,
+            units_per_second,
+            withdrawn,
+            tip: self.tip,
+        })
+    }
+
+    #[inline]
+    fn post_dispatch(
+        pre: Option<Self::Pre>,
+        info: &DispatchInfoOf<Self::Call>,
+        post_info: &PostDispatchInfoOf<Self::Call>,
+        len: usize,
+        result: &DispatchResult,
+    ) -> Result<(), TransactionValidityError> {
+        let (who, asset_id, units_per_second, withdrawn, native_tip) = match pre {
+            Some(AssetPaymentPre::Native(pre)) => {
+                return ChargeTransactionPayment::<T>::post_dispatch(
+                    Some(pre),
+                    info,
+                    post_info,
+                    len,
+                    result,
+                )
+            }
+            Some(AssetPaymentPre::Asset {
+                who,
+                asset_id,
+                units_per_second,
+                withdrawn,
+                tip,
+            }) => (who, asset_id, units_per_second, withdrawn, tip),
+            None => return Ok(()),
+        };
+        let native_fee = pallet_transaction_payment::Pallet::<T>::compute_actual_fee(
+            len as u32, info, post_info, native_tip,
+        );
+        // NOTE: The actual fee never exceeds the estimated one, so neither conversion can fail
+        //       when the withdrawal succeeded. If they did, the payer would not be refunded.
+        let actual_fee = Self::asset_fee(units_per_second, native_fee)
+            .unwrap_or(withdrawn)
+            .min(withdrawn);
+        let refund = withdrawn - actual_fee;
+        if refund != 0 {
+            // NOTE: The refund was burned with the withdrawal, so if it cannot be minted back, for
+            //       instance because it is below the minimum balance, it stays burned.
+            if let Err(err) =
+                C::FungibleLedger::deposit_minting(asset_id.clone(), &who, refund.into())
+            {
+                log::warn!(
+                    target: "asset-tx-payment",
+                    "Refund of {} of asset {:?} to {:?} was lost: {:?}",
+                    refund,
+                    asset_id,
+                    who,
+                    err
+                );
+            }
+        }
+        let tip = Self::asset_fee(units_per_second, native_tip)
+            .unwrap_or(actual_fee)
+            .min(actual_fee);
+        R::on_asset_fees(asset_id, (actual_fee - tip).into(), tip.into());
+        frame_system::Pallet::<T>::deposit_event(
+            <T as pallet_transaction_payment::Config>::Event::from(
+                pallet_transaction_payment::Event::TransactionFeePaid {
+                    who,
+                    actual_fee: native_fee,
+                    tip: native_tip,
+                },
+            )
+            .into(),
+        );
+        Ok(())
+    }
+}
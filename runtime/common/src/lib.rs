@@ -19,8 +19,11 @@
 #![allow(clippy::upper_case_acronyms)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod asset_tx_payment;
 pub mod migration;
 
+pub use asset_tx_payment::{ChargeAssetTransactionPayment, OnChargeAssetFees};
+
 use frame_support::{parameter_types, weights::Weight};
 use manta_primitives::{constants::WEIGHT_PER_NANOS, types::BlockNumber};
 use pallet_transaction_payment::{Multiplier, TargetedFeeAdjustment};
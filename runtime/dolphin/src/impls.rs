@@ -14,8 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with Manta.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{Authorship, Balances, NegativeImbalance};
+use crate::{assets_config::DolphinAssetConfig, Authorship, Balances, NegativeImbalance, Runtime};
 use frame_support::traits::{Currency, Imbalance, OnUnbalanced};
+use manta_primitives::{
+    assets::{AssetConfig, FungibleLedger},
+    types::{Balance, DolphinAssetId},
+};
+use runtime_common::OnChargeAssetFees;
 
 pub struct Author;
 impl OnUnbalanced<NegativeImbalance> for Author {
@@ -38,3 +43,32 @@ impl OnUnbalanced<NegativeImbalance> for DealWithFees {
         }
     }
 }
+
+/// Hands the fees paid in non-native assets to the block author like [`DealWithFees`].
+pub struct DealWithAssetFees;
+impl OnChargeAssetFees<DolphinAssetId, Balance> for DealWithAssetFees {
+    fn on_asset_fees(asset_id: DolphinAssetId, fees: Balance, tips: Balance) {
+        let author = match Authorship::author() {
+            Some(author) => author,
+            _ => return,
+        };
+        let amount = fees.saturating_add(tips);
+        if amount == 0 {
+            return;
+        }
+        if let Err(err) =
+            <DolphinAssetConfig as AssetConfig<Runtime>>::FungibleLedger::deposit_minting(
+                asset_id, &author, amount,
+            )
+        {
+            log::debug!(
+                target: "asset-tx-payment",
+                "Dropped fees of {} of asset {} for {:?}: {:?}",
+                amount,
+                asset_id,
+                author,
+                err
+            );
+        }
+    }
+}
@@ -53,11 +53,13 @@ use frame_system::{
     EnsureRoot,
 };
 use manta_primitives::{
+    assets::{AssetLocation, AssetRegistryMetadata},
     constants::{time::*, RocksDbWeight, STAKING_PALLET_ID, TREASURY_PALLET_ID, WEIGHT_PER_SECOND},
-    types::{AccountId, Balance, BlockNumber, Hash, Header, Index, Signature},
+    types::{AccountId, Balance, BlockNumber, DolphinAssetId, Hash, Header, Index, Signature},
 };
 use runtime_common::{
-    prod_or_fast, BlockExecutionWeight, BlockHashCount, ExtrinsicBaseWeight, SlowAdjustingFeeUpdate,
+    prod_or_fast, BlockExecutionWeight, BlockHashCount, ChargeAssetTransactionPayment,
+    ExtrinsicBaseWeight, SlowAdjustingFeeUpdate,
 };
 use session_key_primitives::{AuraId, NimbusId, VrfId};
 
@@ -74,7 +76,7 @@ pub mod xcm_config;
 
 use currency::*;
 use fee::WeightToFee;
-use impls::DealWithFees;
+use impls::{DealWithAssetFees, DealWithFees};
 
 pub type NegativeImbalance = <Balances as Currency<AccountId>>::NegativeImbalance;
 
@@ -125,10 +127,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("dolphin"),
     impl_name: create_runtime_str!("dolphin"),
     authoring_version: 2,
-    spec_version: 4001,
+    spec_version: 4002,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 5,
+    transaction_version: 6,
     state_version: 0,
 };
 
@@ -215,10 +217,12 @@ impl Contains<Call> for BaseFilter {
         #[allow(clippy::match_like_matches_macro)]
         // keep CallFilter with explicit true/false for documentation
         match call {
+            // Teleports of the native asset to the locations trusted in AssetManager are the only
+            // PolkadotXcm callable allowed, the others are disallowed below.
+            Call::PolkadotXcm(pallet_xcm::Call::limited_teleport_assets {..}) => true,
+
             // Explicitly DISALLOWED calls
             | Call::Assets(_) // Filter Assets. Assets should only be accessed by AssetManager.
-            | Call::AssetManager(_) // AssetManager is also filtered because all of its extrinsics
-                                    // are callable only by Root, and Root calls skip this whole filter.
             // Currently, we filter `register_as_candidate` as this call is not yet ready for community.
             | Call::CollatorSelection( manta_collator_selection::Call::register_as_candidate{..})
             // For now disallow public proposal workflows, treasury workflows,
@@ -281,10 +285,23 @@ impl Contains<Call> for BaseFilter {
                 | manta_collator_selection::Call::register_candidate{..}
                 | manta_collator_selection::Call::remove_collator{..}
                 | manta_collator_selection::Call::leave_intent{..})
+            // Registration proposals and sibling registration requests are open to their callers,
+            // the other AssetManager extrinsics are callable only by Root, and Root calls skip
+            // this whole filter.
+            | Call::AssetManager(pallet_asset_manager::Call::propose_asset {..}
+                | pallet_asset_manager::Call::remove_expired_proposal {..}
+                | pallet_asset_manager::Call::request_sibling_registration {..})
             | Call::Balances(_)
             | Call::XTokens(orml_xtokens::Call::transfer {..}
                 | orml_xtokens::Call::transfer_multicurrencies  {..})
             | Call::MantaPay(_)
+            // Collections are owned by governance, holders can only move, approve and burn items.
+            | Call::Uniques(pallet_uniques::Call::transfer {..}
+                | pallet_uniques::Call::approve_transfer {..}
+                | pallet_uniques::Call::cancel_approval {..}
+                | pallet_uniques::Call::burn {..})
+            // Feeders are managed by `FeederOrigin`, only the price submissions are signed calls.
+            | Call::PriceFeed(pallet_price_feed::Call::submit_price {..})
             | Call::Preimage(_)
             | Call::TransactionPause(_)
             | Call::Utility(_) => true,
@@ -757,6 +774,8 @@ construct_runtime!(
         CumulusXcm: cumulus_pallet_xcm::{Pallet, Event<T>, Origin} = 32,
         DmpQueue: cumulus_pallet_dmp_queue::{Pallet, Call, Storage, Event<T>} = 33,
         XTokens: orml_xtokens::{Pallet, Call, Event<T>, Storage} = 34,
+        XcmFees: pallet_xcm_fees::{Pallet, Call, Storage, Event<T>} = 35,
+        XcmRateLimiter: pallet_xcm_rate_limiter::{Pallet, Call, Storage, Event<T>} = 36,
 
         // Handy utilities.
         Utility: pallet_utility::{Pallet, Call, Event} = 40,
@@ -767,6 +786,8 @@ construct_runtime!(
         Assets: pallet_assets::{Pallet, Call, Storage, Event<T>} = 45,
         AssetManager: pallet_asset_manager::{Pallet, Call, Storage, Config<T>, Event<T>} = 46,
         MantaPay: pallet_manta_pay::{Pallet, Call, Storage, Event<T>} = 47,
+        Uniques: pallet_uniques::{Pallet, Call, Storage, Event<T>} = 49,
+        PriceFeed: pallet_price_feed::{Pallet, Call, Storage, Event<T>} = 51,
     }
 );
 
@@ -786,7 +807,12 @@ pub type SignedExtra = (
     frame_system::CheckEra<Runtime>,
     frame_system::CheckNonce<Runtime>,
     frame_system::CheckWeight<Runtime>,
-    pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+    ChargeAssetTransactionPayment<
+        Runtime,
+        assets_config::DolphinAssetConfig,
+        PriceFeed,
+        DealWithAssetFees,
+    >,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
@@ -795,7 +821,8 @@ pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, Call, SignedExt
 
 /// Types for runtime upgrading.
 /// Each type should implement trait `OnRuntimeUpgrade`.
-pub type OnRuntimeUpgradeHooks = ();
+pub type OnRuntimeUpgradeHooks =
+    (pallet_asset_manager::migrations::LocationNormalizationMigration<Runtime>,);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -837,6 +864,9 @@ mod benches {
         [manta_collator_selection, CollatorSelection]
         [pallet_manta_pay, MantaPay]
         [pallet_asset_manager, AssetManager]
+        [pallet_price_feed, PriceFeed]
+        [pallet_xcm_fees, XcmFees]
+        [pallet_xcm_rate_limiter, XcmRateLimiter]
         // Nimbus pallets
         [pallet_author_inherent, AuthorInherent]
     );
@@ -961,6 +991,51 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_manta_pay::runtime::ForestSnapshotApi<Block> for Runtime {
+        fn forest_snapshot() -> Option<pallet_manta_pay::types::RawForestSnapshot> {
+            MantaPay::forest_snapshot()
+        }
+    }
+
+    impl pallet_asset_manager::runtime::AssetRegistryApi<Block, DolphinAssetId, AssetLocation, AssetRegistryMetadata<Balance>> for Runtime {
+        fn asset_registry_entries(
+            start: Option<DolphinAssetId>,
+            limit: u32,
+        ) -> Vec<pallet_asset_manager::AssetRegistryEntryOf<Runtime>> {
+            AssetManager::registry_entries(start, limit)
+        }
+
+        fn asset_registry_entry(asset_id: DolphinAssetId) -> Option<pallet_asset_manager::AssetRegistryEntryOf<Runtime>> {
+            AssetManager::registry_entry(asset_id)
+        }
+
+        fn asset_registry_entry_by_location(location: AssetLocation) -> Option<pallet_asset_manager::AssetRegistryEntryOf<Runtime>> {
+            AssetManager::registry_entry_by_location(&location)
+        }
+    }
+
+    impl pallet_asset_manager::runtime::AssetMintApi<Block, DolphinAssetId, Balance> for Runtime {
+        fn mint_accounting(asset_id: DolphinAssetId) -> pallet_asset_manager::MintAccounting<Balance> {
+            AssetManager::mint_accounting(asset_id)
+        }
+    }
+
+    impl pallet_asset_manager::runtime::AssetDestroyApi<Block, DolphinAssetId, Balance> for Runtime {
+        fn destroy_blockers(asset_id: DolphinAssetId) -> Vec<pallet_asset_manager::AssetDestroyBlocker<Balance>> {
+            AssetManager::check_destroy_asset(asset_id).err().unwrap_or_default()
+        }
+    }
+
+    impl pallet_asset_manager::runtime::XcmDestinationApi<Block, AssetLocation> for Runtime {
+        fn destination_policies() -> Vec<(AssetLocation, pallet_asset_manager::DestinationPolicy)> {
+            AssetManager::destination_policies()
+        }
+
+        fn is_allowed_destination(dest: AssetLocation) -> bool {
+            AssetManager::is_allowed_destination(&dest)
+        }
+    }
+
     impl nimbus_primitives::NimbusApi<Block> for Runtime {
         fn can_author(author: NimbusId, relay_parent: u32, parent_header: &<Block as BlockT>::Header) -> bool {
             System::initialize(&(parent_header.number + 1), &parent_header.hash(), &parent_header.digest);
@@ -29,10 +29,13 @@ pub mod pallet_manta_pay;
 pub mod pallet_membership;
 pub mod pallet_multisig;
 pub mod pallet_preimage;
+pub mod pallet_price_feed;
 pub mod pallet_scheduler;
 pub mod pallet_session;
 pub mod pallet_timestamp;
 pub mod pallet_treasury;
 pub mod pallet_tx_pause;
 pub mod pallet_utility;
+pub mod pallet_xcm_fees;
+pub mod pallet_xcm_rate_limiter;
 pub mod xcm;
@@ -0,0 +1,123 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+
+//! Autogenerated weights for pallet_price_feed
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2022-12-29, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dolphin-dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/production/manta
+// benchmark
+// pallet
+// --chain=dolphin-dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_price_feed
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./scripts/benchmarking/frame-weights-output/pallet_price_feed.rs
+// --template=.github/resources/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_price_feed.
+pub trait WeightInfo {
+    fn add_feeder() -> Weight;
+    fn remove_feeder(p: u32, ) -> Weight;
+    fn submit_price(f: u32, ) -> Weight;
+}
+
+/// Weights for pallet_price_feed using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_price_feed::WeightInfo for SubstrateWeight<T> {
+    // Storage: PriceFeed Feeders (r:1 w:1)
+    fn add_feeder() -> Weight {
+        (21_740_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    // Storage: PriceFeed Feeders (r:1 w:1)
+    // Storage: PriceFeed PriceCounts (r:1 w:1)
+    // Storage: PriceFeed Prices (r:1 w:1)
+    // Storage: PriceFeed MedianPrices (r:0 w:1)
+    fn remove_feeder(p: u32, ) -> Weight {
+        (24_861_000 as Weight)
+            // Standard Error: 2_000
+            .saturating_add((1_893_000 as Weight).saturating_mul(p as Weight))
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(p as Weight)))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(p as Weight)))
+    }
+    // Storage: PriceFeed Feeders (r:1 w:0)
+    // Storage: PriceFeed Prices (r:1 w:1)
+    // Storage: PriceFeed PriceCounts (r:1 w:1)
+    // Storage: PriceFeed MedianPrices (r:0 w:1)
+    fn submit_price(f: u32, ) -> Weight {
+        (29_106_000 as Weight)
+            // Standard Error: 3_000
+            .saturating_add((2_417_000 as Weight).saturating_mul(f as Weight))
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(f as Weight)))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    // Storage: PriceFeed Feeders (r:1 w:1)
+    fn add_feeder() -> Weight {
+        (21_740_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(1 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    // Storage: PriceFeed Feeders (r:1 w:1)
+    // Storage: PriceFeed PriceCounts (r:1 w:1)
+    // Storage: PriceFeed Prices (r:1 w:1)
+    // Storage: PriceFeed MedianPrices (r:0 w:1)
+    fn remove_feeder(p: u32, ) -> Weight {
+        (24_861_000 as Weight)
+            // Standard Error: 2_000
+            .saturating_add((1_893_000 as Weight).saturating_mul(p as Weight))
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(p as Weight)))
+            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(p as Weight)))
+    }
+    // Storage: PriceFeed Feeders (r:1 w:0)
+    // Storage: PriceFeed Prices (r:1 w:1)
+    // Storage: PriceFeed PriceCounts (r:1 w:1)
+    // Storage: PriceFeed MedianPrices (r:0 w:1)
+    fn submit_price(f: u32, ) -> Weight {
+        (29_106_000 as Weight)
+            // Standard Error: 3_000
+            .saturating_add((2_417_000 as Weight).saturating_mul(f as Weight))
+            .saturating_add(RocksDbWeight::get().reads(3 as Weight))
+            .saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(f as Weight)))
+            .saturating_add(RocksDbWeight::get().writes(3 as Weight))
+    }
+}
@@ -75,6 +75,14 @@ impl<T: frame_system::Config> WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(9 as Weight))
 			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 	}
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Storage: AssetManager TrustedTeleporters (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	pub(crate) fn receive_teleported_asset() -> Weight {
+		(34_702_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 	pub(crate) fn reserve_asset_deposited() -> Weight {
 		(1_511_000 as Weight)
 	}
@@ -15,26 +15,28 @@
 // along with Manta.  If not, see <http://www.gnu.org/licenses/>.
 
 use super::{
-    assets_config::DolphinAssetConfig, AssetManager, Assets, Balances, Call, DmpQueue,
+    assets_config::DolphinAssetConfig, AssetManager, Authorship, Call, DmpQueue,
     EnsureRootOrMoreThanHalfCouncil, Event, Origin, ParachainInfo, ParachainSystem, PolkadotXcm,
-    Runtime, Treasury, XcmpQueue, MAXIMUM_BLOCK_WEIGHT,
+    PriceFeed, Runtime, Treasury, XcmFees, XcmRateLimiter, XcmpQueue, MAXIMUM_BLOCK_WEIGHT,
 };
 use codec::{Decode, Encode};
 use cumulus_pallet_parachain_system::RelayNumberStrictlyIncreases;
 use frame_support::{
     match_types, parameter_types,
-    traits::{Currency, Everything, Nothing},
+    traits::{Contains, EnsureOrigin, Everything, Nothing},
     weights::Weight,
 };
 use frame_system::EnsureRoot;
 use manta_primitives::{
-    assets::AssetIdLocationConvert,
+    assets::{AssetIdLocationConvert, AssetLocation},
     types::{AccountId, Balance, DolphinAssetId},
     xcm::{
-        AccountIdToMultiLocation, FirstAssetTrader, IsNativeConcrete, MultiAssetAdapter,
-        MultiNativeAsset,
+        AccountIdToMultiLocation, FeeSplit, FilteredTransactExecutor, FirstAssetTrader,
+        IsNativeConcrete, MultiAssetAdapter, MultiNativeAsset, NativeTeleportAssets,
+        NativeTeleportFrom, RateLimitedExecutor, TrustedTeleportExecutor, XcmFeesSplit,
     },
 };
+use pallet_asset_manager::IsTrustedTeleporter;
 use pallet_xcm::XcmPassthrough;
 use polkadot_parachain::primitives::Sibling;
 use scale_info::TypeInfo;
@@ -45,7 +47,7 @@ use xcm_builder::{
     AllowTopLevelPaidExecutionFrom, AllowUnpaidExecutionFrom, ConvertedConcreteAssetId,
     EnsureXcmOrigin, FixedRateOfFungible, LocationInverter, ParentAsSuperuser, ParentIsPreset,
     RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
-    SignedAccountId32AsNative, SovereignSignedViaLocation, TakeRevenue, TakeWeightCredit,
+    SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit,
     WeightInfoBounds,
 };
 use xcm_executor::{traits::JustTry, Config, XcmExecutor};
@@ -78,6 +80,7 @@ parameter_types! {
     pub Ancestry: MultiLocation = Parachain(ParachainInfo::parachain_id().into()).into();
     pub SelfReserve: MultiLocation = MultiLocation::new(1, X1(Parachain(ParachainInfo::parachain_id().into())));
     pub CheckingAccount: AccountId = PolkadotXcm::check_account();
+    pub TeleportCheckingAccount: Option<AccountId> = Some(CheckingAccount::get());
 }
 
 /// Type for specifying how a `MultiLocation` can be converted into an `AccountId`. This is used
@@ -117,6 +120,28 @@ pub type XcmOriginToCallOrigin = (
     XcmPassthrough<Origin>,
 );
 
+/// Ensures that the origin is a sibling parachain, as converted from an XCM `Transact` with
+/// `OriginKind::Native` by `SiblingParachainAsNative`, returning its para id.
+pub struct EnsureSiblingParachain;
+
+impl EnsureOrigin<Origin> for EnsureSiblingParachain {
+    type Success = u32;
+
+    #[inline]
+    fn try_origin(o: Origin) -> Result<u32, Origin> {
+        Into::<Result<cumulus_pallet_xcm::Origin, Origin>>::into(o).and_then(|o| match o {
+            cumulus_pallet_xcm::Origin::SiblingParachain(para_id) => Ok(para_id.into()),
+            o => Err(o.into()),
+        })
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    #[inline]
+    fn successful_origin() -> Origin {
+        cumulus_pallet_xcm::Origin::SiblingParachain(2015.into()).into()
+    }
+}
+
 parameter_types! {
     // One XCM operation is 1_000_000_000 weight - almost certainly a conservative estimate.
     pub UnitWeightCost: Weight = 1_000_000_000;
@@ -125,6 +150,9 @@ parameter_types! {
     // We have to use `here()` because of reanchoring logic
     pub ParaTokenPerSecond: (xcm::v2::AssetId, u128) = (Concrete(MultiLocation::here()), 1_000_000_000);
     pub const MaxInstructions: u32 = 100;
+    /// Assets tried first to pay for XCM execution, in order, starting with the native asset.
+    /// The native asset is held at `here()` because of reanchoring logic.
+    pub XcmFeeAssetPreference: Vec<MultiLocation> = vec![MultiLocation::here(), MultiLocation::parent()];
 }
 
 /// Transactor for the native asset which implements `fungible` trait, as well as
@@ -144,6 +172,8 @@ pub type MultiAssetTransactor = MultiAssetAdapter<
         AssetIdLocationConvert<AssetManager>,
         JustTry,
     >,
+    // Used to track the native asset teleported out of this chain.
+    TeleportCheckingAccount,
 >;
 
 match_types! {
@@ -175,40 +205,79 @@ pub type Barrier = (
     AllowSubscriptionsFrom<ParentOrSiblings>,
 );
 
+/// Weigher of XCM messages on this chain
+pub type XcmWeigher =
+    WeightInfoBounds<crate::weights::xcm::DolphinXcmWeight<Call>, Call, MaxInstructions>;
+
 parameter_types! {
-    pub XcmFeesAccount: AccountId = Treasury::account_id();
+    /// Weight of checking the call of a `Transact` instruction from a sibling chain.
+    pub const XcmTransactFilterWeight: Weight = 10_000_000;
 }
 
-/// Xcm fee of native token
-pub struct XcmNativeFeeToTreasury;
+/// Calls which sibling sovereign or derived accounts may dispatch through `Transact`
+///
+/// Filtered calls are trapped with `FILTERED_TRANSACT_TRAP_CODE` instead of being dispatched, and
+/// the allowed calls still go through `BaseFilter` when they are dispatched.
+pub struct XcmTransactFilter;
 
-impl TakeRevenue for XcmNativeFeeToTreasury {
-    #[inline]
-    fn take_revenue(revenue: MultiAsset) {
-        if let MultiAsset {
-            id: Concrete(location),
-            fun: Fungible(amount),
-        } = revenue
-        {
-            if location == MultiLocation::here() {
-                let _ = Balances::deposit_creating(&XcmFeesAccount::get(), amount);
-            }
-        }
+impl Contains<Call> for XcmTransactFilter {
+    fn contains(call: &Call) -> bool {
+        matches!(
+            call,
+            Call::MantaPay(_)
+                | Call::Balances(
+                    pallet_balances::Call::transfer { .. }
+                        | pallet_balances::Call::transfer_keep_alive { .. }
+                )
+                | Call::Assets(
+                    pallet_assets::Call::transfer { .. }
+                        | pallet_assets::Call::transfer_keep_alive { .. }
+                )
+                | Call::XTokens(orml_xtokens::Call::transfer { .. })
+                | Call::AssetManager(
+                    pallet_asset_manager::Call::request_sibling_registration { .. }
+                )
+        )
     }
 }
 
-pub type XcmFeesToAccount = manta_primitives::xcm::XcmFeesToAccount<
-    AccountId,
-    Assets,
+parameter_types! {
+    pub XcmFeesAccount: AccountId = Treasury::account_id();
+    /// Split of the Xcm fee revenue, as set by governance in `XcmFees`.
+    pub XcmFeeSplit: FeeSplit = XcmFees::xcm_fee_split();
+    pub XcmFeeAuthor: Option<AccountId> = Authorship::author();
+}
+
+/// Xcm fees of the native token and of the registered assets, split between the treasury, the
+/// block author, and burning
+pub type XcmFeesToSplit = XcmFeesSplit<
+    Runtime,
+    DolphinAssetConfig,
+    IsNativeConcrete<SelfReserve>,
     ConvertedConcreteAssetId<
         DolphinAssetId,
         Balance,
         AssetIdLocationConvert<AssetManager>,
         JustTry,
     >,
+    XcmFeeSplit,
     XcmFeesAccount,
+    XcmFeeAuthor,
 >;
 
+impl pallet_xcm_rate_limiter::Config for Runtime {
+    type Event = Event;
+    type Location = AssetLocation;
+    type UpdateOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = crate::weights::pallet_xcm_rate_limiter::SubstrateWeight<Runtime>;
+}
+
+impl pallet_xcm_fees::Config for Runtime {
+    type Event = Event;
+    type UpdateOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = crate::weights::pallet_xcm_fees::SubstrateWeight<Runtime>;
+}
+
 pub struct XcmExecutorConfig;
 impl Config for XcmExecutorConfig {
     type Call = Call;
@@ -218,20 +287,28 @@ impl Config for XcmExecutorConfig {
     type OriginConverter = XcmOriginToCallOrigin;
     // Combinations of (Location, Asset) pairs which we trust as reserves.
     type IsReserve = MultiNativeAsset;
-    type IsTeleporter = ();
+    // Teleports of the native asset are accepted from the locations trusted in `AssetManager`.
+    type IsTeleporter = NativeTeleportFrom<SelfReserve, IsTrustedTeleporter<Runtime>>;
     type LocationInverter = LocationInverter<Ancestry>;
     type Barrier = Barrier;
-    type Weigher =
-        WeightInfoBounds<crate::weights::xcm::DolphinXcmWeight<Call>, Call, MaxInstructions>;
+    type Weigher = XcmWeigher;
     // Trader is the means to purchasing weight credit for XCM execution.
     // We define two traders:
     // The first one will charge parachain's native currency, who's `MultiLocation`
     // is defined in `SelfReserve`.
-    // The second one will charge the first asset in the MultiAssets with pre-defined rate
-    // i.e. units_per_second in `AssetManager`
+    // The second one will charge the first asset in the MultiAssets, in the order given by
+    // `XcmFeeAssetPreference`, that can cover the weight with the rate given by `PriceFeed`,
+    // i.e. the oracle price with units_per_second in `AssetManager` as a floor
+    // Both traders split their fee revenue as given by `XcmFeeSplit`.
     type Trader = (
-        FixedRateOfFungible<ParaTokenPerSecond, XcmNativeFeeToTreasury>,
-        FirstAssetTrader<AssetManager, XcmFeesToAccount>,
+        FixedRateOfFungible<ParaTokenPerSecond, XcmFeesToSplit>,
+        FirstAssetTrader<
+            AssetManager,
+            PriceFeed,
+            XcmFeesToSplit,
+            XcmFeeAssetPreference,
+            SelfReserve,
+        >,
     );
     type ResponseHandler = PolkadotXcm;
     type AssetTrap = PolkadotXcm;
@@ -243,6 +320,10 @@ impl Config for XcmExecutorConfig {
 /// No one is allowed to dispatch XCM sends/executions.
 pub type LocalOriginToLocation = ();
 
+/// Only signed accounts are converted, for the teleports of `limited_teleport_assets`, which is the
+/// only `PolkadotXcm` callable allowed by the `BaseFilter`.
+pub type LocalAccountToLocation = SignedToAccountId32<Origin, AccountId, RelayNetwork>;
+
 /// The means for routing XCM messages which are not for local execution into the right message
 /// queues.
 pub type XcmRouter = (
@@ -260,15 +341,17 @@ impl pallet_xcm::Config for Runtime {
     type Event = Event;
     type SendXcmOrigin = EnsureXcmOrigin<Origin, LocalOriginToLocation>;
     type XcmRouter = XcmRouter;
-    type ExecuteXcmOrigin = EnsureXcmOrigin<Origin, LocalOriginToLocation>;
+    type ExecuteXcmOrigin = EnsureXcmOrigin<Origin, LocalAccountToLocation>;
     /// This means that no location will pass XcmExecuteFilter, so a dispatched `execute` message will be filtered.
-    /// This shouldn't be reachable since `LocalOriginToLocation = ();`, but let's be on the safe side.
+    /// This shouldn't be reachable since `execute` is disallowed by the `BaseFilter`, but let's be on the safe side.
     type XcmExecuteFilter = Nothing;
-    type XcmExecutor = XcmExecutor<XcmExecutorConfig>;
-    type XcmTeleportFilter = Nothing;
+    /// Teleports are only sent to the locations trusted in `AssetManager`.
+    type XcmExecutor =
+        TrustedTeleportExecutor<XcmExecutor<XcmExecutorConfig>, IsTrustedTeleporter<Runtime>>;
+    /// Only the native asset can be teleported.
+    type XcmTeleportFilter = NativeTeleportAssets<SelfReserve>;
     type XcmReserveTransferFilter = Nothing;
-    type Weigher =
-        WeightInfoBounds<crate::weights::xcm::DolphinXcmWeight<Call>, Call, MaxInstructions>;
+    type Weigher = XcmWeigher;
     type LocationInverter = LocationInverter<Ancestry>;
     type AdvertisedXcmVersion = pallet_xcm::CurrentXcmVersion;
 }
@@ -280,7 +363,18 @@ impl cumulus_pallet_xcm::Config for Runtime {
 
 impl cumulus_pallet_xcmp_queue::Config for Runtime {
     type Event = Event;
-    type XcmExecutor = XcmExecutor<XcmExecutorConfig>;
+    // Sibling chains may only `Transact` the calls allowed by `XcmTransactFilter`.
+    // Messages over the rate limit of their origin in `XcmRateLimiter` are kept for a later
+    // block, and messages over the rate limit of a whole window are moved to the overweight queue.
+    type XcmExecutor = RateLimitedExecutor<
+        XcmRateLimiter,
+        FilteredTransactExecutor<
+            XcmExecutor<XcmExecutorConfig>,
+            XcmTransactFilter,
+            XcmTransactFilterWeight,
+        >,
+        XcmWeigher,
+    >;
     type ChannelInfo = ParachainSystem;
     type VersionWrapper = PolkadotXcm;
     type ExecuteOverweightOrigin = EnsureRoot<AccountId>;
@@ -291,7 +385,10 @@ impl cumulus_pallet_xcmp_queue::Config for Runtime {
 
 impl cumulus_pallet_dmp_queue::Config for Runtime {
     type Event = Event;
-    type XcmExecutor = XcmExecutor<XcmExecutorConfig>;
+    // Messages over the rate limit of their origin in `XcmRateLimiter` are kept for a later
+    // block, and messages over the rate limit of a whole window are moved to the overweight queue.
+    type XcmExecutor =
+        RateLimitedExecutor<XcmRateLimiter, XcmExecutor<XcmExecutorConfig>, XcmWeigher>;
     type ExecuteOverweightOrigin = EnsureRoot<AccountId>;
 }
 
@@ -335,8 +432,7 @@ impl orml_xtokens::Config for Runtime {
     // Take note that this pallet does not have the typical configurable WeightInfo.
     // It uses the Weigher configuration to calculate weights for the user callable extrinsics on this chain,
     // as well as weights for execution on the destination chain. Both based on the composed xcm messages.
-    type Weigher =
-        WeightInfoBounds<crate::weights::xcm::DolphinXcmWeight<Call>, Call, MaxInstructions>;
+    type Weigher = XcmWeigher;
     type BaseXcmWeight = BaseXcmWeight;
     type LocationInverter = LocationInverter<Ancestry>;
     type MaxAssetsForTransfer = MaxAssetsForTransfer;